@@ -1,23 +1,35 @@
 use crate::error::Result;
 use crate::extraction::mammo_type::extract_mammogram_type_impl;
 use crate::extraction::tags::{
-    get_int_value, get_string_value, BREAST_IMPLANT_PRESENT, COLUMNS, CONCATENATION_UID,
-    IMAGER_PIXEL_SPACING, MANUFACTURER, MANUFACTURER_MODEL_NAME, MODALITY, NUMBER_OF_FRAMES,
-    PIXEL_SPACING, PRESENTATION_INTENT_TYPE, ROWS, SOP_CLASS_UID,
-    SOP_INSTANCE_UID_OF_CONCATENATION_SOURCE,
+    get_float_value, get_int_value, get_string_value, BODY_PART_THICKNESS, BREAST_IMPLANT_PRESENT,
+    COLUMNS, COMPRESSION_FORCE, CONCATENATION_UID, IMAGER_PIXEL_SPACING, MANUFACTURER,
+    MANUFACTURER_MODEL_NAME, MODALITY, NUMBER_OF_FRAMES, PHOTOMETRIC_INTERPRETATION, PIXEL_SPACING,
+    PRESENTATION_INTENT_TYPE, RESCALE_INTERCEPT, RESCALE_SLOPE, ROWS, SOP_CLASS_UID,
+    SOP_INSTANCE_UID_OF_CONCATENATION_SOURCE, SPECIFIC_CHARACTER_SET,
 };
 use crate::extraction::{
     extract_dbt_object_kind, extract_image_type, extract_laterality, extract_view_descriptor,
+    is_localizer, is_specimen,
 };
 use crate::types::{
     DbtObjectKind, ImageType, Laterality, MammogramType, MammogramView, MammographyViewModifier,
-    PixelSpacing, ViewPosition,
+    PhotometricInterpretation, PixelSpacing, PresentationIntent, ViewPosition,
 };
+use dicom::encoding::text::SpecificCharacterSet;
 use dicom::transfer_syntax::{TransferSyntaxIndex, TransferSyntaxRegistry};
+use dicom_core::Tag;
 use dicom_object::{FileDicomObject, InMemDicomObject};
+use std::collections::HashMap;
 
 const UNKNOWN_TRANSFER_SYNTAX: &str = "unknown transfer syntax";
 
+/// Minimum ImagerPixelSpacing / PixelSpacing ratio treated as geometric magnification.
+///
+/// Contact views typically report a radiographic magnification factor of about
+/// 1.05-1.15 between the detector and breast planes, while magnification views
+/// use factors of 1.5-2.0.
+pub const SPACING_MAGNIFICATION_THRESHOLD: f64 = 1.3;
+
 /// Main extractor for mammography metadata
 ///
 /// Provides a high-level API for extracting all relevant mammography
@@ -85,6 +97,44 @@ impl MammogramExtractor {
         Self::extract_file_with_options(dcm, false)
     }
 
+    /// Extracts metadata plus the raw values of caller-specified extra tags
+    ///
+    /// Intended for trying out new fields without modifying the crate. Each
+    /// extra tag present in the dataset maps to its trimmed string value,
+    /// with multiple values joined by a backslash. Tags that are absent or
+    /// cannot be rendered as text are omitted from the map.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MammogramExtractor::extract`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::MammogramExtractor;
+    /// use dicom_core::{DataElement, PrimitiveValue, Tag, VR};
+    /// use dicom_object::InMemDicomObject;
+    ///
+    /// let body_part = Tag(0x0018, 0x0015); // BodyPartExamined
+    /// let mut dcm = InMemDicomObject::new_empty();
+    /// dcm.put(DataElement::new(Tag(0x0008, 0x0060), VR::CS, PrimitiveValue::from("MG")));
+    /// dcm.put(DataElement::new(body_part, VR::CS, PrimitiveValue::from("BREAST")));
+    ///
+    /// let (_, extra) = MammogramExtractor::extract_with_extra_tags(&dcm, &[body_part]).unwrap();
+    /// assert_eq!(extra[&body_part], "BREAST");
+    /// ```
+    pub fn extract_with_extra_tags(
+        dcm: &InMemDicomObject,
+        extra_tags: &[Tag],
+    ) -> Result<(MammogramMetadata, HashMap<Tag, String>)> {
+        let metadata = Self::extract(dcm)?;
+        let extra_values = extra_tags
+            .iter()
+            .filter_map(|&tag| get_string_value(dcm, tag).map(|value| (tag, value)))
+            .collect();
+        Ok((metadata, extra_values))
+    }
+
     /// Extracts metadata with optional SFM flag
     ///
     /// The `is_sfm` flag manually indicates if the mammogram is SFM
@@ -93,38 +143,99 @@ impl MammogramExtractor {
         Self::extract_with_options_and_modality_policy(dcm, is_sfm, false)
     }
 
-    /// Extracts metadata with optional SFM flag and configurable modality strictness.
-    pub(crate) fn extract_with_options_and_modality_policy(
+    /// Extracts metadata with optional SFM flag and configurable modality strictness
+    ///
+    /// With `ignore_modality` set, the Modality tag is not required to be
+    /// "MG" and the object is classified from its other tags, which lets
+    /// research datasets with mislabeled modality (often "OT") be processed.
+    /// Such objects are only flagged as secondary capture by their SOP Class,
+    /// not by an "OT" Modality. The recorded `modality` field still holds the
+    /// actual tag value.
+    pub fn extract_with_options_and_modality_policy(
         dcm: &InMemDicomObject,
         is_sfm: bool,
         ignore_modality: bool,
     ) -> Result<MammogramMetadata> {
         let mammogram_type = extract_mammogram_type_impl(dcm, is_sfm, ignore_modality)?;
+        let mut metadata = Self::build_metadata(dcm, mammogram_type, extract_laterality(dcm)?);
+        if ignore_modality {
+            metadata.is_secondary_capture = Self::is_secondary_capture_sop_class(dcm);
+        }
+        Ok(metadata)
+    }
+
+    /// Extracts metadata without ever failing
+    ///
+    /// Unlike [`MammogramExtractor::extract`], which rejects objects whose
+    /// Modality is not "MG", this is meant for scanning mixed directories:
+    /// non-mammography objects and other recoverable problems yield
+    /// metadata with `mammogram_type = Unknown` while the remaining fields,
+    /// including the actual `modality`, are filled in as usual. Use
+    /// `extract` when a non-mammogram should be treated as an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::{MammogramExtractor, MammogramType};
+    /// use dicom_core::{DataElement, PrimitiveValue, Tag, VR};
+    /// use dicom_object::InMemDicomObject;
+    ///
+    /// let mut dcm = InMemDicomObject::new_empty();
+    /// dcm.put(DataElement::new(Tag(0x0008, 0x0060), VR::CS, PrimitiveValue::from("CT")));
+    ///
+    /// assert!(MammogramExtractor::extract(&dcm).is_err());
+    /// let metadata = MammogramExtractor::extract_lenient(&dcm);
+    /// assert_eq!(metadata.mammogram_type, MammogramType::Unknown);
+    /// assert_eq!(metadata.modality.as_deref(), Some("CT"));
+    /// ```
+    pub fn extract_lenient(dcm: &InMemDicomObject) -> MammogramMetadata {
+        let mammogram_type =
+            extract_mammogram_type_impl(dcm, false, false).unwrap_or(MammogramType::Unknown);
+        let laterality = extract_laterality(dcm).unwrap_or(Laterality::Unknown);
+        Self::build_metadata(dcm, mammogram_type, laterality)
+    }
+
+    /// Assembles metadata once the fallible type and laterality are known
+    fn build_metadata(
+        dcm: &InMemDicomObject,
+        mammogram_type: MammogramType,
+        laterality: Laterality,
+    ) -> MammogramMetadata {
         let view = extract_view_descriptor(dcm);
-        Ok(MammogramMetadata {
+        MammogramMetadata {
             mammogram_type,
             dbt_object_kind: extract_dbt_object_kind(dcm, mammogram_type),
-            laterality: extract_laterality(dcm)?,
+            laterality,
             view_position: view.view_position,
             view_modifiers: view.modifiers,
             image_type: extract_image_type(dcm),
             is_for_processing: Self::extract_for_processing(dcm),
+            presentation_intent: Self::extract_presentation_intent(dcm),
             has_implant: Self::extract_implant_status(dcm),
             manufacturer: get_string_value(dcm, MANUFACTURER),
             model: get_string_value(dcm, MANUFACTURER_MODEL_NAME),
             number_of_frames: get_int_value(dcm, NUMBER_OF_FRAMES).unwrap_or(1),
             pixel_spacing: Self::extract_pixel_spacing(dcm),
+            imager_pixel_spacing: Self::extract_imager_pixel_spacing(dcm),
+            photometric: Self::extract_photometric(dcm),
+            rescale_slope: get_float_value(dcm, RESCALE_SLOPE),
+            rescale_intercept: get_float_value(dcm, RESCALE_INTERCEPT),
+            body_part_thickness_mm: get_float_value(dcm, BODY_PART_THICKNESS),
+            compression_force_n: get_float_value(dcm, COMPRESSION_FORCE),
             concatenation_uid: get_string_value(dcm, CONCATENATION_UID),
             sop_instance_uid_of_concatenation_source: get_string_value(
                 dcm,
                 SOP_INSTANCE_UID_OF_CONCATENATION_SOURCE,
             ),
             is_secondary_capture: Self::extract_secondary_capture(dcm),
+            is_localizer: is_localizer(dcm),
+            is_specimen: is_specimen(dcm),
             modality: Self::extract_modality(dcm),
+            character_set: Self::extract_character_set(dcm),
             transfer_syntax_uid: None,
             transfer_syntax_name: None,
             compression_type: None,
-        })
+        }
     }
 
     /// Extracts metadata from a full DICOM file object with optional SFM flag.
@@ -135,8 +246,10 @@ impl MammogramExtractor {
         Self::extract_file_with_options_and_modality_policy(dcm, is_sfm, false)
     }
 
-    /// Extracts metadata from a full DICOM file object with configurable modality strictness.
-    pub(crate) fn extract_file_with_options_and_modality_policy(
+    /// Extracts metadata from a full DICOM file object with configurable modality strictness
+    ///
+    /// See [`MammogramExtractor::extract_with_options_and_modality_policy`].
+    pub fn extract_file_with_options_and_modality_policy(
         dcm: &FileDicomObject<InMemDicomObject>,
         is_sfm: bool,
         ignore_modality: bool,
@@ -159,6 +272,13 @@ impl MammogramExtractor {
             .unwrap_or(false)
     }
 
+    /// Extracts PresentationIntentType, defaulting to Unknown when absent
+    fn extract_presentation_intent(dcm: &InMemDicomObject) -> PresentationIntent {
+        get_string_value(dcm, PRESENTATION_INTENT_TYPE)
+            .map(|s| PresentationIntent::from_str(&s))
+            .unwrap_or_default()
+    }
+
     /// Extracts breast implant status
     fn extract_implant_status(dcm: &InMemDicomObject) -> bool {
         get_string_value(dcm, BREAST_IMPLANT_PRESENT)
@@ -171,10 +291,19 @@ impl MammogramExtractor {
     /// Checks if SOP Class UID indicates a secondary capture image.
     /// Secondary Capture SOP Class UID: 1.2.840.10008.5.1.4.1.1.7
     /// Multi-frame variants: .7.1, .7.2, .7.3, .7.4
+    ///
+    /// Modality "OT" (Other) is also treated as secondary capture, since
+    /// screenshots and scanned films are commonly stored that way.
     fn extract_secondary_capture(dcm: &InMemDicomObject) -> bool {
+        Self::is_secondary_capture_sop_class(dcm)
+            || Self::extract_modality(dcm)
+                .is_some_and(|modality| modality.eq_ignore_ascii_case("OT"))
+    }
+
+    /// Whether SOPClassUID is one of the Secondary Capture Image Storage classes
+    fn is_secondary_capture_sop_class(dcm: &InMemDicomObject) -> bool {
         get_string_value(dcm, SOP_CLASS_UID)
-            .map(|uid| uid.starts_with("1.2.840.10008.5.1.4.1.1.7"))
-            .unwrap_or(false)
+            .is_some_and(|uid| uid.starts_with("1.2.840.10008.5.1.4.1.1.7"))
     }
 
     /// Extracts modality
@@ -184,15 +313,49 @@ impl MammogramExtractor {
         get_string_value(dcm, MODALITY)
     }
 
+    /// Extracts PhotometricInterpretation, defaulting to Unknown when absent
+    fn extract_photometric(dcm: &InMemDicomObject) -> PhotometricInterpretation {
+        get_string_value(dcm, PHOTOMETRIC_INTERPRETATION)
+            .map(|value| PhotometricInterpretation::from_str(&value))
+            .unwrap_or(PhotometricInterpretation::Unknown)
+    }
+
+    /// Extracts SpecificCharacterSet
+    ///
+    /// Text values are decoded by the DICOM parser according to this
+    /// character set. Terms the parser cannot decode are logged because text
+    /// tags such as Manufacturer or PaddleDescription may then be garbled.
+    fn extract_character_set(dcm: &InMemDicomObject) -> Option<String> {
+        let character_set = get_string_value(dcm, SPECIFIC_CHARACTER_SET)
+            .filter(|value| !value.trim_matches('\\').is_empty())?;
+        for term in character_set.split('\\').map(str::trim) {
+            if !term.is_empty() && SpecificCharacterSet::from_code(term).is_none() {
+                log::warn!(
+                    "unsupported SpecificCharacterSet term {term:?}; text tags may be decoded incorrectly"
+                );
+            }
+        }
+        Some(character_set)
+    }
+
     /// Extracts pixel spacing from PixelSpacing with ImagerPixelSpacing fallback.
     fn extract_pixel_spacing(dcm: &InMemDicomObject) -> Option<PixelSpacing> {
+        [PIXEL_SPACING, IMAGER_PIXEL_SPACING]
+            .into_iter()
+            .find_map(|tag| Self::parse_spacing_tag(dcm, tag))
+    }
+
+    /// Extracts detector-plane spacing from ImagerPixelSpacing only.
+    fn extract_imager_pixel_spacing(dcm: &InMemDicomObject) -> Option<PixelSpacing> {
+        Self::parse_spacing_tag(dcm, IMAGER_PIXEL_SPACING)
+    }
+
+    fn parse_spacing_tag(dcm: &InMemDicomObject, tag: Tag) -> Option<PixelSpacing> {
         let rows = get_int_value(dcm, ROWS).and_then(|value| value.try_into().ok());
         let columns = get_int_value(dcm, COLUMNS).and_then(|value| value.try_into().ok());
 
-        [PIXEL_SPACING, IMAGER_PIXEL_SPACING]
-            .into_iter()
-            .filter_map(|tag| get_string_value(dcm, tag))
-            .find_map(|value| PixelSpacing::parse_with_dimensions(&value, rows, columns).ok())
+        get_string_value(dcm, tag)
+            .and_then(|value| PixelSpacing::parse_with_dimensions(&value, rows, columns).ok())
     }
 }
 
@@ -327,6 +490,9 @@ pub struct MammogramMetadata {
     /// Whether this is marked "FOR PROCESSING"
     pub is_for_processing: bool,
 
+    /// PresentationIntentType, Unknown when absent or unrecognized
+    pub presentation_intent: PresentationIntent,
+
     /// Whether breast implant is present
     pub has_implant: bool,
 
@@ -342,6 +508,24 @@ pub struct MammogramMetadata {
     /// Physical pixel spacing in millimeters, when available.
     pub pixel_spacing: Option<PixelSpacing>,
 
+    /// Detector-plane ImagerPixelSpacing in millimeters, when present.
+    pub imager_pixel_spacing: Option<PixelSpacing>,
+
+    /// PhotometricInterpretation (MONOCHROME1, MONOCHROME2, etc.)
+    pub photometric: PhotometricInterpretation,
+
+    /// RescaleSlope for converting stored pixel values, when present
+    pub rescale_slope: Option<f64>,
+
+    /// RescaleIntercept for converting stored pixel values, when present
+    pub rescale_intercept: Option<f64>,
+
+    /// BodyPartThickness: compressed breast thickness in mm, when present
+    pub body_part_thickness_mm: Option<f64>,
+
+    /// CompressionForce in newtons, when present
+    pub compression_force_n: Option<f64>,
+
     /// DICOM ConcatenationUID, when present
     pub concatenation_uid: Option<String>,
 
@@ -351,9 +535,18 @@ pub struct MammogramMetadata {
     /// Whether this is a secondary capture image
     pub is_secondary_capture: bool,
 
+    /// Whether this is a scout/localizer frame (ImageType LOCALIZER or SCOUT)
+    pub is_localizer: bool,
+
+    /// Whether this is a specimen image (specimen SOP class or SeriesDescription)
+    pub is_specimen: bool,
+
     /// DICOM Modality (should be "MG" for mammography)
     pub modality: Option<String>,
 
+    /// DICOM SpecificCharacterSet used to decode text tags, when present
+    pub character_set: Option<String>,
+
     /// DICOM Transfer Syntax UID from file meta information
     pub transfer_syntax_uid: Option<String>,
 
@@ -387,9 +580,32 @@ impl MammogramMetadata {
     }
 
     /// Whether this is a magnification view.
+    ///
+    /// Besides the Magnification view modifier, a spacing magnification factor
+    /// above [`SPACING_MAGNIFICATION_THRESHOLD`] is treated as corroborating
+    /// evidence.
     pub fn is_magnified(&self) -> bool {
         self.view_modifiers
             .contains(&MammographyViewModifier::Magnification)
+            || self
+                .spacing_magnification_factor()
+                .is_some_and(|factor| factor > SPACING_MAGNIFICATION_THRESHOLD)
+    }
+
+    /// Ratio of ImagerPixelSpacing to PixelSpacing, averaged over rows and columns.
+    ///
+    /// Returns `None` unless both spacings are available.
+    pub fn spacing_magnification_factor(&self) -> Option<f64> {
+        let imager = self.imager_pixel_spacing?;
+        let pixel = self.pixel_spacing?;
+        Some((imager.row / pixel.row + imager.col / pixel.col) / 2.0)
+    }
+
+    /// Whether this is a rolled (lateral, medial, inferior, or superior) view.
+    pub fn is_rolled(&self) -> bool {
+        self.view_modifiers
+            .iter()
+            .any(|modifier| modifier.is_rolled())
     }
 
     /// Whether this is an implant displaced view.
@@ -397,6 +613,11 @@ impl MammogramMetadata {
         self.view_modifiers
             .contains(&MammographyViewModifier::ImplantDisplaced)
     }
+
+    /// Whether pixel values must be inverted for display (MONOCHROME1).
+    pub fn needs_inversion(&self) -> bool {
+        self.photometric.is_inverted()
+    }
 }
 
 #[cfg(feature = "json")]
@@ -407,7 +628,7 @@ impl serde::Serialize for MammogramMetadata {
     {
         use serde::ser::SerializeStruct;
 
-        let mut state = serializer.serialize_struct("MammogramMetadata", 22)?;
+        let mut state = serializer.serialize_struct("MammogramMetadata", 34)?;
         state.serialize_field("mammogram_type", &self.mammogram_type)?;
         state.serialize_field("dbt_object_kind", &self.dbt_object_kind)?;
         state.serialize_field("laterality", &self.laterality)?;
@@ -415,21 +636,33 @@ impl serde::Serialize for MammogramMetadata {
         state.serialize_field("view_modifiers", &self.view_modifiers)?;
         state.serialize_field("image_type", &self.image_type)?;
         state.serialize_field("is_for_processing", &self.is_for_processing)?;
+        state.serialize_field("presentation_intent", &self.presentation_intent.to_string())?;
         state.serialize_field("has_implant", &self.has_implant)?;
         state.serialize_field("is_spot_compression", &self.is_spot_compression())?;
         state.serialize_field("is_magnified", &self.is_magnified())?;
         state.serialize_field("is_implant_displaced", &self.is_implant_displaced())?;
+        state.serialize_field("is_rolled", &self.is_rolled())?;
         state.serialize_field("manufacturer", &self.manufacturer)?;
         state.serialize_field("model", &self.model)?;
         state.serialize_field("number_of_frames", &self.number_of_frames)?;
         state.serialize_field("pixel_spacing", &self.pixel_spacing)?;
+        state.serialize_field("imager_pixel_spacing", &self.imager_pixel_spacing)?;
+        state.serialize_field("photometric", &self.photometric.to_string())?;
+        state.serialize_field("needs_inversion", &self.needs_inversion())?;
+        state.serialize_field("rescale_slope", &self.rescale_slope)?;
+        state.serialize_field("rescale_intercept", &self.rescale_intercept)?;
+        state.serialize_field("body_part_thickness_mm", &self.body_part_thickness_mm)?;
+        state.serialize_field("compression_force_n", &self.compression_force_n)?;
         state.serialize_field("concatenation_uid", &self.concatenation_uid)?;
         state.serialize_field(
             "sop_instance_uid_of_concatenation_source",
             &self.sop_instance_uid_of_concatenation_source,
         )?;
         state.serialize_field("is_secondary_capture", &self.is_secondary_capture)?;
+        state.serialize_field("is_localizer", &self.is_localizer)?;
+        state.serialize_field("is_specimen", &self.is_specimen)?;
         state.serialize_field("modality", &self.modality)?;
+        state.serialize_field("character_set", &self.character_set)?;
         state.serialize_field("transfer_syntax_uid", &self.transfer_syntax_uid)?;
         state.serialize_field("transfer_syntax_name", &self.transfer_syntax_name)?;
         state.serialize_field("compression_type", &self.compression_type)?;
@@ -437,6 +670,111 @@ impl serde::Serialize for MammogramMetadata {
     }
 }
 
+/// Wire format read back by the `Deserialize` impl for [`MammogramMetadata`]
+///
+/// Mirrors the serialized field names. Derived fields written by `Serialize`
+/// (e.g. `is_magnified`, `needs_inversion`) are recomputed, so they are ignored.
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+struct MammogramMetadataRepr {
+    mammogram_type: MammogramType,
+    dbt_object_kind: DbtObjectKind,
+    laterality: Laterality,
+    view_position: ViewPosition,
+    #[serde(default)]
+    view_modifiers: std::collections::BTreeSet<MammographyViewModifier>,
+    image_type: ImageType,
+    is_for_processing: bool,
+    #[serde(default)]
+    presentation_intent: Option<String>,
+    has_implant: bool,
+    #[serde(default)]
+    manufacturer: Option<String>,
+    #[serde(default)]
+    model: Option<String>,
+    number_of_frames: i32,
+    #[serde(default)]
+    pixel_spacing: Option<PixelSpacing>,
+    #[serde(default)]
+    imager_pixel_spacing: Option<PixelSpacing>,
+    #[serde(default)]
+    photometric: Option<String>,
+    #[serde(default)]
+    rescale_slope: Option<f64>,
+    #[serde(default)]
+    rescale_intercept: Option<f64>,
+    #[serde(default)]
+    body_part_thickness_mm: Option<f64>,
+    #[serde(default)]
+    compression_force_n: Option<f64>,
+    #[serde(default)]
+    concatenation_uid: Option<String>,
+    #[serde(default)]
+    sop_instance_uid_of_concatenation_source: Option<String>,
+    is_secondary_capture: bool,
+    #[serde(default)]
+    is_localizer: bool,
+    #[serde(default)]
+    is_specimen: bool,
+    #[serde(default)]
+    modality: Option<String>,
+    #[serde(default)]
+    character_set: Option<String>,
+    #[serde(default)]
+    transfer_syntax_uid: Option<String>,
+    #[serde(default)]
+    transfer_syntax_name: Option<String>,
+    #[serde(default)]
+    compression_type: Option<String>,
+}
+
+#[cfg(feature = "json")]
+impl<'de> serde::Deserialize<'de> for MammogramMetadata {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let repr = MammogramMetadataRepr::deserialize(deserializer)?;
+        Ok(Self {
+            mammogram_type: repr.mammogram_type,
+            dbt_object_kind: repr.dbt_object_kind,
+            laterality: repr.laterality,
+            view_position: repr.view_position,
+            view_modifiers: repr.view_modifiers,
+            image_type: repr.image_type,
+            is_for_processing: repr.is_for_processing,
+            presentation_intent: repr
+                .presentation_intent
+                .map(|value| PresentationIntent::from_str(&value))
+                .unwrap_or_default(),
+            has_implant: repr.has_implant,
+            manufacturer: repr.manufacturer,
+            model: repr.model,
+            number_of_frames: repr.number_of_frames,
+            pixel_spacing: repr.pixel_spacing,
+            imager_pixel_spacing: repr.imager_pixel_spacing,
+            photometric: repr
+                .photometric
+                .map(|value| PhotometricInterpretation::from_str(&value))
+                .unwrap_or(PhotometricInterpretation::Unknown),
+            rescale_slope: repr.rescale_slope,
+            rescale_intercept: repr.rescale_intercept,
+            body_part_thickness_mm: repr.body_part_thickness_mm,
+            compression_force_n: repr.compression_force_n,
+            concatenation_uid: repr.concatenation_uid,
+            sop_instance_uid_of_concatenation_source: repr.sop_instance_uid_of_concatenation_source,
+            is_secondary_capture: repr.is_secondary_capture,
+            is_localizer: repr.is_localizer,
+            is_specimen: repr.is_specimen,
+            modality: repr.modality,
+            character_set: repr.character_set,
+            transfer_syntax_uid: repr.transfer_syntax_uid,
+            transfer_syntax_name: repr.transfer_syntax_name,
+            compression_type: repr.compression_type,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -468,6 +806,36 @@ mod tests {
         dcm
     }
 
+    #[test]
+    fn test_extract_with_extra_tags_returns_requested_values() {
+        let acquisition_device = Tag(0x0018, 0x1400);
+        let detector_id = Tag(0x0018, 0x700A);
+        let missing = Tag(0x0018, 0x1405);
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            acquisition_device,
+            VR::LO,
+            PrimitiveValue::from("DEVICE-7 "),
+        ));
+        dcm.put(DataElement::new(
+            detector_id,
+            VR::SH,
+            PrimitiveValue::Strs(vec!["A".to_string(), "B".to_string()].into()),
+        ));
+
+        let (metadata, extra) = MammogramExtractor::extract_with_extra_tags(
+            &dcm,
+            &[acquisition_device, detector_id, missing],
+        )
+        .unwrap();
+
+        assert_eq!(metadata, MammogramExtractor::extract(&dcm).unwrap());
+        assert_eq!(extra.len(), 2);
+        assert_eq!(extra[&acquisition_device], "DEVICE-7");
+        assert_eq!(extra[&detector_id], "A\\B");
+        assert!(!extra.contains_key(&missing));
+    }
+
     #[test]
     fn test_mammogram_metadata_view() {
         let metadata = MammogramMetadata {
@@ -478,15 +846,25 @@ mod tests {
             view_modifiers: Default::default(),
             image_type: ImageType::new("ORIGINAL".to_string(), "PRIMARY".to_string(), None, None),
             is_for_processing: false,
+            presentation_intent: PresentationIntent::Unknown,
             has_implant: false,
             manufacturer: Some("Test Manufacturer".to_string()),
             model: Some("Test Model".to_string()),
             number_of_frames: 1,
             pixel_spacing: None,
+            imager_pixel_spacing: None,
+            photometric: PhotometricInterpretation::Monochrome2,
+            rescale_slope: None,
+            rescale_intercept: None,
+            body_part_thickness_mm: None,
+            compression_force_n: None,
             concatenation_uid: None,
             sop_instance_uid_of_concatenation_source: None,
             is_secondary_capture: false,
+            is_localizer: false,
+            is_specimen: false,
             modality: Some("MG".to_string()),
+            character_set: None,
             transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
             transfer_syntax_name: Some("Explicit VR Little Endian".to_string()),
             compression_type: Some("uncompressed".to_string()),
@@ -509,15 +887,25 @@ mod tests {
             view_modifiers: Default::default(),
             image_type: ImageType::new("DERIVED".to_string(), "PRIMARY".to_string(), None, None),
             is_for_processing: false,
+            presentation_intent: PresentationIntent::Unknown,
             has_implant: false,
             manufacturer: Some("Test Manufacturer".to_string()),
             model: Some("Test Model".to_string()),
             number_of_frames: 50,
             pixel_spacing: Some(PixelSpacing::new(0.07, 0.08)),
+            imager_pixel_spacing: None,
+            photometric: PhotometricInterpretation::Monochrome2,
+            rescale_slope: None,
+            rescale_intercept: None,
+            body_part_thickness_mm: None,
+            compression_force_n: None,
             concatenation_uid: None,
             sop_instance_uid_of_concatenation_source: None,
             is_secondary_capture: false,
+            is_localizer: false,
+            is_specimen: false,
             modality: Some("MG".to_string()),
+            character_set: None,
             transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
             transfer_syntax_name: Some("Explicit VR Little Endian".to_string()),
             compression_type: Some("uncompressed".to_string()),
@@ -576,6 +964,234 @@ mod tests {
         assert_eq!(pixel_spacing.col, 0.091);
     }
 
+    #[test]
+    fn diverging_imager_and_pixel_spacing_flags_magnification() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0028, 0x0030),
+            VR::DS,
+            PrimitiveValue::from("0.039\\0.039"),
+        ));
+        dcm.put(DataElement::new(
+            Tag(0x0018, 0x1164),
+            VR::DS,
+            PrimitiveValue::from("0.070\\0.070"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert!(!metadata
+            .view_modifiers
+            .contains(&MammographyViewModifier::Magnification));
+        assert!(metadata.spacing_magnification_factor().unwrap() > 1.7);
+        assert!(metadata.is_magnified());
+    }
+
+    #[test]
+    fn contact_view_spacing_ratio_does_not_flag_magnification() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0028, 0x0030),
+            VR::DS,
+            PrimitiveValue::from("0.065\\0.065"),
+        ));
+        dcm.put(DataElement::new(
+            Tag(0x0018, 0x1164),
+            VR::DS,
+            PrimitiveValue::from("0.070\\0.070"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert!(!metadata.is_magnified());
+    }
+
+    #[test]
+    fn imager_spacing_alone_does_not_flag_magnification() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0018, 0x1164),
+            VR::DS,
+            PrimitiveValue::from("0.070\\0.070"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert_eq!(metadata.spacing_magnification_factor(), Some(1.0));
+        assert!(!metadata.is_magnified());
+    }
+
+    #[test]
+    fn extracts_monochrome1_photometric_as_needing_inversion() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0028, 0x0004),
+            VR::CS,
+            PrimitiveValue::from("MONOCHROME1"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert_eq!(metadata.photometric, PhotometricInterpretation::Monochrome1);
+        assert!(metadata.needs_inversion());
+    }
+
+    #[test]
+    fn extracts_monochrome2_photometric_without_inversion() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0028, 0x0004),
+            VR::CS,
+            PrimitiveValue::from("MONOCHROME2 "),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert_eq!(metadata.photometric, PhotometricInterpretation::Monochrome2);
+        assert!(!metadata.needs_inversion());
+    }
+
+    #[test]
+    fn extracts_presentation_intent() {
+        let metadata = MammogramExtractor::extract(&minimal_mammo_dicom()).unwrap();
+        assert_eq!(metadata.presentation_intent, PresentationIntent::Unknown);
+
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0068),
+            VR::CS,
+            PrimitiveValue::from("FOR PRESENTATION"),
+        ));
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+        assert_eq!(
+            metadata.presentation_intent,
+            PresentationIntent::ForPresentation
+        );
+        assert!(!metadata.is_for_processing);
+    }
+
+    #[test]
+    fn extracts_identity_rescale_values() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0028, 0x1053),
+            VR::DS,
+            PrimitiveValue::from("1"),
+        ));
+        dcm.put(DataElement::new(
+            Tag(0x0028, 0x1052),
+            VR::DS,
+            PrimitiveValue::from("0"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert_eq!(metadata.rescale_slope, Some(1.0));
+        assert_eq!(metadata.rescale_intercept, Some(0.0));
+    }
+
+    #[test]
+    fn extracts_non_default_rescale_values() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0028, 0x1053),
+            VR::DS,
+            PrimitiveValue::from("0.5 "),
+        ));
+        dcm.put(DataElement::new(
+            Tag(0x0028, 0x1052),
+            VR::DS,
+            PrimitiveValue::from("-1024"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert_eq!(metadata.rescale_slope, Some(0.5));
+        assert_eq!(metadata.rescale_intercept, Some(-1024.0));
+    }
+
+    #[test]
+    fn missing_rescale_values_are_none() {
+        let metadata = MammogramExtractor::extract(&minimal_mammo_dicom()).unwrap();
+
+        assert_eq!(metadata.rescale_slope, None);
+        assert_eq!(metadata.rescale_intercept, None);
+    }
+
+    #[test]
+    fn extracts_thickness_and_compression_force() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            BODY_PART_THICKNESS,
+            VR::DS,
+            PrimitiveValue::from("52.5"),
+        ));
+        dcm.put(DataElement::new(
+            COMPRESSION_FORCE,
+            VR::DS,
+            PrimitiveValue::from("98 "),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert_eq!(metadata.body_part_thickness_mm, Some(52.5));
+        assert_eq!(metadata.compression_force_n, Some(98.0));
+    }
+
+    #[test]
+    fn missing_or_non_numeric_thickness_and_force_are_none() {
+        let metadata = MammogramExtractor::extract(&minimal_mammo_dicom()).unwrap();
+        assert_eq!(metadata.body_part_thickness_mm, None);
+        assert_eq!(metadata.compression_force_n, None);
+
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            BODY_PART_THICKNESS,
+            VR::DS,
+            PrimitiveValue::from("unknown"),
+        ));
+        dcm.put(DataElement::new(
+            COMPRESSION_FORCE,
+            VR::DS,
+            PrimitiveValue::from(""),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert_eq!(metadata.body_part_thickness_mm, None);
+        assert_eq!(metadata.compression_force_n, None);
+    }
+
+    #[test]
+    fn missing_photometric_defaults_to_unknown() {
+        let metadata = MammogramExtractor::extract(&minimal_mammo_dicom()).unwrap();
+
+        assert_eq!(metadata.photometric, PhotometricInterpretation::Unknown);
+        assert!(!metadata.needs_inversion());
+    }
+
+    #[test]
+    fn pixel_spacing_takes_precedence_over_imager_pixel_spacing() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0028, 0x0030),
+            VR::DS,
+            PrimitiveValue::from("0.094\\0.094"),
+        ));
+        dcm.put(DataElement::new(
+            Tag(0x0018, 0x1164),
+            VR::DS,
+            PrimitiveValue::from("0.100\\0.100"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert_eq!(
+            metadata.pixel_spacing,
+            Some(PixelSpacing::new(0.094, 0.094))
+        );
+    }
+
     #[test]
     fn invalid_primary_pixel_spacing_uses_valid_imager_fallback() {
         let mut dcm = minimal_mammo_dicom();
@@ -598,6 +1214,173 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extracts_secondary_capture_from_sop_class() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0016),
+            VR::UI,
+            PrimitiveValue::from("1.2.840.10008.5.1.4.1.1.7"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert!(metadata.is_secondary_capture);
+        assert_eq!(metadata.modality.as_deref(), Some("MG"));
+    }
+
+    #[test]
+    fn extracts_secondary_capture_from_multiframe_sop_class() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0016),
+            VR::UI,
+            PrimitiveValue::from("1.2.840.10008.5.1.4.1.1.7.4"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert!(metadata.is_secondary_capture);
+    }
+
+    #[test]
+    fn digital_mammography_sop_class_is_not_secondary_capture() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0016),
+            VR::UI,
+            PrimitiveValue::from("1.2.840.10008.5.1.4.1.1.1.2"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert!(!metadata.is_secondary_capture);
+        assert!(!metadata.is_localizer);
+        assert!(!metadata.is_specimen);
+    }
+
+    #[test]
+    fn specimen_sop_class_sets_is_specimen() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0016),
+            VR::UI,
+            PrimitiveValue::from("1.2.840.10008.5.1.4.1.1.77.1.2"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert!(metadata.is_specimen);
+        assert!(!metadata.is_localizer);
+    }
+
+    #[test]
+    fn other_modality_is_secondary_capture() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0060),
+            VR::CS,
+            PrimitiveValue::from("OT"),
+        ));
+
+        let metadata = MammogramExtractor::extract_lenient(&dcm);
+
+        assert!(metadata.is_secondary_capture);
+        assert_eq!(metadata.modality.as_deref(), Some("OT"));
+    }
+
+    #[test]
+    fn ignore_modality_uses_sop_class_for_secondary_capture() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0060),
+            VR::CS,
+            PrimitiveValue::from("OT"),
+        ));
+
+        let metadata =
+            MammogramExtractor::extract_with_options_and_modality_policy(&dcm, false, true)
+                .unwrap();
+        assert!(!metadata.is_secondary_capture);
+
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0016),
+            VR::UI,
+            PrimitiveValue::from("1.2.840.10008.5.1.4.1.1.7"),
+        ));
+        let metadata =
+            MammogramExtractor::extract_with_options_and_modality_policy(&dcm, false, true)
+                .unwrap();
+        assert!(metadata.is_secondary_capture);
+    }
+
+    #[test]
+    fn ignore_modality_classifies_mislabeled_mammogram() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0060),
+            VR::CS,
+            PrimitiveValue::from("OT"),
+        ));
+
+        assert!(MammogramExtractor::extract(&dcm).is_err());
+        let metadata =
+            MammogramExtractor::extract_with_options_and_modality_policy(&dcm, false, true)
+                .unwrap();
+
+        assert_eq!(metadata.mammogram_type, MammogramType::Ffdm);
+        assert_eq!(metadata.view_position, ViewPosition::Mlo);
+    }
+
+    #[test]
+    fn extract_lenient_returns_unknown_type_for_ct() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0060),
+            VR::CS,
+            PrimitiveValue::from("CT"),
+        ));
+
+        assert!(MammogramExtractor::extract(&dcm).is_err());
+        let metadata = MammogramExtractor::extract_lenient(&dcm);
+
+        assert_eq!(metadata.mammogram_type, MammogramType::Unknown);
+        assert_eq!(metadata.dbt_object_kind, DbtObjectKind::None);
+        assert_eq!(metadata.modality.as_deref(), Some("CT"));
+        assert_eq!(metadata.laterality, Laterality::Left);
+    }
+
+    #[test]
+    fn extract_lenient_matches_extract_for_mammograms() {
+        let dcm = minimal_mammo_dicom();
+
+        assert_eq!(
+            MammogramExtractor::extract_lenient(&dcm),
+            MammogramExtractor::extract(&dcm).unwrap()
+        );
+    }
+
+    #[test]
+    fn extracts_specific_character_set() {
+        let mut dcm = minimal_mammo_dicom();
+        dcm.put(DataElement::new(
+            Tag(0x0008, 0x0005),
+            VR::CS,
+            PrimitiveValue::from("ISO_IR 100"),
+        ));
+
+        let metadata = MammogramExtractor::extract(&dcm).unwrap();
+
+        assert_eq!(metadata.character_set.as_deref(), Some("ISO_IR 100"));
+    }
+
+    #[test]
+    fn missing_specific_character_set_is_none() {
+        let metadata = MammogramExtractor::extract(&minimal_mammo_dicom()).unwrap();
+
+        assert_eq!(metadata.character_set, None);
+    }
+
     #[cfg(feature = "json")]
     #[test]
     fn test_mammogram_metadata_json_includes_dbt_object_kind() {
@@ -620,15 +1403,25 @@ mod tests {
                 None,
             ),
             is_for_processing: false,
+            presentation_intent: PresentationIntent::Unknown,
             has_implant: false,
             manufacturer: None,
             model: None,
             number_of_frames: 1,
             pixel_spacing: Some(PixelSpacing::new(0.07, 0.08)),
+            imager_pixel_spacing: None,
+            photometric: PhotometricInterpretation::Monochrome2,
+            rescale_slope: None,
+            rescale_intercept: None,
+            body_part_thickness_mm: None,
+            compression_force_n: None,
             concatenation_uid: Some("1.2.826.0.1.100".to_string()),
             sop_instance_uid_of_concatenation_source: Some("1.2.826.0.1.101".to_string()),
             is_secondary_capture: false,
+            is_localizer: false,
+            is_specimen: false,
             modality: Some("MG".to_string()),
+            character_set: None,
             transfer_syntax_uid: None,
             transfer_syntax_name: None,
             compression_type: None,
@@ -645,8 +1438,11 @@ mod tests {
         assert_eq!(value["is_spot_compression"], true);
         assert_eq!(value["is_magnified"], true);
         assert_eq!(value["is_implant_displaced"], true);
+        assert_eq!(value["is_rolled"], false);
         assert_eq!(value["pixel_spacing"]["row"], 0.07);
         assert_eq!(value["pixel_spacing"]["column"], 0.08);
+        assert_eq!(value["photometric"], "MONOCHROME2");
+        assert_eq!(value["needs_inversion"], false);
         assert_eq!(value["concatenation_uid"], "1.2.826.0.1.100");
         assert_eq!(
             value["sop_instance_uid_of_concatenation_source"],
@@ -654,6 +1450,54 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_mammogram_metadata_json_round_trip() {
+        let metadata = MammogramMetadata {
+            mammogram_type: MammogramType::Synth,
+            dbt_object_kind: DbtObjectKind::None,
+            laterality: Laterality::Left,
+            view_position: ViewPosition::Xccl,
+            view_modifiers: [MammographyViewModifier::SpotCompression]
+                .into_iter()
+                .collect(),
+            image_type: ImageType::new(
+                "DERIVED".to_string(),
+                "PRIMARY".to_string(),
+                Some("GENERATED_2D".to_string()),
+                Some(vec!["EXTRA".to_string()]),
+            ),
+            is_for_processing: false,
+            presentation_intent: PresentationIntent::ForPresentation,
+            has_implant: true,
+            manufacturer: Some("Vendor".to_string()),
+            model: Some("Model".to_string()),
+            number_of_frames: 1,
+            pixel_spacing: Some(PixelSpacing::new(0.1, 0.1)),
+            imager_pixel_spacing: Some(PixelSpacing::new(0.15, 0.15)),
+            photometric: PhotometricInterpretation::Monochrome1,
+            rescale_slope: Some(1.0),
+            rescale_intercept: Some(-1024.0),
+            body_part_thickness_mm: None,
+            compression_force_n: None,
+            concatenation_uid: None,
+            sop_instance_uid_of_concatenation_source: None,
+            is_secondary_capture: false,
+            is_localizer: false,
+            is_specimen: false,
+            modality: Some("MG".to_string()),
+            character_set: Some("ISO_IR 100".to_string()),
+            transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
+            transfer_syntax_name: Some("Explicit VR Little Endian".to_string()),
+            compression_type: Some("uncompressed".to_string()),
+        };
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        let restored: MammogramMetadata = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, metadata);
+    }
+
     #[test]
     fn transfer_syntax_metadata_resolves_compression_type() {
         let metadata = resolve_transfer_syntax_metadata("1.2.840.10008.1.2.4.90").unwrap();
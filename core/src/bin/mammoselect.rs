@@ -1,23 +1,31 @@
 use clap::{Parser, ValueEnum};
 use log::{error, info, warn};
+use mammocat_core::cli::naming::{NameTemplate, DEFAULT_NAME_TEMPLATE};
+use mammocat_core::cli::write_output;
+use mammocat_core::error::MammocatError;
 use mammocat_core::{
-    collect_dicom_files, get_preferred_views_filtered_with_study_mode_and_warnings, DbtObjectKind,
-    FilterConfig, MammogramRecord, MammogramType, MammogramView, PreferenceOrder,
+    collect_dicom_files, collect_dicom_files_recursively,
+    get_preferred_views_filtered_with_study_mode_and_warnings, missing_views, ordered_selection,
+    DbtObjectKind, FilterConfig, MammogramRecord, MammogramType, MammogramView, PreferenceOrder,
     PreferredViewSelectionWithWarnings, SelectionWarning, StudySelectionMode, STANDARD_MAMMO_VIEWS,
 };
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
+use std::time::{Duration, Instant};
 
 /// CLI tool for selecting preferred mammogram views from a directory
 #[derive(Parser, Debug)]
 #[command(name = "mammoselect")]
-#[command(about = "Select preferred mammogram views from a directory of DICOM files")]
+#[command(about = "Select preferred mammogram views from a directory or glob of DICOM files")]
 #[command(version)]
 struct Cli {
-    /// Directory containing DICOM files
-    #[arg(value_name = "DIRECTORY")]
+    /// Directory containing DICOM files, or a glob pattern such as 'study*/IMG*.dcm'
+    ///
+    /// Inputs containing '*', '?', or '[' are expanded as glob patterns;
+    /// every matching regular file is treated as a DICOM candidate.
+    #[arg(value_name = "DIRECTORY|GLOB")]
     directory: PathBuf,
 
     /// Output format
@@ -28,10 +36,62 @@ struct Cli {
     #[arg(short, long, default_value = "default")]
     preference: PreferenceOrderArg,
 
+    /// Write the result to this file instead of stdout
+    ///
+    /// Missing parent directories are created.
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Also write a manifest mapping each standard view to its selected path
+    ///
+    /// JSON (`{"left mlo": "...", "right mlo": null, ...}`) by default, or CSV
+    /// with --format csv. With --by-study, entries are keyed by study UID.
+    #[arg(long, value_name = "PATH")]
+    manifest: Option<PathBuf>,
+
+    /// Also place each selected view in this directory as `<view>.dcm` (e.g. lcc.dcm)
+    ///
+    /// Files are symbolic links by default; see --copy. With --by-study, each
+    /// study gets its own subdirectory named by StudyInstanceUID.
+    #[arg(long, value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+
+    /// Symlink selected files into --output-dir (default)
+    #[arg(long, requires = "output_dir", conflicts_with = "copy")]
+    link: bool,
+
+    /// Copy selected files into --output-dir instead of symlinking them
+    #[arg(long, requires = "output_dir")]
+    copy: bool,
+
+    /// Copy each selected view into this directory, named by --name-template
+    ///
+    /// With --by-study, each study gets its own subdirectory.
+    #[arg(long, value_name = "DIR")]
+    copy_to: Option<PathBuf>,
+
+    /// Symlink each selected view into this directory, named by --name-template
+    ///
+    /// With --by-study, each study gets its own subdirectory.
+    #[arg(long, value_name = "DIR")]
+    symlink_to: Option<PathBuf>,
+
+    /// File name template for --copy-to and --symlink-to
+    ///
+    /// Placeholders: {laterality}, {view}, {type}, {manufacturer}, {sop_uid},
+    /// {stem}. Must include {laterality} and {view}, or {sop_uid}. Missing
+    /// values render as "unknown".
+    #[arg(long, value_name = "TEMPLATE", default_value = DEFAULT_NAME_TEMPLATE)]
+    name_template: NameTemplate,
+
     /// Verbose logging
     #[arg(short, long)]
     verbose: bool,
 
+    /// Search subdirectories for DICOM files (symbolic link loops are skipped)
+    #[arg(short, long)]
+    recursive: bool,
+
     /// Allowed mammogram types (comma-separated: ffdm,tomo,synth,sfm)
     #[arg(long, value_delimiter = ',')]
     allowed_types: Option<Vec<MammogramTypeArg>>,
@@ -45,21 +105,56 @@ struct Cli {
     exclude_implants: bool,
 
     /// Only include standard views (CC and MLO)
-    #[arg(long)]
+    #[arg(long, visible_alias = "exclude-non-standard")]
     only_standard_views: bool,
 
-    /// Include FOR PROCESSING views (excluded by default)
+    /// Exclude spot compression and magnification views
+    #[arg(long)]
+    exclude_spot_mag: bool,
+
+    /// Exclude phantom and test scans (PatientName with a PHANTOM or TEST component)
     #[arg(long)]
+    exclude_phantoms: bool,
+
+    /// Exclude records whose PatientName contains any of these substrings
+    /// (comma-separated, case-insensitive)
+    #[arg(long, value_delimiter = ',', value_name = "PATTERN")]
+    exclude_patient_name: Option<Vec<String>>,
+
+    /// Include FOR PROCESSING views (excluded by default)
+    #[arg(long, visible_alias = "keep-for-processing")]
     include_for_processing: bool,
 
     /// Include secondary capture images (excluded by default)
     #[arg(long)]
     include_secondary_capture: bool,
 
+    /// Include scout/localizer frames (excluded by default)
+    #[arg(long)]
+    include_localizers: bool,
+
+    /// Include specimen images (excluded by default)
+    #[arg(long)]
+    include_specimens: bool,
+
     /// Include non-MG modality (excluded by default)
     #[arg(long)]
     include_non_mg: bool,
 
+    /// Classify files even when their Modality tag is not "MG" (implies --include-non-mg)
+    ///
+    /// Useful for research datasets with mislabeled modality tags. Files
+    /// labeled "OT" are only treated as secondary capture when their SOP
+    /// Class says so.
+    #[arg(long)]
+    ignore_modality: bool,
+
+    /// Keep whatever metadata can be read from truncated or corrupt files
+    ///
+    /// Each file's problems are logged as warnings instead of skipping it.
+    #[arg(long)]
+    best_effort: bool,
+
     /// Exclude lossy compressed images
     #[arg(long)]
     exclude_lossy: bool,
@@ -72,9 +167,44 @@ struct Cli {
     #[arg(long)]
     require_common_modality: bool,
 
+    /// Select the study with the most recent StudyDate, even over a more complete older study
+    #[arg(long)]
+    prefer_latest_study: bool,
+
+    /// Read filter settings from a FilterConfig JSON file instead of the
+    /// individual filter flags (missing fields use the library defaults;
+    /// unknown fields are rejected)
+    #[arg(long, value_name = "PATH")]
+    filter_config: Option<PathBuf>,
+
     /// Error if usable records contain multiple studies or missing StudyInstanceUID
     #[arg(long)]
     strict: bool,
+
+    /// Select views independently for each StudyInstanceUID
+    ///
+    /// Records without a StudyInstanceUID are grouped under "unknown". Text,
+    /// JSON, and paths output show one section per study.
+    #[arg(long)]
+    by_study: bool,
+
+    /// Exit with an error if any standard view (LMLO, RMLO, LCC, RCC) is missing
+    #[arg(long)]
+    require_complete: bool,
+
+    /// View label style for the text report
+    #[arg(long, default_value = "compact")]
+    view_labels: ViewLabelStyle,
+
+    /// Number of worker threads for parsing files (requires 'parallel' feature)
+    ///
+    /// Defaults to one thread per CPU when built with the 'parallel' feature.
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+
+    /// Print per-file parse durations and min/max/mean to stderr
+    #[arg(long)]
+    timings: bool,
 }
 
 /// Output format options
@@ -84,8 +214,28 @@ enum OutputFormat {
     Text,
     /// JSON format
     Json,
+    /// Newline-delimited JSON, one compact object per selected view
+    Ndjson,
+    /// YAML mapping of views to selected records
+    Yaml,
     /// File paths only (one per line)
     Paths,
+    /// CSV with one row per standard view
+    Csv,
+    /// Graphviz DOT graph linking study, views, and selected files
+    Dot,
+    /// GitHub-flavored Markdown table for tickets and wikis
+    Markdown,
+}
+
+/// View label style for the text report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum ViewLabelStyle {
+    /// Compact lowercase codes such as "lmlo"
+    #[default]
+    Compact,
+    /// Clinical abbreviations such as "L MLO"
+    Clinical,
 }
 
 /// Preference ordering for mammogram type selection
@@ -117,6 +267,8 @@ enum MammogramTypeArg {
     Synth,
     /// Screen-film mammography
     Sfm,
+    /// Contrast-enhanced mammography
+    Cem,
 }
 
 impl From<MammogramTypeArg> for MammogramType {
@@ -126,6 +278,7 @@ impl From<MammogramTypeArg> for MammogramType {
             MammogramTypeArg::Tomo => MammogramType::Tomo,
             MammogramTypeArg::Synth => MammogramType::Synth,
             MammogramTypeArg::Sfm => MammogramType::Sfm,
+            MammogramTypeArg::Cem => MammogramType::Cem,
         }
     }
 }
@@ -154,66 +307,103 @@ impl From<DbtObjectKindArg> for DbtObjectKind {
     }
 }
 
+/// Exit code for successful selection.
+const EXIT_SUCCESS: i32 = 0;
+/// Exit code for runtime failures such as unreadable directories or selection errors.
+const EXIT_FAILURE: i32 = 1;
+/// Exit code when the input directory contains no DICOM candidates.
+const EXIT_NO_DICOM_FILES: i32 = 2;
+/// Exit code when no DICOM candidate could be read as a mammogram record.
+const EXIT_NO_VALID_RECORDS: i32 = 3;
+/// Exit code when `--require-complete` is set and a standard view is missing.
+const EXIT_INCOMPLETE_STUDY: i32 = 4;
+
 fn main() {
     let cli = Cli::parse();
 
     // Setup logging
     setup_logging(cli.verbose);
 
+    process::exit(run(&cli));
+}
+
+/// Runs preferred-view selection and returns the process exit code.
+fn run(cli: &Cli) -> i32 {
+    let glob_input = is_glob_pattern(&cli.directory);
+
     // Verify directory exists
-    if !cli.directory.is_dir() {
+    if !glob_input && !cli.directory.is_dir() {
         eprintln!("Error: {} is not a directory", cli.directory.display());
-        process::exit(1);
+        return EXIT_FAILURE;
     }
 
-    info!("Processing directory: {}", cli.directory.display());
+    if glob_input {
+        info!("Expanding glob pattern: {}", cli.directory.display());
+    } else {
+        info!("Processing directory: {}", cli.directory.display());
+    }
 
     let preference_order: PreferenceOrder = cli.preference.into();
 
+    // Build filter configuration before reading any files so a bad config fails fast
+    let filter_config = match load_filter_config(cli) {
+        Ok(config) => config,
+        Err(e) => {
+            error!("{}", e);
+            eprintln!("Error: {}", e);
+            return EXIT_FAILURE;
+        }
+    };
+    info!("Filter config: {:?}", filter_config);
+
     // Collect all .dcm files
-    let dicom_files = match collect_dicom_files(&cli.directory) {
+    let dicom_files = match collect_input_files(cli) {
         Ok(files) => files,
         Err(e) => {
             error!("Failed to read directory: {}", e);
             eprintln!("Error: Failed to read directory: {}", e);
-            process::exit(1);
+            return EXIT_FAILURE;
         }
     };
 
     if dicom_files.is_empty() {
-        eprintln!("Error: No DICOM files (.dcm) found in directory");
-        process::exit(1);
+        if glob_input {
+            eprintln!("Error: No files matched the glob pattern");
+        } else {
+            eprintln!("Error: No DICOM files (.dcm) found in directory");
+        }
+        return EXIT_NO_DICOM_FILES;
     }
 
     info!("Found {} DICOM files", dicom_files.len());
 
     // Create records from files
-    let mut records = Vec::new();
-    for file_path in dicom_files {
-        match MammogramRecord::from_file(file_path.clone()) {
-            Ok(record) => {
-                info!("Processed: {}", file_path.display());
-                records.push(record);
-            }
+    let (records, timings) =
+        match load_records_with_jobs(dicom_files, cli.jobs, ReadOptions::from_cli(cli)) {
+            Ok(loaded) => loaded,
             Err(e) => {
-                warn!("Skipping {}: {}", file_path.display(), e);
+                error!("Failed to start worker threads: {}", e);
+                eprintln!("Error: Failed to start worker threads: {}", e);
+                return EXIT_FAILURE;
             }
-        }
+        };
+    if cli.timings {
+        output_timings(&timings);
     }
 
     if records.is_empty() {
         eprintln!("Error: No valid mammogram files could be processed");
-        process::exit(1);
+        return EXIT_NO_VALID_RECORDS;
     }
 
     info!("Successfully processed {} files", records.len());
 
-    // Build filter configuration
-    let filter_config = build_filter_config(&cli);
-    info!("Filter config: {:?}", filter_config);
-
     info!("Using preference order: {:?}", preference_order);
 
+    if cli.by_study {
+        return run_by_study(cli, &records, &filter_config, preference_order);
+    }
+
     // Select preferred views with filtering
     let (selections, warnings) =
         match select_preferred_views(&records, &filter_config, preference_order, cli.strict) {
@@ -221,14 +411,538 @@ fn main() {
             Err(e) => {
                 error!("Selection failed: {}", e);
                 eprintln!("Error: {}", e);
-                process::exit(1);
+                return EXIT_FAILURE;
             }
         };
     output_selection_warnings(&warnings);
     output_selected_lossy_warnings(&selections, &filter_config);
 
+    if cli.require_complete {
+        let exit_code = check_complete_selection(&selections);
+        if exit_code != EXIT_SUCCESS {
+            return exit_code;
+        }
+    }
+
+    for export in ViewExport::from_cli(cli) {
+        let exit_code = export_view_files(&selections, &export.dir, export.mode, &export.naming);
+        if exit_code != EXIT_SUCCESS {
+            return exit_code;
+        }
+    }
+
+    if let Some(manifest) = &cli.manifest {
+        let exit_code = write_manifest(format_manifest(&selections, &cli.format), manifest);
+        if exit_code != EXIT_SUCCESS {
+            return exit_code;
+        }
+    }
+
     // Output results
-    output_selections(&selections, cli.format);
+    output_selections(
+        &selections,
+        &cli.format,
+        cli.view_labels,
+        cli.output.as_deref(),
+    )
+}
+
+/// Runs selection separately per study and writes one section per study.
+fn run_by_study(
+    cli: &Cli,
+    records: &[MammogramRecord],
+    filter_config: &FilterConfig,
+    preference_order: PreferenceOrder,
+) -> i32 {
+    let studies =
+        match select_preferred_views_by_study(records, filter_config, preference_order, cli.strict)
+        {
+            Ok(studies) => studies,
+            Err(e) => {
+                error!("Selection failed: {}", e);
+                eprintln!("Error: {}", e);
+                return EXIT_FAILURE;
+            }
+        };
+    for (study_uid, selections) in &studies {
+        info!("Selected views for study {}", study_uid);
+        output_selected_lossy_warnings(selections, filter_config);
+    }
+
+    if cli.require_complete {
+        for (study_uid, selections) in &studies {
+            if !missing_views(selections).is_empty() {
+                eprintln!("Error: Study {} is incomplete", study_uid);
+                return check_complete_selection(selections);
+            }
+        }
+    }
+
+    for export in ViewExport::from_cli(cli) {
+        for (study_uid, selections) in &studies {
+            let study_dir = match study_dir_name(study_uid) {
+                Ok(name) => export.dir.join(name),
+                Err(e) => {
+                    error!("Refusing to export study {:?}: {}", study_uid, e);
+                    eprintln!("Error: Refusing to export study {:?}: {}", study_uid, e);
+                    return EXIT_FAILURE;
+                }
+            };
+            let exit_code = export_view_files(selections, &study_dir, export.mode, &export.naming);
+            if exit_code != EXIT_SUCCESS {
+                return exit_code;
+            }
+        }
+    }
+
+    if let Some(manifest) = &cli.manifest {
+        let exit_code = write_manifest(format_study_manifest(&studies, &cli.format), manifest);
+        if exit_code != EXIT_SUCCESS {
+            return exit_code;
+        }
+    }
+
+    output_study_selections(
+        &studies,
+        &cli.format,
+        cli.view_labels,
+        cli.output.as_deref(),
+    )
+}
+
+/// Returns `study_uid` when it is safe to use as a single directory name
+///
+/// StudyInstanceUID comes straight from the DICOM header, so values with path
+/// separators or `.`/`..` components are rejected rather than allowed to
+/// escape the export directory.
+fn study_dir_name(study_uid: &str) -> std::io::Result<&str> {
+    let is_single_component = !study_uid.is_empty()
+        && study_uid != "."
+        && study_uid != ".."
+        && !study_uid.contains(['/', '\\'])
+        && Path::new(study_uid).components().count() == 1;
+    if is_single_component {
+        Ok(study_uid)
+    } else {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "StudyInstanceUID is not a valid directory name",
+        ))
+    }
+}
+
+/// How `--output-dir` materializes each selected view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportMode {
+    /// Symbolic link to the selected file
+    Link,
+    /// Byte-for-byte copy of the selected file
+    Copy,
+}
+
+impl ExportMode {
+    fn from_cli(cli: &Cli) -> Self {
+        if cli.copy {
+            Self::Copy
+        } else {
+            Self::Link
+        }
+    }
+}
+
+/// File names given to exported views
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ViewFileNaming {
+    /// `<view>.dcm`, e.g. `lcc.dcm` (--output-dir)
+    Compact,
+    /// Rendered from --name-template (--copy-to, --symlink-to)
+    Template(NameTemplate),
+}
+
+impl ViewFileNaming {
+    fn file_name(&self, view: &MammogramView, record: &MammogramRecord) -> String {
+        match self {
+            Self::Compact => format!("{view}.dcm"),
+            Self::Template(template) => template.render(view, record),
+        }
+    }
+}
+
+/// A destination directory requested on the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ViewExport {
+    dir: PathBuf,
+    mode: ExportMode,
+    naming: ViewFileNaming,
+}
+
+impl ViewExport {
+    /// Collects --output-dir, --copy-to, and --symlink-to destinations
+    fn from_cli(cli: &Cli) -> Vec<Self> {
+        let mut exports = Vec::new();
+        if let Some(dir) = &cli.output_dir {
+            exports.push(Self {
+                dir: dir.clone(),
+                mode: ExportMode::from_cli(cli),
+                naming: ViewFileNaming::Compact,
+            });
+        }
+        if let Some(dir) = &cli.copy_to {
+            exports.push(Self {
+                dir: dir.clone(),
+                mode: ExportMode::Copy,
+                naming: ViewFileNaming::Template(cli.name_template.clone()),
+            });
+        }
+        if let Some(dir) = &cli.symlink_to {
+            exports.push(Self {
+                dir: dir.clone(),
+                mode: ExportMode::Link,
+                naming: ViewFileNaming::Template(cli.name_template.clone()),
+            });
+        }
+        exports
+    }
+}
+
+/// Places each selected view in `output_dir` and maps failures to an exit code.
+fn export_view_files(
+    selections: &HashMap<MammogramView, Option<MammogramRecord>>,
+    output_dir: &Path,
+    mode: ExportMode,
+    naming: &ViewFileNaming,
+) -> i32 {
+    match write_view_files(selections, output_dir, mode, naming) {
+        Ok(written) => {
+            info!(
+                "Wrote {} view files to {}",
+                written.len(),
+                output_dir.display()
+            );
+            EXIT_SUCCESS
+        }
+        Err(e) => {
+            error!("Failed to write view files: {}", e);
+            eprintln!(
+                "Error: Failed to write view files to {}: {}",
+                output_dir.display(),
+                e
+            );
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// Links or copies each selected view into `output_dir` named by `naming`
+///
+/// Views without a selection are skipped, and nothing is written when two
+/// views would share a file name. The directory is created when missing and
+/// existing entries with the same name are replaced, unless the entry is the
+/// selected source file itself, which is left in place. Symlinks point at the
+/// canonical source path so they resolve from any directory.
+///
+/// # Returns
+///
+/// Paths written, in standard view order
+fn write_view_files(
+    selections: &HashMap<MammogramView, Option<MammogramRecord>>,
+    output_dir: &Path,
+    mode: ExportMode,
+    naming: &ViewFileNaming,
+) -> std::io::Result<Vec<PathBuf>> {
+    let planned: Vec<_> = STANDARD_MAMMO_VIEWS
+        .iter()
+        .filter_map(|view| match selections.get(view) {
+            Some(Some(record)) => Some((output_dir.join(naming.file_name(view, record)), record)),
+            _ => None,
+        })
+        .collect();
+    let mut destinations = HashSet::new();
+    for (destination, _) in &planned {
+        if !destinations.insert(destination) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "more than one view would be written to {}",
+                    destination.display()
+                ),
+            ));
+        }
+    }
+
+    std::fs::create_dir_all(output_dir)?;
+
+    let mut written = Vec::new();
+    for (destination, record) in planned {
+        if is_source_file(&record.file_path, &destination)? {
+            written.push(destination);
+            continue;
+        }
+        if destination.symlink_metadata().is_ok() {
+            std::fs::remove_file(&destination)?;
+        }
+        match mode {
+            ExportMode::Link => symlink_file(&record.file_path.canonicalize()?, &destination)?,
+            ExportMode::Copy => {
+                std::fs::copy(&record.file_path, &destination)?;
+            }
+        }
+        written.push(destination);
+    }
+    Ok(written)
+}
+
+/// Returns true when `destination` names the same directory entry as `source`
+///
+/// Only the parent directory is resolved, so an existing symlink at
+/// `destination` is not mistaken for its target.
+fn is_source_file(source: &Path, destination: &Path) -> std::io::Result<bool> {
+    let (Some(parent), Some(name)) = (destination.parent(), destination.file_name()) else {
+        return Ok(false);
+    };
+    let parent = if parent.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        parent
+    };
+    Ok(parent.canonicalize()?.join(name) == source.canonicalize()?)
+}
+
+#[cfg(unix)]
+fn symlink_file(source: &Path, destination: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(source, destination)
+}
+
+#[cfg(windows)]
+fn symlink_file(source: &Path, destination: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_file(source, destination)
+}
+
+/// Collects DICOM candidates from the input directory or glob pattern.
+///
+/// Directories are scanned for DICOM files, recursing when requested. Glob
+/// patterns are expanded directly, so `--recursive` has no effect on them.
+fn collect_input_files(cli: &Cli) -> std::io::Result<Vec<PathBuf>> {
+    if is_glob_pattern(&cli.directory) {
+        expand_glob_pattern(&cli.directory)
+    } else if cli.recursive {
+        collect_dicom_files_recursively(&cli.directory)
+    } else {
+        collect_dicom_files(&cli.directory)
+    }
+}
+
+/// Returns true when the input contains glob metacharacters ('*', '?', '[').
+fn is_glob_pattern(input: &Path) -> bool {
+    input.to_string_lossy().contains(['*', '?', '['])
+}
+
+/// Expands a glob pattern into a sorted list of matching regular files.
+fn expand_glob_pattern(pattern: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let pattern = pattern.to_string_lossy();
+    let entries = glob::glob(&pattern)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(glob::GlobError::into_error)?;
+        if path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Parse duration for a single DICOM candidate
+struct FileTiming {
+    path: PathBuf,
+    duration: Duration,
+}
+
+/// Aggregate parse durations across a directory
+#[derive(Debug, PartialEq, Eq)]
+struct TimingSummary {
+    min: Duration,
+    max: Duration,
+    mean: Duration,
+}
+
+/// How each DICOM candidate is read into a record
+#[derive(Debug, Clone, Copy, Default)]
+struct ReadOptions {
+    ignore_modality: bool,
+    best_effort: bool,
+}
+
+impl ReadOptions {
+    fn from_cli(cli: &Cli) -> Self {
+        Self {
+            ignore_modality: cli.ignore_modality,
+            best_effort: cli.best_effort,
+        }
+    }
+}
+
+/// Outcome of parsing a single DICOM candidate, with best-effort warnings
+type ParsedFile = (
+    PathBuf,
+    mammocat_core::error::Result<MammogramRecord>,
+    Vec<String>,
+    Duration,
+);
+
+/// Reads records from DICOM candidates, timing each parse attempt.
+///
+/// Files that fail to parse are skipped but still timed, since slow failures
+/// are as relevant for performance tuning as slow successes.
+fn load_records(
+    dicom_files: Vec<PathBuf>,
+    options: ReadOptions,
+) -> (Vec<MammogramRecord>, Vec<FileTiming>) {
+    collect_parsed_files(
+        dicom_files
+            .into_iter()
+            .map(|file_path| parse_file(file_path, options))
+            .collect(),
+    )
+}
+
+/// Reads records using `jobs` worker threads when the 'parallel' feature is enabled.
+///
+/// Results are gathered in input order, so selection output does not depend
+/// on the thread count.
+#[cfg(feature = "parallel")]
+fn load_records_with_jobs(
+    dicom_files: Vec<PathBuf>,
+    jobs: Option<u16>,
+    options: ReadOptions,
+) -> Result<(Vec<MammogramRecord>, Vec<FileTiming>), rayon::ThreadPoolBuildError> {
+    use rayon::prelude::*;
+
+    if jobs == Some(1) {
+        return Ok(load_records(dicom_files, options));
+    }
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.map_or(0, usize::from))
+        .build()?;
+    let parsed = pool.install(|| {
+        dicom_files
+            .into_par_iter()
+            .map(|file_path| parse_file(file_path, options))
+            .collect()
+    });
+    Ok(collect_parsed_files(parsed))
+}
+
+/// Reads records serially; `--jobs` needs the 'parallel' feature.
+#[cfg(not(feature = "parallel"))]
+fn load_records_with_jobs(
+    dicom_files: Vec<PathBuf>,
+    jobs: Option<u16>,
+    options: ReadOptions,
+) -> Result<(Vec<MammogramRecord>, Vec<FileTiming>), std::convert::Infallible> {
+    if jobs.is_some_and(|jobs| jobs > 1) {
+        warn!("--jobs requires the 'parallel' feature; processing files serially");
+    }
+    Ok(load_records(dicom_files, options))
+}
+
+fn parse_file(file_path: PathBuf, options: ReadOptions) -> ParsedFile {
+    let start = Instant::now();
+    if !options.best_effort {
+        let result = MammogramRecord::from_image_file(file_path.clone(), options.ignore_modality);
+        return (file_path, result, Vec::new(), start.elapsed());
+    }
+
+    let (record, mut warnings) = MammogramRecord::from_file_best_effort_with_modality_policy(
+        file_path.clone(),
+        options.ignore_modality,
+    );
+    let result = record.ok_or_else(|| MammocatError::ExtractionError(warnings.join("; ")));
+    if result.is_err() {
+        warnings.clear();
+    }
+    (file_path, result, warnings, start.elapsed())
+}
+
+/// Logs parse outcomes in input order and splits them into records and timings.
+fn collect_parsed_files(parsed: Vec<ParsedFile>) -> (Vec<MammogramRecord>, Vec<FileTiming>) {
+    let mut records = Vec::new();
+    let mut timings = Vec::with_capacity(parsed.len());
+    for (file_path, result, warnings, duration) in parsed {
+        for warning in warnings {
+            warn!("Partially read {}: {}", file_path.display(), warning);
+        }
+        let result = result.and_then(|record| {
+            if record.sop_class.is_non_image() {
+                Err(MammocatError::NonImageSopClass(record.sop_class))
+            } else {
+                Ok(record)
+            }
+        });
+        match result {
+            Ok(record) => {
+                info!("Processed: {}", file_path.display());
+                records.push(record);
+            }
+            Err(MammocatError::NonImageSopClass(sop_class)) => {
+                info!(
+                    "Skipping non-image {} object: {}",
+                    sop_class,
+                    file_path.display()
+                );
+            }
+            Err(e) => {
+                warn!("Skipping {}: {}", file_path.display(), e);
+            }
+        }
+        timings.push(FileTiming {
+            path: file_path,
+            duration,
+        });
+    }
+    (records, timings)
+}
+
+fn summarize_timings(timings: &[FileTiming]) -> Option<TimingSummary> {
+    let min = timings.iter().map(|timing| timing.duration).min()?;
+    let max = timings.iter().map(|timing| timing.duration).max()?;
+    let total: Duration = timings.iter().map(|timing| timing.duration).sum();
+    let mean = total / u32::try_from(timings.len()).unwrap_or(u32::MAX);
+    Some(TimingSummary { min, max, mean })
+}
+
+/// Prints parse timings to stderr, slowest files first.
+fn output_timings(timings: &[FileTiming]) {
+    let mut sorted: Vec<&FileTiming> = timings.iter().collect();
+    sorted.sort_by(|a, b| {
+        b.duration
+            .cmp(&a.duration)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    eprintln!("Parse timings ({} files):", timings.len());
+    for timing in sorted {
+        eprintln!(
+            "  {:>10.3} ms  {}",
+            duration_ms(timing.duration),
+            timing.path.display()
+        );
+    }
+    if let Some(summary) = summarize_timings(timings) {
+        eprintln!(
+            "  min {:.3} ms, max {:.3} ms, mean {:.3} ms",
+            duration_ms(summary.min),
+            duration_ms(summary.max),
+            duration_ms(summary.mean)
+        );
+    }
+}
+
+fn duration_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
 }
 
 fn setup_logging(verbose: bool) {
@@ -243,6 +957,27 @@ fn setup_logging(verbose: bool) {
     }
 }
 
+/// Returns the FilterConfig from `--filter-config` when given, otherwise from the filter flags
+fn load_filter_config(cli: &Cli) -> Result<FilterConfig, String> {
+    let Some(path) = &cli.filter_config else {
+        return Ok(build_filter_config(cli));
+    };
+    #[cfg(feature = "json")]
+    {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read filter config {}: {}", path.display(), e))?;
+        serde_json::from_str(&text)
+            .map_err(|e| format!("Invalid filter config {}: {}", path.display(), e))
+    }
+    #[cfg(not(feature = "json"))]
+    {
+        Err(format!(
+            "--filter-config {} requires the 'json' feature; rebuild with: cargo build --features json",
+            path.display()
+        ))
+    }
+}
+
 /// Builds FilterConfig from CLI arguments
 fn build_filter_config(cli: &Cli) -> FilterConfig {
     let mut config = FilterConfig::default();
@@ -267,14 +1002,24 @@ fn build_filter_config(cli: &Cli) -> FilterConfig {
     // Handle exclude flags
     config = config.exclude_implants(cli.exclude_implants);
     config = config.exclude_non_standard_views(cli.only_standard_views);
+    config = config.exclude_spot_mag(cli.exclude_spot_mag);
+    if let Some(patterns) = &cli.exclude_patient_name {
+        config = config.with_excluded_patient_name_patterns(patterns.clone());
+    }
+    if cli.exclude_phantoms {
+        config = config.exclude_phantoms();
+    }
 
     // Handle include flags (inverted logic)
     config = config.exclude_for_processing(!cli.include_for_processing);
     config = config.exclude_secondary_capture(!cli.include_secondary_capture);
-    config = config.exclude_non_mg_modality(!cli.include_non_mg);
+    config = config.exclude_localizers(!cli.include_localizers);
+    config = config.exclude_specimens(!cli.include_specimens);
+    config = config.exclude_non_mg_modality(!(cli.include_non_mg || cli.ignore_modality));
     config = config.exclude_lossy_compressed(cli.exclude_lossy);
     config = config.deprioritize_lossy_compressed(!cli.no_deprioritize_lossy);
     config = config.require_common_modality(cli.require_common_modality);
+    config = config.prefer_latest_study(cli.prefer_latest_study);
 
     config
 }
@@ -314,6 +1059,21 @@ fn selected_lossy_warning_messages(
         .collect()
 }
 
+/// Reports missing standard views and returns the exit code for `--require-complete`.
+fn check_complete_selection(selections: &HashMap<MammogramView, Option<MammogramRecord>>) -> i32 {
+    let missing = missing_views(selections);
+    if missing.is_empty() {
+        return EXIT_SUCCESS;
+    }
+
+    let missing_names: Vec<String> = missing.iter().map(ToString::to_string).collect();
+    eprintln!(
+        "Error: Incomplete study, missing views: {}",
+        missing_names.join(", ")
+    );
+    EXIT_INCOMPLETE_STUDY
+}
+
 fn select_preferred_views(
     records: &[MammogramRecord],
     filter_config: &FilterConfig,
@@ -328,33 +1088,80 @@ fn select_preferred_views(
     )
 }
 
+/// Study key for records without a StudyInstanceUID
+const UNKNOWN_STUDY_KEY: &str = "unknown";
+
+/// Preferred view selections keyed by StudyInstanceUID
+type StudySelections = BTreeMap<String, HashMap<MammogramView, Option<MammogramRecord>>>;
+
+/// Groups records by StudyInstanceUID, using [`UNKNOWN_STUDY_KEY`] when missing.
+fn group_records_by_study(records: &[MammogramRecord]) -> BTreeMap<String, Vec<MammogramRecord>> {
+    let mut groups: BTreeMap<String, Vec<MammogramRecord>> = BTreeMap::new();
+    for record in records {
+        let study_uid = record
+            .study_instance_uid
+            .as_deref()
+            .map(str::trim)
+            .filter(|uid| !uid.is_empty())
+            .unwrap_or(UNKNOWN_STUDY_KEY);
+        groups
+            .entry(study_uid.to_string())
+            .or_default()
+            .push(record.clone());
+    }
+    groups
+}
+
+/// Selects preferred views independently within each study.
+fn select_preferred_views_by_study(
+    records: &[MammogramRecord],
+    filter_config: &FilterConfig,
+    preference_order: PreferenceOrder,
+    strict: bool,
+) -> mammocat_core::Result<StudySelections> {
+    group_records_by_study(records)
+        .into_iter()
+        .map(|(study_uid, study_records)| {
+            let (selections, warnings) =
+                select_preferred_views(&study_records, filter_config, preference_order, strict)?;
+            output_selection_warnings(&warnings);
+            Ok((study_uid, selections))
+        })
+        .collect()
+}
+
 fn output_selection_warnings(warnings: &[SelectionWarning]) {
     for warning in warnings {
         warn!("{}", warning.message());
     }
 }
 
+/// Formats selections and writes them to `output`, or stdout when None.
 fn output_selections(
     selections: &HashMap<MammogramView, Option<MammogramRecord>>,
-    format: OutputFormat,
-) {
-    match format {
+    format: &OutputFormat,
+    view_labels: ViewLabelStyle,
+    output: Option<&Path>,
+) -> i32 {
+    let content = match format {
         OutputFormat::Text => {
-            let report = TextReport::new(selections);
-            println!("{}", report);
-        }
-        OutputFormat::Paths => {
-            output_paths(selections);
+            let report = TextReport::new(selections).with_view_labels(view_labels);
+            format!("{}\n", report)
         }
+        OutputFormat::Markdown => MarkdownReport::new(selections)
+            .with_view_labels(view_labels)
+            .to_string(),
+        OutputFormat::Paths => output_paths(selections),
+        OutputFormat::Dot => output_dot(selections),
         OutputFormat::Json => {
             #[cfg(feature = "json")]
             {
                 match output_json(selections) {
-                    Ok(json) => println!("{}", json),
+                    Ok(json) => format!("{}\n", json),
                     Err(e) => {
                         error!("Failed to serialize to JSON: {}", e);
                         eprintln!("Error: Failed to serialize to JSON: {}", e);
-                        process::exit(1);
+                        return EXIT_FAILURE;
                     }
                 }
             }
@@ -362,76 +1169,560 @@ fn output_selections(
             {
                 eprintln!("Error: JSON output requires the 'json' feature");
                 eprintln!("Rebuild with: cargo build --features json");
-                process::exit(1);
+                return EXIT_FAILURE;
             }
         }
-    }
-}
-
-fn output_paths(selections: &HashMap<MammogramView, Option<MammogramRecord>>) {
-    for view in &STANDARD_MAMMO_VIEWS {
-        if let Some(Some(record)) = selections.get(view) {
-            println!("{}", record.file_path.display());
+        OutputFormat::Ndjson => {
+            #[cfg(feature = "json")]
+            {
+                match output_ndjson(selections, None) {
+                    Ok(ndjson) => ndjson,
+                    Err(e) => {
+                        error!("Failed to serialize to NDJSON: {}", e);
+                        eprintln!("Error: Failed to serialize to NDJSON: {}", e);
+                        return EXIT_FAILURE;
+                    }
+                }
+            }
+            #[cfg(not(feature = "json"))]
+            {
+                eprintln!("Error: NDJSON output requires the 'json' feature");
+                eprintln!("Rebuild with: cargo build --features json");
+                return EXIT_FAILURE;
+            }
         }
-    }
-}
-
-#[cfg(feature = "json")]
+        OutputFormat::Yaml => {
+            #[cfg(feature = "yaml")]
+            {
+                match serde_yaml::to_string(&selection_json(selections)) {
+                    Ok(yaml) => yaml,
+                    Err(e) => {
+                        error!("Failed to serialize to YAML: {}", e);
+                        eprintln!("Error: Failed to serialize to YAML: {}", e);
+                        return EXIT_FAILURE;
+                    }
+                }
+            }
+            #[cfg(not(feature = "yaml"))]
+            {
+                eprintln!("Error: YAML output requires the 'yaml' feature");
+                eprintln!("Rebuild with: cargo build --features yaml");
+                return EXIT_FAILURE;
+            }
+        }
+        OutputFormat::Csv => {
+            #[cfg(feature = "csv")]
+            {
+                output_csv(selections)
+            }
+            #[cfg(not(feature = "csv"))]
+            {
+                eprintln!("Error: CSV output requires the 'csv' feature");
+                eprintln!("Rebuild with: cargo build --features csv");
+                return EXIT_FAILURE;
+            }
+        }
+    };
+
+    write_formatted_output(&content, output)
+}
+
+/// Formats per-study selections and writes them to `output`, or stdout when None.
+fn output_study_selections(
+    studies: &StudySelections,
+    format: &OutputFormat,
+    view_labels: ViewLabelStyle,
+    output: Option<&Path>,
+) -> i32 {
+    let content: String = match format {
+        OutputFormat::Text => studies
+            .iter()
+            .map(|(study_uid, selections)| {
+                let report = TextReport::new(selections).with_view_labels(view_labels);
+                format!("Study: {}\n\n{}", study_uid, report)
+            })
+            .collect(),
+        OutputFormat::Markdown => studies
+            .iter()
+            .map(|(study_uid, selections)| {
+                let report = MarkdownReport::new(selections)
+                    .with_view_labels(view_labels)
+                    .with_heading(format!("Study {}", study_uid));
+                report.to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Paths => studies.values().map(output_paths).collect(),
+        OutputFormat::Json => {
+            #[cfg(feature = "json")]
+            {
+                match output_study_json(studies) {
+                    Ok(json) => format!("{}\n", json),
+                    Err(e) => {
+                        error!("Failed to serialize to JSON: {}", e);
+                        eprintln!("Error: Failed to serialize to JSON: {}", e);
+                        return EXIT_FAILURE;
+                    }
+                }
+            }
+            #[cfg(not(feature = "json"))]
+            {
+                eprintln!("Error: JSON output requires the 'json' feature");
+                eprintln!("Rebuild with: cargo build --features json");
+                return EXIT_FAILURE;
+            }
+        }
+        OutputFormat::Ndjson => {
+            #[cfg(feature = "json")]
+            {
+                let lines: Result<String, serde_json::Error> = studies
+                    .iter()
+                    .map(|(study_uid, selections)| output_ndjson(selections, Some(study_uid)))
+                    .collect();
+                match lines {
+                    Ok(ndjson) => ndjson,
+                    Err(e) => {
+                        error!("Failed to serialize to NDJSON: {}", e);
+                        eprintln!("Error: Failed to serialize to NDJSON: {}", e);
+                        return EXIT_FAILURE;
+                    }
+                }
+            }
+            #[cfg(not(feature = "json"))]
+            {
+                eprintln!("Error: NDJSON output requires the 'json' feature");
+                eprintln!("Rebuild with: cargo build --features json");
+                return EXIT_FAILURE;
+            }
+        }
+        OutputFormat::Yaml => {
+            #[cfg(feature = "yaml")]
+            {
+                match serde_yaml::to_string(&study_selections_json(studies)) {
+                    Ok(yaml) => yaml,
+                    Err(e) => {
+                        error!("Failed to serialize to YAML: {}", e);
+                        eprintln!("Error: Failed to serialize to YAML: {}", e);
+                        return EXIT_FAILURE;
+                    }
+                }
+            }
+            #[cfg(not(feature = "yaml"))]
+            {
+                eprintln!("Error: YAML output requires the 'yaml' feature");
+                eprintln!("Rebuild with: cargo build --features yaml");
+                return EXIT_FAILURE;
+            }
+        }
+        OutputFormat::Csv | OutputFormat::Dot => {
+            eprintln!(
+                "Error: --by-study supports text, markdown, json, ndjson, yaml, and paths output only"
+            );
+            return EXIT_FAILURE;
+        }
+    };
+
+    write_formatted_output(&content, output)
+}
+
+/// Writes formatted output and maps write failures to an exit code.
+fn write_formatted_output(content: &str, output: Option<&Path>) -> i32 {
+    if let Err(e) = write_output(content, output) {
+        error!("Failed to write output: {}", e);
+        eprintln!("Error: Failed to write output: {}", e);
+        return EXIT_FAILURE;
+    }
+
+    EXIT_SUCCESS
+}
+
+/// Formats selected file paths, one per line in standard view order.
+fn output_paths(selections: &HashMap<MammogramView, Option<MammogramRecord>>) -> String {
+    ordered_selection(selections)
+        .into_iter()
+        .filter_map(|(_, record)| record)
+        .map(|record| format!("{}\n", record.file_path.display()))
+        .collect()
+}
+
+/// Returns the manifest key for a view, e.g. "left mlo"
+fn manifest_view_key(view: &MammogramView) -> String {
+    format!(
+        "{} {}",
+        view.laterality.simple_name(),
+        view.view.short_str()
+    )
+}
+
+/// Returns `(view key, selected path)` pairs in standard view order
+fn manifest_entries(
+    selections: &HashMap<MammogramView, Option<MammogramRecord>>,
+) -> Vec<(String, Option<String>)> {
+    ordered_selection(selections)
+        .into_iter()
+        .map(|(view, record)| {
+            (
+                manifest_view_key(&view),
+                record.map(|record| record.file_path.display().to_string()),
+            )
+        })
+        .collect()
+}
+
+/// Formats the `--manifest` content for a single selection
+fn format_manifest(
+    selections: &HashMap<MammogramView, Option<MammogramRecord>>,
+    format: &OutputFormat,
+) -> Result<String, String> {
+    let entries = manifest_entries(selections);
+    match format {
+        OutputFormat::Csv => format_manifest_csv(
+            entries
+                .into_iter()
+                .map(|(view, path)| vec![view, path.unwrap_or_default()]),
+            &["view", "file_path"],
+        ),
+        #[cfg(feature = "json")]
+        _ => format_manifest_json(&ManifestJson(entries)),
+        #[cfg(not(feature = "json"))]
+        _ => format_manifest_json(&entries),
+    }
+}
+
+/// Formats the `--manifest` content for per-study selections
+fn format_study_manifest(
+    studies: &StudySelections,
+    format: &OutputFormat,
+) -> Result<String, String> {
+    match format {
+        OutputFormat::Csv => format_manifest_csv(
+            studies.iter().flat_map(|(study_uid, selections)| {
+                manifest_entries(selections)
+                    .into_iter()
+                    .map(move |(view, path)| {
+                        vec![study_uid.clone(), view, path.unwrap_or_default()]
+                    })
+            }),
+            &["study", "view", "file_path"],
+        ),
+        #[cfg(feature = "json")]
+        _ => format_manifest_json(
+            &studies
+                .iter()
+                .map(|(study_uid, selections)| {
+                    (
+                        study_uid.clone(),
+                        ManifestJson(manifest_entries(selections)),
+                    )
+                })
+                .collect::<BTreeMap<_, _>>(),
+        ),
+        #[cfg(not(feature = "json"))]
+        _ => format_manifest_json(studies),
+    }
+}
+
+/// Manifest object whose keys follow standard view order
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+#[serde(transparent)]
+struct ManifestJson(
+    #[serde(serialize_with = "serialize_ordered_map")] Vec<(String, Option<String>)>,
+);
+
+#[cfg(feature = "json")]
+fn format_manifest_json<T: serde::Serialize>(manifest: &T) -> Result<String, String> {
+    serde_json::to_string_pretty(manifest)
+        .map(|json| format!("{json}\n"))
+        .map_err(|e| format!("Failed to serialize manifest: {e}"))
+}
+
+#[cfg(not(feature = "json"))]
+fn format_manifest_json<T>(_manifest: &T) -> Result<String, String> {
+    Err(
+        "JSON manifest requires the 'json' feature; rebuild with: cargo build --features json"
+            .to_string(),
+    )
+}
+
+#[cfg(feature = "csv")]
+fn format_manifest_csv(
+    rows: impl Iterator<Item = Vec<String>>,
+    header: &[&str],
+) -> Result<String, String> {
+    use mammocat_core::cli::csv::format_csv_row;
+
+    let mut output = format_csv_row(header.iter().copied());
+    output.push('\n');
+    for row in rows {
+        output.push_str(&format_csv_row(row));
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+#[cfg(not(feature = "csv"))]
+fn format_manifest_csv(
+    _rows: impl Iterator<Item = Vec<String>>,
+    _header: &[&str],
+) -> Result<String, String> {
+    Err(
+        "CSV manifest requires the 'csv' feature; rebuild with: cargo build --features csv"
+            .to_string(),
+    )
+}
+
+/// Writes formatted manifest content and maps failures to an exit code.
+fn write_manifest(content: Result<String, String>, manifest: &Path) -> i32 {
+    let result = content.and_then(|content| {
+        write_output(&content, Some(manifest))
+            .map_err(|e| format!("Failed to write manifest {}: {}", manifest.display(), e))
+    });
+    match result {
+        Ok(()) => {
+            info!("Wrote manifest to {}", manifest.display());
+            EXIT_SUCCESS
+        }
+        Err(message) => {
+            error!("{}", message);
+            eprintln!("Error: {}", message);
+            EXIT_FAILURE
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct SelectionJson {
+    /// Serialized as an object whose keys follow standard view order
+    #[serde(serialize_with = "serialize_ordered_map")]
+    selections: Vec<(String, Option<RecordJson>)>,
+}
+
+#[cfg(feature = "json")]
+fn serialize_ordered_map<S, V>(entries: &[(String, V)], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    V: serde::Serialize,
+{
+    serializer.collect_map(entries.iter().map(|(key, value)| (key, value)))
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct RecordJson {
+    file_path: String,
+    metadata: mammocat_core::MammogramMetadata,
+    rows: Option<u16>,
+    columns: Option<u16>,
+    image_area: Option<u32>,
+    transfer_syntax_uid: Option<String>,
+    is_lossy_compressed: bool,
+    is_implant_displaced: bool,
+}
+
+/// One NDJSON line: a selected record tagged with its view (and study in --by-study mode).
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct ViewRecordJson<'a> {
+    view: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    study: Option<&'a str>,
+    #[serde(flatten)]
+    record: RecordJson,
+}
+
+#[cfg(feature = "json")]
 fn output_json(
     selections: &HashMap<MammogramView, Option<MammogramRecord>>,
 ) -> Result<String, serde_json::Error> {
-    use serde::Serialize;
+    serde_json::to_string_pretty(&selection_json(selections))
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct StudiesJson {
+    studies: BTreeMap<String, SelectionJson>,
+}
+
+/// Formats per-study selections as `{"studies": {uid: {"selections": ...}}}`.
+#[cfg(feature = "json")]
+fn output_study_json(studies: &StudySelections) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&study_selections_json(studies))
+}
 
-    #[derive(Serialize)]
-    struct SelectionJson {
-        selections: HashMap<String, Option<RecordJson>>,
+#[cfg(feature = "json")]
+fn study_selections_json(studies: &StudySelections) -> StudiesJson {
+    StudiesJson {
+        studies: studies
+            .iter()
+            .map(|(study_uid, selections)| (study_uid.clone(), selection_json(selections)))
+            .collect(),
     }
+}
 
-    #[derive(Serialize)]
-    struct RecordJson {
-        file_path: String,
-        metadata: mammocat_core::MammogramMetadata,
-        rows: Option<u16>,
-        columns: Option<u16>,
-        image_area: Option<u32>,
-        transfer_syntax_uid: Option<String>,
-        is_lossy_compressed: bool,
-        is_implant_displaced: bool,
+/// Formats selections as NDJSON, one compact line per selected standard view
+///
+/// Views without a selected record are omitted, so a complete study yields
+/// four lines. Each line is independently parseable for streaming consumers.
+#[cfg(feature = "json")]
+fn output_ndjson(
+    selections: &HashMap<MammogramView, Option<MammogramRecord>>,
+    study: Option<&str>,
+) -> Result<String, serde_json::Error> {
+    let mut output = String::new();
+    for (view, record) in ordered_selection(selections) {
+        let Some(record) = record else {
+            continue;
+        };
+        let line = ViewRecordJson {
+            view: view.to_string(),
+            study,
+            record: record_json(record),
+        };
+        output.push_str(&serde_json::to_string(&line)?);
+        output.push('\n');
     }
+    Ok(output)
+}
 
-    let json_selections: HashMap<String, Option<RecordJson>> = selections
-        .iter()
-        .map(|(view, record)| {
-            let key = format!("{}", view);
-            let value = record.as_ref().map(|r| RecordJson {
-                file_path: r.file_path.display().to_string(),
-                metadata: r.metadata.clone(),
-                rows: r.rows,
-                columns: r.columns,
-                image_area: r.image_area(),
-                transfer_syntax_uid: r.transfer_syntax_uid.clone(),
-                is_lossy_compressed: r.is_lossy_compressed,
-                is_implant_displaced: r.is_implant_displaced(),
-            });
-            (key, value)
-        })
+#[cfg(feature = "json")]
+fn selection_json(selections: &HashMap<MammogramView, Option<MammogramRecord>>) -> SelectionJson {
+    let json_selections = ordered_selection(selections)
+        .into_iter()
+        .map(|(view, record)| (view.to_string(), record.map(record_json)))
         .collect();
 
-    let output = SelectionJson {
+    SelectionJson {
         selections: json_selections,
-    };
+    }
+}
+
+#[cfg(feature = "json")]
+fn record_json(record: &MammogramRecord) -> RecordJson {
+    RecordJson {
+        file_path: record.file_path.display().to_string(),
+        metadata: record.metadata.clone(),
+        rows: record.rows,
+        columns: record.columns,
+        image_area: record.image_area(),
+        transfer_syntax_uid: record.transfer_syntax_uid.clone(),
+        is_lossy_compressed: record.is_lossy_compressed,
+        is_implant_displaced: record.is_implant_displaced(),
+    }
+}
+
+#[cfg(feature = "csv")]
+const SELECTION_CSV_HEADER: [&str; 9] = [
+    "view",
+    "file_path",
+    "type",
+    "laterality",
+    "manufacturer",
+    "model",
+    "frames",
+    "rows",
+    "columns",
+];
+
+/// Formats selections as CSV with one row per standard view
+///
+/// Views without a selected record keep their `view` cell and leave the
+/// remaining cells empty.
+#[cfg(feature = "csv")]
+fn output_csv(selections: &HashMap<MammogramView, Option<MammogramRecord>>) -> String {
+    use mammocat_core::cli::csv::format_csv_row;
+
+    let mut output = format_csv_row(SELECTION_CSV_HEADER);
+    output.push('\n');
+
+    for view in &STANDARD_MAMMO_VIEWS {
+        let mut row = vec![view.to_string()];
+        match selections.get(view) {
+            Some(Some(record)) => row.extend([
+                record.file_path.display().to_string(),
+                record.metadata.mammogram_type.simple_name().to_string(),
+                record.metadata.laterality.simple_name().to_string(),
+                record.metadata.manufacturer.clone().unwrap_or_default(),
+                record.metadata.model.clone().unwrap_or_default(),
+                record.metadata.number_of_frames.to_string(),
+                record.rows.map(|rows| rows.to_string()).unwrap_or_default(),
+                record
+                    .columns
+                    .map(|columns| columns.to_string())
+                    .unwrap_or_default(),
+            ]),
+            _ => row.resize(SELECTION_CSV_HEADER.len(), String::new()),
+        }
+        output.push_str(&format_csv_row(row));
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats selections as a Graphviz DOT study map
+///
+/// Each study with a selected record links to its selected standard views,
+/// and each view links to its selected file. Views without a selection are
+/// drawn as dashed, unlinked nodes. Render with e.g. `dot -Tsvg`.
+fn output_dot(selections: &HashMap<MammogramView, Option<MammogramRecord>>) -> String {
+    let mut output = String::from("digraph study_map {\n    rankdir=LR;\n");
+    let mut seen_studies = HashSet::new();
+
+    for view in &STANDARD_MAMMO_VIEWS {
+        let view_id = dot_quote(&format!("view:{view}"));
+        let label = dot_quote(&view.display_abbrev());
+        let Some(Some(record)) = selections.get(view) else {
+            output.push_str(&format!("    {view_id} [label={label}, style=dashed];\n"));
+            continue;
+        };
+
+        let study_uid = record.study_instance_uid.as_deref().unwrap_or("unknown");
+        let study_id = dot_quote(&format!("study:{study_uid}"));
+        let file_path = record.file_path.display().to_string();
+        let file_id = dot_quote(&format!("file:{file_path}"));
+        if seen_studies.insert(study_uid) {
+            output.push_str(&format!(
+                "    {study_id} [label={}, shape=box];\n",
+                dot_quote(&format!("Study {study_uid}"))
+            ));
+        }
+        output.push_str(&format!("    {view_id} [label={label}];\n"));
+        output.push_str(&format!(
+            "    {file_id} [label={}, shape=note];\n",
+            dot_quote(&file_path)
+        ));
+        output.push_str(&format!("    {study_id} -> {view_id};\n"));
+        output.push_str(&format!("    {view_id} -> {file_id};\n"));
+    }
+
+    output.push_str("}\n");
+    output
+}
 
-    serde_json::to_string_pretty(&output)
+/// Quotes a DOT identifier, escaping backslashes and double quotes.
+fn dot_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
 /// Text report for preferred view selection
 struct TextReport<'a> {
     selections: &'a HashMap<MammogramView, Option<MammogramRecord>>,
+    view_labels: ViewLabelStyle,
 }
 
 impl<'a> TextReport<'a> {
     fn new(selections: &'a HashMap<MammogramView, Option<MammogramRecord>>) -> Self {
-        Self { selections }
+        Self {
+            selections,
+            view_labels: ViewLabelStyle::default(),
+        }
+    }
+
+    fn with_view_labels(mut self, view_labels: ViewLabelStyle) -> Self {
+        self.view_labels = view_labels;
+        self
     }
 }
 
@@ -441,11 +1732,20 @@ impl<'a> fmt::Display for TextReport<'a> {
         writeln!(f, "========================")?;
         writeln!(f)?;
 
-        for view in &STANDARD_MAMMO_VIEWS {
-            write!(f, "{}: ", view)?;
+        for (view, record) in ordered_selection(self.selections) {
+            match self.view_labels {
+                ViewLabelStyle::Compact => write!(f, "{}: ", view)?,
+                ViewLabelStyle::Clinical => write!(f, "{}: ", view.display_abbrev())?,
+            }
 
-            if let Some(Some(record)) = self.selections.get(view) {
+            if let Some(record) = record {
                 writeln!(f, "{}", record.file_path.display())?;
+                if let Some(study_uid) = &record.study_instance_uid {
+                    writeln!(f, "  Study UID: {}", study_uid)?;
+                }
+                if let Some(sop_uid) = &record.sop_instance_uid {
+                    writeln!(f, "  SOP UID: {}", sop_uid)?;
+                }
                 writeln!(
                     f,
                     "  Type: {}",
@@ -471,6 +1771,9 @@ impl<'a> fmt::Display for TextReport<'a> {
                         area
                     )?;
                 }
+                if let Some(spacing) = record.metadata.pixel_spacing {
+                    writeln!(f, "  Pixel Spacing: {} x {} mm", spacing.row, spacing.col)?;
+                }
                 if record.is_implant_displaced() {
                     writeln!(f, "  Implant Displaced: yes")?;
                 }
@@ -490,10 +1793,103 @@ impl<'a> fmt::Display for TextReport<'a> {
     }
 }
 
+/// Markdown report for preferred view selection
+///
+/// Renders a completeness note and a GitHub-flavored table with one row per
+/// standard view.
+struct MarkdownReport<'a> {
+    selections: &'a HashMap<MammogramView, Option<MammogramRecord>>,
+    view_labels: ViewLabelStyle,
+    heading: String,
+}
+
+impl<'a> MarkdownReport<'a> {
+    fn new(selections: &'a HashMap<MammogramView, Option<MammogramRecord>>) -> Self {
+        Self {
+            selections,
+            view_labels: ViewLabelStyle::default(),
+            heading: "Preferred View Selection".to_string(),
+        }
+    }
+
+    fn with_view_labels(mut self, view_labels: ViewLabelStyle) -> Self {
+        self.view_labels = view_labels;
+        self
+    }
+
+    fn with_heading(mut self, heading: String) -> Self {
+        self.heading = heading;
+        self
+    }
+}
+
+impl<'a> fmt::Display for MarkdownReport<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "## {}", markdown_cell(&self.heading))?;
+        writeln!(f)?;
+
+        let missing = missing_views(self.selections);
+        let present = STANDARD_MAMMO_VIEWS.len() - missing.len();
+        write!(
+            f,
+            "**Completeness:** {}/{} standard views",
+            present,
+            STANDARD_MAMMO_VIEWS.len()
+        )?;
+        if missing.is_empty() {
+            writeln!(f)?;
+        } else {
+            let missing: Vec<String> = missing.iter().map(|view| view.to_string()).collect();
+            writeln!(f, " (missing: {})", missing.join(", "))?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "| View | File | Type | Manufacturer | Resolution |")?;
+        writeln!(f, "| --- | --- | --- | --- | --- |")?;
+        for (view, record) in ordered_selection(self.selections) {
+            let label = match self.view_labels {
+                ViewLabelStyle::Compact => view.to_string(),
+                ViewLabelStyle::Clinical => view.display_abbrev(),
+            };
+            let Some(record) = record else {
+                writeln!(f, "| {} | Not found | | | |", label)?;
+                continue;
+            };
+            let resolution = match (record.rows, record.columns) {
+                (Some(rows), Some(columns)) => format!("{}x{}", rows, columns),
+                _ => "unknown".to_string(),
+            };
+            writeln!(
+                f,
+                "| {} | {} | {} | {} | {} |",
+                label,
+                markdown_cell(&record.file_path.display().to_string()),
+                record.metadata.mammogram_type.simple_name(),
+                markdown_cell(record.metadata.manufacturer.as_deref().unwrap_or("unknown")),
+                resolution
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Escapes characters that would break a Markdown table cell
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
+
+#[cfg(test)]
+#[path = "../test_support/dicom.rs"]
+mod test_support;
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mammocat_core::{DbtObjectKind, ImageType, Laterality, MammogramMetadata, ViewPosition};
+    use mammocat_core::{
+        DbtObjectKind, ImageType, Laterality, MammogramMetadata, PhotometricInterpretation,
+        PresentationIntent, SopClassKind, ViewPosition,
+    };
     use std::fs::File;
     use std::io::Write;
     use tempfile::TempDir;
@@ -534,15 +1930,25 @@ mod tests {
                     None,
                 ),
                 is_for_processing: false,
+                presentation_intent: PresentationIntent::Unknown,
                 has_implant: false,
                 manufacturer: None,
                 model: None,
                 number_of_frames: 1,
                 pixel_spacing: None,
+                imager_pixel_spacing: None,
+                photometric: PhotometricInterpretation::Monochrome2,
+                rescale_slope: None,
+                rescale_intercept: None,
+                body_part_thickness_mm: None,
+                compression_force_n: None,
                 concatenation_uid: None,
                 sop_instance_uid_of_concatenation_source: None,
                 is_secondary_capture: false,
+                is_localizer: false,
+                is_specimen: false,
                 modality: Some("MG".to_string()),
+                character_set: None,
                 transfer_syntax_uid: Some(transfer_syntax_uid.to_string()),
                 transfer_syntax_name: None,
                 compression_type: None,
@@ -556,9 +1962,14 @@ mod tests {
             )),
             rows: Some(2560),
             columns: Some(3328),
+            bits_stored: None,
             transfer_syntax_uid: Some(transfer_syntax_uid.to_string()),
             is_lossy_compressed,
             series_instance_uid: Some(format!("{study_uid}.series")),
+            study_date: None,
+            patient_id: None,
+            patient_name: None,
+            sop_class: SopClassKind::MammoImage,
         }
     }
 
@@ -684,72 +2095,405 @@ mod tests {
     }
 
     #[test]
-    fn test_build_filter_config_deprioritizes_lossy_by_default() {
-        let cli = Cli::try_parse_from(["mammoselect", "/tmp"]).unwrap();
-        let config = build_filter_config(&cli);
-
-        assert!(!config.exclude_lossy_compressed);
-        assert!(config.deprioritize_lossy_compressed);
+    fn test_collect_input_files_recurses_only_when_requested() {
+        let temp_dir = TempDir::new().unwrap();
+        let nested = temp_dir.path().join("study").join("series");
+        std::fs::create_dir_all(&nested).unwrap();
+        let top_level = temp_dir.path().join("top.dcm");
+        let deep = nested.join("deep.dcm");
+        File::create(&top_level).unwrap();
+        File::create(&deep).unwrap();
+        File::create(nested.join("notes.txt")).unwrap();
+        let directory = temp_dir.path().to_str().unwrap();
+
+        let flat_cli = Cli::try_parse_from(["mammoselect", directory]).unwrap();
+        let recursive_cli = Cli::try_parse_from(["mammoselect", "--recursive", directory]).unwrap();
+        let short_cli = Cli::try_parse_from(["mammoselect", "-r", directory]).unwrap();
+
+        assert_eq!(
+            collect_input_files(&flat_cli).unwrap(),
+            vec![top_level.clone()]
+        );
+        let mut expected = vec![top_level, deep];
+        expected.sort();
+        assert_eq!(collect_input_files(&recursive_cli).unwrap(), expected);
+        assert_eq!(collect_input_files(&short_cli).unwrap(), expected);
     }
 
     #[test]
-    fn test_build_filter_config_excludes_lossy_when_flag_enabled() {
-        let cli = Cli::try_parse_from(["mammoselect", "--exclude-lossy", "/tmp"]).unwrap();
-        let config = build_filter_config(&cli);
+    fn test_is_glob_pattern_detects_metacharacters() {
+        assert!(is_glob_pattern(Path::new("study*/IMG*.dcm")));
+        assert!(is_glob_pattern(Path::new("IMG?.dcm")));
+        assert!(is_glob_pattern(Path::new("study[12]")));
+        assert!(!is_glob_pattern(Path::new("/data/study")));
+    }
 
-        assert!(config.exclude_lossy_compressed);
-        assert!(config.deprioritize_lossy_compressed);
+    #[test]
+    fn test_collect_input_files_expands_glob_to_matching_subset() {
+        let temp_dir = TempDir::new().unwrap();
+        for study in ["study1", "study2", "other"] {
+            let study_dir = temp_dir.path().join(study);
+            std::fs::create_dir_all(&study_dir).unwrap();
+            File::create(study_dir.join("IMG001.dcm")).unwrap();
+            File::create(study_dir.join("SR001.dcm")).unwrap();
+        }
+        std::fs::create_dir(temp_dir.path().join("study1").join("IMG_dir.dcm")).unwrap();
+        let pattern = temp_dir.path().join("study*").join("IMG*.dcm");
+        let directory = temp_dir.path().join("study1");
+
+        let glob_cli = Cli::try_parse_from(["mammoselect", pattern.to_str().unwrap()]).unwrap();
+        let dir_cli = Cli::try_parse_from(["mammoselect", directory.to_str().unwrap()]).unwrap();
+
+        assert_eq!(
+            collect_input_files(&glob_cli).unwrap(),
+            vec![
+                temp_dir.path().join("study1").join("IMG001.dcm"),
+                temp_dir.path().join("study2").join("IMG001.dcm"),
+            ]
+        );
+        let mut expected = vec![directory.join("IMG001.dcm"), directory.join("SR001.dcm")];
+        expected.sort();
+        let mut scanned = collect_input_files(&dir_cli).unwrap();
+        scanned.sort();
+        assert_eq!(scanned, expected);
     }
 
     #[test]
-    fn test_build_filter_config_allows_dbt_object_kinds() {
-        let cli = Cli::try_parse_from([
-            "mammoselect",
-            "--allowed-dbt-object-kinds",
-            "volume,slice",
-            "/tmp",
-        ])
-        .unwrap();
-        let config = build_filter_config(&cli);
-        let allowed = config
-            .allowed_dbt_object_kinds
-            .expect("allowed DBT object kinds");
+    fn test_run_exits_with_no_dicom_files_code_for_unmatched_glob() {
+        let temp_dir = TempDir::new().unwrap();
+        let pattern = temp_dir.path().join("*.dcm");
+        let cli = Cli::try_parse_from(["mammoselect", pattern.to_str().unwrap()]).unwrap();
 
-        assert_eq!(allowed.len(), 2);
-        assert!(allowed.contains(&DbtObjectKind::Volume));
-        assert!(allowed.contains(&DbtObjectKind::Slice));
+        assert_eq!(run(&cli), EXIT_NO_DICOM_FILES);
     }
 
     #[test]
-    fn test_build_filter_config_can_disable_lossy_deprioritization() {
-        let cli = Cli::try_parse_from(["mammoselect", "--no-deprioritize-lossy", "/tmp"]).unwrap();
-        let config = build_filter_config(&cli);
+    fn test_run_exits_with_failure_for_missing_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("missing");
+        let cli = Cli::try_parse_from(["mammoselect", missing.to_str().unwrap()]).unwrap();
 
-        assert!(!config.exclude_lossy_compressed);
-        assert!(!config.deprioritize_lossy_compressed);
+        assert_eq!(run(&cli), EXIT_FAILURE);
     }
 
     #[test]
-    fn test_selected_lossy_warning_messages_warns_when_lossy_selected() {
-        let view = MammogramView::new(Laterality::Left, ViewPosition::Mlo);
-        let mut selections = HashMap::new();
-        selections.insert(
-            view,
-            Some(make_cli_test_record_with_path(view, "/tmp/lossy.dcm", true)),
-        );
-
-        let warnings = selected_lossy_warning_messages(&selections, &FilterConfig::default());
+    fn test_run_exits_with_no_dicom_files_code_for_empty_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        File::create(temp_dir.path().join("notes.txt")).unwrap();
+        let cli = Cli::try_parse_from(["mammoselect", temp_dir.path().to_str().unwrap()]).unwrap();
 
-        assert_eq!(warnings.len(), 1);
-        assert!(warnings[0].contains("lossy compressed image selected"));
-        assert!(warnings[0].contains(&view.to_string()));
-        assert!(warnings[0].contains("/tmp/lossy.dcm"));
-        assert!(warnings[0].contains("--exclude-lossy"));
+        assert_eq!(run(&cli), EXIT_NO_DICOM_FILES);
     }
 
     #[test]
-    fn test_selected_lossy_warning_messages_suppressed_when_lossy_excluded() {
-        let view = MammogramView::new(Laterality::Left, ViewPosition::Mlo);
+    fn test_run_exits_with_no_valid_records_code_for_unreadable_files() {
+        let temp_dir = TempDir::new().unwrap();
+        File::create(temp_dir.path().join("broken.dcm"))
+            .unwrap()
+            .write_all(b"not a dicom file")
+            .unwrap();
+        let cli = Cli::try_parse_from(["mammoselect", temp_dir.path().to_str().unwrap()]).unwrap();
+
+        assert_eq!(run(&cli), EXIT_NO_VALID_RECORDS);
+    }
+
+    #[test]
+    fn test_load_records_excludes_cad_sr() {
+        use crate::test_support::MammogramFixture;
+        use dicom_core::VR;
+        use dicom_dictionary_std::{tags, uids};
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("cad.dcm");
+        MammogramFixture::new("1.2.826.0.41")
+            .with(tags::SOP_CLASS_UID, VR::UI, uids::MAMMOGRAPHY_CADSR_STORAGE)
+            .with(tags::MODALITY, VR::CS, "SR")
+            .write(&path);
+
+        let error = MammogramRecord::from_image_file(path.clone(), true).unwrap_err();
+        assert!(matches!(
+            error,
+            MammocatError::NonImageSopClass(SopClassKind::CadSr)
+        ));
+
+        for ignore_modality in [false, true] {
+            for best_effort in [false, true] {
+                let options = ReadOptions {
+                    ignore_modality,
+                    best_effort,
+                };
+                let (records, timings) = load_records(vec![path.clone()], options);
+                assert!(records.is_empty());
+                assert_eq!(timings.len(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_records_ignore_modality_classifies_mislabeled_files() {
+        use crate::test_support::MammogramFixture;
+        use dicom_core::VR;
+        use dicom_dictionary_std::tags;
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("mislabeled.dcm");
+        MammogramFixture::new("1.2.826.0.42")
+            .with(tags::MODALITY, VR::CS, "OT")
+            .view("L", "CC")
+            .write(&path);
+
+        let (strict, _) = load_records(vec![path.clone()], ReadOptions::default());
+        assert!(strict.is_empty());
+
+        let (records, _) = load_records(
+            vec![path],
+            ReadOptions {
+                ignore_modality: true,
+                ..ReadOptions::default()
+            },
+        );
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].metadata.mammogram_type, MammogramType::Ffdm);
+        assert_eq!(records[0].metadata.modality.as_deref(), Some("OT"));
+
+        assert!(!records[0].metadata.is_secondary_capture);
+
+        let cli = Cli::try_parse_from(["mammoselect", "--ignore-modality", "/tmp/study"]).unwrap();
+        assert!(cli.ignore_modality);
+        let filter_config = build_filter_config(&cli);
+        assert!(!filter_config.exclude_non_mg_modality);
+
+        let (selections, _) =
+            select_preferred_views(&records, &filter_config, PreferenceOrder::Default, false)
+                .unwrap();
+        let lcc = MammogramView::new(Laterality::Left, ViewPosition::Cc);
+        assert_eq!(
+            selections[&lcc]
+                .as_ref()
+                .and_then(|record| record.sop_instance_uid.as_deref()),
+            Some("1.2.826.0.42")
+        );
+    }
+
+    #[test]
+    fn test_load_records_best_effort_keeps_truncated_files() {
+        use crate::test_support::MammogramFixture;
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("truncated.dcm");
+        MammogramFixture::new("1.2.826.0.43")
+            .view("L", "MLO")
+            .write(&path);
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+
+        let (strict, _) = load_records(vec![path.clone()], ReadOptions::default());
+        assert!(strict.is_empty());
+
+        let (records, timings) = load_records(
+            vec![path],
+            ReadOptions {
+                best_effort: true,
+                ..ReadOptions::default()
+            },
+        );
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].metadata.mammogram_view(),
+            MammogramView::new(Laterality::Left, ViewPosition::Mlo)
+        );
+        // Columns is the last element, so the cut drops it
+        assert_eq!((records[0].rows, records[0].columns), (Some(32), None));
+        assert_eq!(timings.len(), 1);
+    }
+
+    #[test]
+    fn test_load_records_times_every_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let files: Vec<PathBuf> = ["first.dcm", "second.dcm"]
+            .iter()
+            .map(|name| {
+                let path = temp_dir.path().join(name);
+                File::create(&path)
+                    .unwrap()
+                    .write_all(b"not a dicom file")
+                    .unwrap();
+                path
+            })
+            .collect();
+
+        let (records, timings) = load_records(files.clone(), ReadOptions::default());
+
+        assert!(records.is_empty());
+        let timed_paths: Vec<PathBuf> = timings.iter().map(|t| t.path.clone()).collect();
+        assert_eq!(timed_paths, files);
+        assert!(summarize_timings(&timings).is_some());
+    }
+
+    #[cfg(feature = "parallel")]
+    fn write_stub_dicom(path: &std::path::Path, laterality: &str, view: &str, sop_uid: &str) {
+        use crate::test_support::MammogramFixture;
+        use dicom_core::VR;
+        use dicom_dictionary_std::tags;
+
+        MammogramFixture::new(sop_uid)
+            .with(tags::PRESENTATION_INTENT_TYPE, VR::CS, "FOR PRESENTATION")
+            .view(laterality, view)
+            .write(path);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_load_matches_serial_selection() {
+        let temp_dir = TempDir::new().unwrap();
+        let views = [("L", "MLO"), ("R", "MLO"), ("L", "CC"), ("R", "CC")];
+        for index in 0..64 {
+            let (laterality, view) = views[index % views.len()];
+            write_stub_dicom(
+                &temp_dir.path().join(format!("stub_{index:03}")),
+                laterality,
+                view,
+                &format!("1.2.826.0.40.{index}"),
+            );
+        }
+        File::create(temp_dir.path().join("broken.dcm"))
+            .unwrap()
+            .write_all(b"not a dicom file")
+            .unwrap();
+        let files = collect_dicom_files(temp_dir.path()).unwrap();
+
+        let (serial_records, serial_timings) = load_records(files.clone(), ReadOptions::default());
+        let (parallel_records, parallel_timings) =
+            load_records_with_jobs(files.clone(), Some(4), ReadOptions::default()).unwrap();
+
+        let record_paths = |records: &[MammogramRecord]| -> Vec<PathBuf> {
+            records
+                .iter()
+                .map(|record| record.file_path.clone())
+                .collect()
+        };
+        assert_eq!(serial_records.len(), 64);
+        assert_eq!(
+            record_paths(&parallel_records),
+            record_paths(&serial_records)
+        );
+        let timed_paths: Vec<PathBuf> = parallel_timings.iter().map(|t| t.path.clone()).collect();
+        assert_eq!(timed_paths, files);
+        assert_eq!(serial_timings.len(), files.len());
+
+        let select = |records: &[MammogramRecord]| {
+            select_preferred_views(
+                records,
+                &FilterConfig::default(),
+                PreferenceOrder::Default,
+                false,
+            )
+            .unwrap()
+            .0
+        };
+        let serial_selection = select(&serial_records);
+        let parallel_selection = select(&parallel_records);
+        for view in &STANDARD_MAMMO_VIEWS {
+            let selected_path = |selection: &HashMap<MammogramView, Option<MammogramRecord>>| {
+                selection[view]
+                    .as_ref()
+                    .map(|record| record.file_path.clone())
+            };
+            assert!(selected_path(&serial_selection).is_some());
+            assert_eq!(
+                selected_path(&parallel_selection),
+                selected_path(&serial_selection)
+            );
+        }
+    }
+
+    #[test]
+    fn test_summarize_timings_reports_min_max_mean() {
+        let timings = [10, 30, 20].map(|ms| FileTiming {
+            path: PathBuf::from(format!("{ms}.dcm")),
+            duration: Duration::from_millis(ms),
+        });
+
+        let summary = summarize_timings(&timings).unwrap();
+
+        assert_eq!(
+            summary,
+            TimingSummary {
+                min: Duration::from_millis(10),
+                max: Duration::from_millis(30),
+                mean: Duration::from_millis(20),
+            }
+        );
+        assert_eq!(summarize_timings(&[]), None);
+    }
+
+    #[test]
+    fn test_build_filter_config_deprioritizes_lossy_by_default() {
+        let cli = Cli::try_parse_from(["mammoselect", "/tmp"]).unwrap();
+        let config = build_filter_config(&cli);
+
+        assert!(!config.exclude_lossy_compressed);
+        assert!(config.deprioritize_lossy_compressed);
+    }
+
+    #[test]
+    fn test_build_filter_config_excludes_lossy_when_flag_enabled() {
+        let cli = Cli::try_parse_from(["mammoselect", "--exclude-lossy", "/tmp"]).unwrap();
+        let config = build_filter_config(&cli);
+
+        assert!(config.exclude_lossy_compressed);
+        assert!(config.deprioritize_lossy_compressed);
+    }
+
+    #[test]
+    fn test_build_filter_config_allows_dbt_object_kinds() {
+        let cli = Cli::try_parse_from([
+            "mammoselect",
+            "--allowed-dbt-object-kinds",
+            "volume,slice",
+            "/tmp",
+        ])
+        .unwrap();
+        let config = build_filter_config(&cli);
+        let allowed = config
+            .allowed_dbt_object_kinds
+            .expect("allowed DBT object kinds");
+
+        assert_eq!(allowed.len(), 2);
+        assert!(allowed.contains(&DbtObjectKind::Volume));
+        assert!(allowed.contains(&DbtObjectKind::Slice));
+    }
+
+    #[test]
+    fn test_build_filter_config_can_disable_lossy_deprioritization() {
+        let cli = Cli::try_parse_from(["mammoselect", "--no-deprioritize-lossy", "/tmp"]).unwrap();
+        let config = build_filter_config(&cli);
+
+        assert!(!config.exclude_lossy_compressed);
+        assert!(!config.deprioritize_lossy_compressed);
+    }
+
+    #[test]
+    fn test_selected_lossy_warning_messages_warns_when_lossy_selected() {
+        let view = MammogramView::new(Laterality::Left, ViewPosition::Mlo);
+        let mut selections = HashMap::new();
+        selections.insert(
+            view,
+            Some(make_cli_test_record_with_path(view, "/tmp/lossy.dcm", true)),
+        );
+
+        let warnings = selected_lossy_warning_messages(&selections, &FilterConfig::default());
+
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("lossy compressed image selected"));
+        assert!(warnings[0].contains(&view.to_string()));
+        assert!(warnings[0].contains("/tmp/lossy.dcm"));
+        assert!(warnings[0].contains("--exclude-lossy"));
+    }
+
+    #[test]
+    fn test_selected_lossy_warning_messages_suppressed_when_lossy_excluded() {
+        let view = MammogramView::new(Laterality::Left, ViewPosition::Mlo);
         let mut selections = HashMap::new();
         selections.insert(
             view,
@@ -835,6 +2579,1051 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_require_complete_fails_for_three_of_four_views() {
+        let study = "1.2.826.0.30";
+        let records = vec![
+            make_cli_test_record(
+                Laterality::Left,
+                ViewPosition::Mlo,
+                MammogramType::Ffdm,
+                study,
+            ),
+            make_cli_test_record(
+                Laterality::Right,
+                ViewPosition::Mlo,
+                MammogramType::Ffdm,
+                study,
+            ),
+            make_cli_test_record(
+                Laterality::Left,
+                ViewPosition::Cc,
+                MammogramType::Ffdm,
+                study,
+            ),
+        ];
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            missing_views(&selections),
+            vec![MammogramView::new(Laterality::Right, ViewPosition::Cc)]
+        );
+        assert_eq!(check_complete_selection(&selections), EXIT_INCOMPLETE_STUDY);
+    }
+
+    #[test]
+    fn test_require_complete_passes_for_all_four_views() {
+        let study = "1.2.826.0.30";
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study)
+            })
+            .collect();
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        assert!(missing_views(&selections).is_empty());
+        assert_eq!(check_complete_selection(&selections), EXIT_SUCCESS);
+    }
+
+    #[test]
+    fn test_text_report_clinical_view_labels() {
+        let records = vec![make_cli_test_record(
+            Laterality::Right,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            "1.2.826.0.33",
+        )];
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let compact = TextReport::new(&selections).to_string();
+        let clinical = TextReport::new(&selections)
+            .with_view_labels(ViewLabelStyle::Clinical)
+            .to_string();
+
+        assert!(compact.contains("rcc: 1.2.826.0.33_Right_Cc.dcm"));
+        assert!(clinical.contains("R CC: 1.2.826.0.33_Right_Cc.dcm"));
+        assert!(clinical.contains("L MLO: Not found"));
+    }
+
+    #[test]
+    fn test_text_report_prints_uids_and_spacing_when_present() {
+        let mut record = make_cli_test_record(
+            Laterality::Right,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            "1.2.826.0.35",
+        );
+        record.metadata.pixel_spacing = Some(mammocat_core::PixelSpacing::new(0.07, 0.07));
+        let mut bare = record.clone();
+        bare.study_instance_uid = None;
+        bare.sop_instance_uid = None;
+        bare.metadata.pixel_spacing = None;
+        let rcc = MammogramView::new(Laterality::Right, ViewPosition::Cc);
+
+        let report = TextReport::new(&HashMap::from([(rcc, Some(record))])).to_string();
+        assert!(report.contains("  Study UID: 1.2.826.0.35\n"));
+        assert!(report.contains("  SOP UID: 1.2.826.0.35.r.cc\n"));
+        assert!(report.contains("  Pixel Spacing: 0.07 x 0.07 mm\n"));
+
+        let report = TextReport::new(&HashMap::from([(rcc, Some(bare))])).to_string();
+        assert!(!report.contains("Study UID"));
+        assert!(!report.contains("SOP UID"));
+        assert!(!report.contains("Pixel Spacing"));
+    }
+
+    #[test]
+    fn test_markdown_report_renders_table_row_per_view() {
+        let records = vec![make_cli_test_record(
+            Laterality::Right,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            "1.2.826.0.34",
+        )];
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let report = MarkdownReport::new(&selections).to_string();
+        let lines: Vec<&str> = report.lines().collect();
+
+        assert_eq!(lines[0], "## Preferred View Selection");
+        assert_eq!(
+            lines[2],
+            "**Completeness:** 1/4 standard views (missing: lmlo, rmlo, lcc)"
+        );
+        let table_start = lines
+            .iter()
+            .position(|line| *line == "| View | File | Type | Manufacturer | Resolution |")
+            .unwrap();
+        assert_eq!(lines[table_start + 1], "| --- | --- | --- | --- | --- |");
+        let rows = &lines[table_start + 2..];
+        assert_eq!(rows.len(), STANDARD_MAMMO_VIEWS.len());
+        assert_eq!(rows[0], "| lmlo | Not found | | | |");
+        assert!(rows
+            .iter()
+            .any(|row| row.starts_with("| rcc | 1.2.826.0.34_Right_Cc.dcm | ffdm |")));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_output_csv_writes_header_and_row_per_view() {
+        let study = "1.2.826.0.31";
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                let mut record =
+                    make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study);
+                record.metadata.manufacturer = Some("Vendor, Inc.".to_string());
+                record.metadata.model = Some("Model X".to_string());
+                record
+            })
+            .collect();
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let output = output_csv(&selections);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[0],
+            "view,file_path,type,laterality,manufacturer,model,frames,rows,columns"
+        );
+        assert_eq!(
+            lines[1],
+            "lmlo,1.2.826.0.31_Left_Mlo.dcm,ffdm,left,\"Vendor, Inc.\",Model X,1,2560,3328"
+        );
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_output_csv_leaves_missing_view_cells_empty() {
+        let study = "1.2.826.0.32";
+        let records = vec![make_cli_test_record(
+            Laterality::Left,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            study,
+        )];
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let output = output_csv(&selections);
+
+        assert!(output.lines().any(|line| line == "rcc,,,,,,,,"));
+    }
+
+    #[test]
+    fn test_exclude_implants_flag_empties_implant_study_selection() {
+        let study = "1.2.826.0.34";
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                let mut record =
+                    make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study);
+                record.metadata.has_implant = true;
+                record
+            })
+            .collect();
+        let select = |args: &[&str]| {
+            let cli = Cli::try_parse_from(args).unwrap();
+            let (selections, _) = select_preferred_views(
+                &records,
+                &build_filter_config(&cli),
+                PreferenceOrder::Default,
+                false,
+            )
+            .unwrap();
+            selections
+        };
+
+        let unfiltered = select(&["mammoselect", "/data"]);
+        let filtered = select(&["mammoselect", "--exclude-implants", "/data"]);
+
+        assert!(unfiltered.values().all(Option::is_some));
+        assert_eq!(filtered.len(), STANDARD_MAMMO_VIEWS.len());
+        assert!(filtered.values().all(Option::is_none));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_filter_config_file_overrides_flags() {
+        let temp = TempDir::new().unwrap();
+        let config_path = temp.path().join("filter.json");
+        std::fs::write(&config_path, r#"{"exclude_implants": true}"#).unwrap();
+        let study = "1.2.826.0.35";
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                let mut record =
+                    make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study);
+                record.metadata.has_implant = true;
+                record
+            })
+            .collect();
+        let cli = Cli::try_parse_from([
+            "mammoselect",
+            "--include-for-processing",
+            "--filter-config",
+            config_path.to_str().unwrap(),
+            "/data",
+        ])
+        .unwrap();
+
+        let config = load_filter_config(&cli).unwrap();
+        let (selections, _) =
+            select_preferred_views(&records, &config, PreferenceOrder::Default, false).unwrap();
+
+        assert!(config.exclude_implants);
+        assert!(config.exclude_for_processing);
+        assert!(selections.values().all(Option::is_none));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_filter_config_file_reports_parse_errors() {
+        let temp = TempDir::new().unwrap();
+        let config_path = temp.path().join("filter.json");
+        std::fs::write(&config_path, r#"{"exclude_implants": "yes"}"#).unwrap();
+        let cli = Cli::try_parse_from([
+            "mammoselect",
+            "--filter-config",
+            config_path.to_str().unwrap(),
+            "/data",
+        ])
+        .unwrap();
+
+        let error = load_filter_config(&cli).unwrap_err();
+
+        assert!(error.starts_with("Invalid filter config"), "{error}");
+        assert!(error.contains("filter.json"), "{error}");
+        std::fs::write(&config_path, r#"{"exclude_implant": true}"#).unwrap();
+        let error = load_filter_config(&cli).unwrap_err();
+        assert!(error.contains("unknown field"), "{error}");
+        let missing = Cli::try_parse_from([
+            "mammoselect",
+            "--filter-config",
+            "/nonexistent/filter.json",
+            "/data",
+        ])
+        .unwrap();
+        assert!(load_filter_config(&missing)
+            .unwrap_err()
+            .starts_with("Failed to read filter config"));
+    }
+
+    #[test]
+    fn test_filter_flag_aliases_build_filter_config() {
+        let cli = Cli::try_parse_from([
+            "mammoselect",
+            "--exclude-non-standard",
+            "--exclude-spot-mag",
+            "--keep-for-processing",
+            "--allowed-types",
+            "ffdm,tomo",
+            "/data",
+        ])
+        .unwrap();
+
+        let config = build_filter_config(&cli);
+
+        assert!(config.exclude_non_standard_views);
+        assert!(config.exclude_spot_mag);
+        assert!(!config.exclude_for_processing);
+        assert_eq!(
+            config.allowed_types,
+            Some([MammogramType::Ffdm, MammogramType::Tomo].into())
+        );
+    }
+
+    #[test]
+    fn test_patient_name_flags_build_filter_config() {
+        let cli = Cli::try_parse_from([
+            "mammoselect",
+            "--exclude-patient-name",
+            "daily qa,QC",
+            "--exclude-phantoms",
+            "/data",
+        ])
+        .unwrap();
+
+        let config = build_filter_config(&cli);
+
+        assert_eq!(
+            config.exclude_patient_name_patterns,
+            Some(vec!["daily qa".to_string(), "QC".to_string()])
+        );
+        assert!(config.exclude_phantoms);
+        assert!(config.patient_name_excluded(Some("ACR PHANTOM")));
+    }
+
+    #[test]
+    fn test_output_dot_links_study_views_and_files() {
+        let study = "1.2.826.0.35";
+        let records = vec![
+            make_cli_test_record(
+                Laterality::Left,
+                ViewPosition::Mlo,
+                MammogramType::Ffdm,
+                study,
+            ),
+            make_cli_test_record(
+                Laterality::Right,
+                ViewPosition::Cc,
+                MammogramType::Ffdm,
+                study,
+            ),
+        ];
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let output = output_dot(&selections);
+
+        assert!(output.starts_with("digraph study_map {"));
+        assert!(output.contains("\"view:lmlo\" [label=\"L MLO\"];"));
+        assert!(output.contains("\"view:rcc\" [label=\"R CC\"];"));
+        assert!(output.contains("\"view:lcc\" [label=\"L CC\", style=dashed];"));
+        assert!(output.contains("\"study:1.2.826.0.35\" -> \"view:lmlo\";"));
+        assert!(output.contains("\"view:rcc\" -> \"file:1.2.826.0.35_Right_Cc.dcm\";"));
+        assert_eq!(output.matches("shape=box").count(), 1);
+        assert!(output.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_dot_quote_escapes_quotes_and_backslashes() {
+        assert_eq!(dot_quote(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
+
+    fn make_two_study_records() -> Vec<MammogramRecord> {
+        ["1.2.826.0.36", "1.2.826.0.37"]
+            .iter()
+            .flat_map(|study| {
+                STANDARD_MAMMO_VIEWS.iter().map(move |view| {
+                    make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study)
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_select_preferred_views_by_study_selects_each_study_independently() {
+        let mut records = make_two_study_records();
+        let mut orphan = make_cli_test_record(
+            Laterality::Left,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            "orphan",
+        );
+        orphan.study_instance_uid = None;
+        records.push(orphan);
+
+        let studies = select_preferred_views_by_study(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let keys: Vec<&str> = studies.keys().map(String::as_str).collect();
+        assert_eq!(
+            keys,
+            vec!["1.2.826.0.36", "1.2.826.0.37", UNKNOWN_STUDY_KEY]
+        );
+        for study in ["1.2.826.0.36", "1.2.826.0.37"] {
+            let selections = &studies[study];
+            assert!(missing_views(selections).is_empty());
+            assert!(selections
+                .values()
+                .flatten()
+                .all(|record| { record.study_instance_uid.as_deref() == Some(study) }));
+        }
+        assert_eq!(missing_views(&studies[UNKNOWN_STUDY_KEY]).len(), 3);
+    }
+
+    #[test]
+    fn test_output_study_selections_writes_section_per_study() {
+        let studies = select_preferred_views_by_study(
+            &make_two_study_records(),
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("studies.txt");
+
+        let exit_code = output_study_selections(
+            &studies,
+            &OutputFormat::Text,
+            ViewLabelStyle::Compact,
+            Some(&output),
+        );
+        let text = std::fs::read_to_string(&output).unwrap();
+
+        assert_eq!(exit_code, EXIT_SUCCESS);
+        assert_eq!(text.matches("Preferred View Selection").count(), 2);
+        let first = text.find("Study: 1.2.826.0.36").unwrap();
+        let second = text.find("Study: 1.2.826.0.37").unwrap();
+        assert!(first < second);
+        assert!(text[second..].contains("1.2.826.0.37_Left_Mlo.dcm"));
+        assert!(!text[second..].contains("1.2.826.0.36_"));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_output_study_json_nests_selections_by_study() {
+        let studies = select_preferred_views_by_study(
+            &make_two_study_records(),
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&output_study_json(&studies).unwrap()).unwrap();
+
+        assert_eq!(json["studies"].as_object().unwrap().len(), 2);
+        assert_eq!(
+            json["studies"]["1.2.826.0.37"]["selections"]["rcc"]["file_path"],
+            "1.2.826.0.37_Right_Cc.dcm"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_output_ndjson_writes_one_parseable_line_per_view() {
+        let study = "1.2.826.0.38";
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study)
+            })
+            .collect();
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let output = output_ndjson(&selections, None).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0]["view"], "lmlo");
+        assert_eq!(lines[0]["file_path"], "1.2.826.0.38_Left_Mlo.dcm");
+        assert!(lines[0].get("study").is_none());
+        assert!(lines.iter().all(|line| line["metadata"].is_object()));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_output_json_keys_follow_standard_view_order() {
+        let study = "1.2.826.0.38";
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .rev()
+            .map(|view| {
+                make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study)
+            })
+            .collect();
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let output = output_json(&selections).unwrap();
+
+        let positions: Vec<usize> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| output.find(&format!("\"{view}\": ")).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_manifest_maps_each_standard_view_to_path_or_null() {
+        let study = "1.2.826.0.41";
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS[1..]
+            .iter()
+            .map(|view| {
+                make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study)
+            })
+            .collect();
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("out").join("manifest.json");
+
+        let exit_code = write_manifest(
+            format_manifest(&selections, &OutputFormat::Text),
+            &manifest_path,
+        );
+
+        assert_eq!(exit_code, EXIT_SUCCESS);
+        let manifest: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&manifest_path).unwrap()).unwrap();
+        assert_eq!(
+            manifest,
+            serde_json::json!({
+                "left mlo": null,
+                "right mlo": "1.2.826.0.41_Right_Mlo.dcm",
+                "left cc": "1.2.826.0.41_Left_Cc.dcm",
+                "right cc": "1.2.826.0.41_Right_Cc.dcm",
+            })
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_study_manifest_is_keyed_by_study_uid() {
+        let studies = select_preferred_views_by_study(
+            &make_two_study_records(),
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let manifest: serde_json::Value =
+            serde_json::from_str(&format_study_manifest(&studies, &OutputFormat::Json).unwrap())
+                .unwrap();
+
+        let manifest = manifest.as_object().unwrap();
+        assert_eq!(manifest.len(), studies.len());
+        for (study_uid, views) in manifest {
+            assert!(studies.contains_key(study_uid));
+            assert_eq!(views.as_object().unwrap().len(), 4);
+        }
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_manifest_csv_leaves_missing_paths_empty() {
+        let records = vec![make_cli_test_record(
+            Laterality::Left,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            "1.2.826.0.42",
+        )];
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        let manifest = format_manifest(&selections, &OutputFormat::Csv).unwrap();
+
+        assert_eq!(
+            manifest,
+            "view,file_path\n\
+             left mlo,\n\
+             right mlo,\n\
+             left cc,1.2.826.0.42_Left_Cc.dcm\n\
+             right cc,\n"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_output_study_selections_ndjson_tags_study() {
+        let studies = select_preferred_views_by_study(
+            &make_two_study_records(),
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("studies.ndjson");
+
+        let exit_code = output_study_selections(
+            &studies,
+            &OutputFormat::Ndjson,
+            ViewLabelStyle::Compact,
+            Some(&output),
+        );
+        let text = std::fs::read_to_string(&output).unwrap();
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(exit_code, EXIT_SUCCESS);
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines[0]["study"], "1.2.826.0.36");
+        assert_eq!(lines[7]["study"], "1.2.826.0.37");
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_output_selections_yaml_round_trips_records() {
+        let study = "1.2.826.0.39";
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                let mut record =
+                    make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study);
+                record.metadata.manufacturer = Some("Vendor".to_string());
+                record
+            })
+            .collect();
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("selections.yaml");
+
+        let exit_code = output_selections(
+            &selections,
+            &OutputFormat::Yaml,
+            ViewLabelStyle::Compact,
+            Some(&output),
+        );
+        let yaml: serde_json::Value =
+            serde_yaml::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+
+        assert_eq!(exit_code, EXIT_SUCCESS);
+        assert_eq!(
+            yaml,
+            serde_json::to_value(selection_json(&selections)).unwrap()
+        );
+        for view in &STANDARD_MAMMO_VIEWS {
+            let record = selections[view].as_ref().unwrap();
+            let metadata: MammogramMetadata =
+                serde_json::from_value(yaml["selections"][view.to_string()]["metadata"].clone())
+                    .unwrap();
+            assert_eq!(metadata, record.metadata);
+        }
+    }
+
+    #[test]
+    fn test_output_study_selections_rejects_csv() {
+        let studies = StudySelections::new();
+
+        let exit_code =
+            output_study_selections(&studies, &OutputFormat::Csv, ViewLabelStyle::Compact, None);
+
+        assert_eq!(exit_code, EXIT_FAILURE);
+    }
+
+    #[test]
+    fn test_output_selections_writes_to_output_file() {
+        let study = "1.2.826.0.33";
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                make_cli_test_record(view.laterality, view.view, MammogramType::Ffdm, study)
+            })
+            .collect();
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("nested").join("paths.txt");
+
+        let exit_code = output_selections(
+            &selections,
+            &OutputFormat::Paths,
+            ViewLabelStyle::Compact,
+            Some(&output),
+        );
+
+        assert_eq!(exit_code, EXIT_SUCCESS);
+        assert_eq!(
+            std::fs::read_to_string(&output).unwrap(),
+            "1.2.826.0.33_Left_Mlo.dcm\n\
+             1.2.826.0.33_Right_Mlo.dcm\n\
+             1.2.826.0.33_Left_Cc.dcm\n\
+             1.2.826.0.33_Right_Cc.dcm\n"
+        );
+    }
+
+    /// Creates a complete four-view study whose records point at real files in `dir`.
+    fn write_complete_study_files(dir: &Path) -> HashMap<MammogramView, Option<MammogramRecord>> {
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                let mut record = make_cli_test_record(
+                    view.laterality,
+                    view.view,
+                    MammogramType::Ffdm,
+                    "1.2.826.0.40",
+                );
+                record.file_path = dir.join(&record.file_path);
+                std::fs::write(&record.file_path, view.to_string()).unwrap();
+                record
+            })
+            .collect();
+        let (selections, _) = select_preferred_views(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+        selections
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_view_files_links_each_view() {
+        let temp_dir = TempDir::new().unwrap();
+        let selections = write_complete_study_files(temp_dir.path());
+        let output_dir = temp_dir.path().join("views");
+
+        let written = write_view_files(
+            &selections,
+            &output_dir,
+            ExportMode::Link,
+            &ViewFileNaming::Compact,
+        )
+        .unwrap();
+
+        let names: Vec<_> = written
+            .iter()
+            .map(|path| path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["lmlo.dcm", "rmlo.dcm", "lcc.dcm", "rcc.dcm"]);
+        for path in &written {
+            assert!(path.symlink_metadata().unwrap().file_type().is_symlink());
+        }
+        assert_eq!(
+            std::fs::read_to_string(output_dir.join("rcc.dcm")).unwrap(),
+            "rcc"
+        );
+
+        // Re-running replaces the existing links instead of failing
+        assert_eq!(
+            write_view_files(
+                &selections,
+                &output_dir,
+                ExportMode::Link,
+                &ViewFileNaming::Compact
+            )
+            .unwrap()
+            .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_write_view_files_copies_and_skips_missing_views() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut selections = write_complete_study_files(temp_dir.path());
+        selections.insert(STANDARD_MAMMO_VIEWS[0], None);
+        let output_dir = temp_dir.path().join("copies");
+
+        let written = write_view_files(
+            &selections,
+            &output_dir,
+            ExportMode::Copy,
+            &ViewFileNaming::Compact,
+        )
+        .unwrap();
+
+        assert_eq!(written.len(), 3);
+        assert!(!output_dir.join("lmlo.dcm").exists());
+        let lcc = output_dir.join("lcc.dcm");
+        assert!(!lcc.symlink_metadata().unwrap().file_type().is_symlink());
+        assert_eq!(std::fs::read_to_string(lcc).unwrap(), "lcc");
+    }
+
+    #[test]
+    fn test_write_view_files_keeps_source_already_at_destination() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut selections = write_complete_study_files(temp_dir.path());
+        let lcc = STANDARD_MAMMO_VIEWS[2];
+        let source = temp_dir.path().join("lcc.dcm");
+        std::fs::write(&source, "source lcc").unwrap();
+        if let Some(Some(record)) = selections.get_mut(&lcc) {
+            record.file_path = source.clone();
+        }
+
+        for mode in [ExportMode::Copy, ExportMode::Link] {
+            let written =
+                write_view_files(&selections, temp_dir.path(), mode, &ViewFileNaming::Compact)
+                    .unwrap();
+
+            assert_eq!(written.len(), 4);
+            assert!(!source.symlink_metadata().unwrap().file_type().is_symlink());
+            assert_eq!(std::fs::read_to_string(&source).unwrap(), "source lcc");
+        }
+    }
+
+    #[test]
+    fn test_write_view_files_rejects_duplicate_destinations() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut selections = write_complete_study_files(temp_dir.path());
+        for record in selections.values_mut().flatten() {
+            record.sop_instance_uid = None;
+        }
+        let output_dir = temp_dir.path().join("named");
+        let naming = ViewFileNaming::Template(NameTemplate::new("{sop_uid}.dcm").unwrap());
+
+        let error =
+            write_view_files(&selections, &output_dir, ExportMode::Copy, &naming).unwrap_err();
+
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("unknown.dcm"));
+        assert!(!output_dir.exists());
+    }
+
+    #[test]
+    fn test_study_dir_name_rejects_path_components() {
+        assert_eq!(study_dir_name("1.2.840.1").unwrap(), "1.2.840.1");
+        for study_uid in ["", ".", "..", "../../x", "/tmp/x", "a/b", "a\\b"] {
+            assert!(study_dir_name(study_uid).is_err(), "{study_uid:?}");
+        }
+    }
+
+    #[test]
+    fn test_copy_to_writes_laterality_view_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let selections = write_complete_study_files(temp_dir.path());
+        let copy_dir = temp_dir.path().join("nested").join("selected");
+        let cli = Cli::try_parse_from([
+            "mammoselect",
+            "dir",
+            "--copy-to",
+            copy_dir.to_str().unwrap(),
+        ])
+        .unwrap();
+        let exports = ViewExport::from_cli(&cli);
+        assert_eq!(
+            exports,
+            vec![ViewExport {
+                dir: copy_dir.clone(),
+                mode: ExportMode::Copy,
+                naming: ViewFileNaming::Template(NameTemplate::default()),
+            }]
+        );
+
+        let written =
+            write_view_files(&selections, &copy_dir, exports[0].mode, &exports[0].naming).unwrap();
+
+        let names: Vec<_> = written
+            .iter()
+            .map(|path| path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "left_mlo.dcm",
+                "right_mlo.dcm",
+                "left_cc.dcm",
+                "right_cc.dcm"
+            ]
+        );
+        for path in &written {
+            assert!(!path.symlink_metadata().unwrap().file_type().is_symlink());
+        }
+        assert_eq!(
+            std::fs::read_to_string(copy_dir.join("right_cc.dcm")).unwrap(),
+            "rcc"
+        );
+    }
+
+    #[test]
+    fn test_symlink_to_uses_link_mode() {
+        let cli = Cli::try_parse_from([
+            "mammoselect",
+            "dir",
+            "--output-dir",
+            "out",
+            "--symlink-to",
+            "links",
+        ])
+        .unwrap();
+
+        let exports = ViewExport::from_cli(&cli);
+
+        assert_eq!(exports.len(), 2);
+        assert_eq!(exports[0].naming, ViewFileNaming::Compact);
+        assert_eq!(exports[1].dir, PathBuf::from("links"));
+        assert_eq!(exports[1].mode, ExportMode::Link);
+        assert_eq!(
+            exports[1].naming,
+            ViewFileNaming::Template(NameTemplate::default())
+        );
+    }
+
+    #[test]
+    fn test_name_template_names_exported_views() {
+        let temp_dir = TempDir::new().unwrap();
+        let selections = write_complete_study_files(temp_dir.path());
+        let cli = Cli::try_parse_from([
+            "mammoselect",
+            "dir",
+            "--symlink-to",
+            "links",
+            "--name-template",
+            "{type}-{laterality}{view}-{manufacturer}.dcm",
+        ])
+        .unwrap();
+        let exports = ViewExport::from_cli(&cli);
+        let output_dir = temp_dir.path().join("named");
+
+        let written = write_view_files(
+            &selections,
+            &output_dir,
+            ExportMode::Copy,
+            &exports[0].naming,
+        )
+        .unwrap();
+
+        assert_eq!(written[0].file_name().unwrap(), "ffdm-leftmlo-unknown.dcm");
+    }
+
+    #[test]
+    fn test_name_template_rejects_unknown_placeholders() {
+        let error = Cli::try_parse_from([
+            "mammoselect",
+            "dir",
+            "--copy-to",
+            "out",
+            "--name-template",
+            "{series}.dcm",
+        ])
+        .unwrap_err();
+
+        assert!(error.to_string().contains("unknown placeholder {series}"));
+    }
+
+    #[test]
+    fn test_copy_requires_output_dir() {
+        assert!(Cli::try_parse_from(["mammoselect", "dir", "--copy"]).is_err());
+        assert!(Cli::try_parse_from([
+            "mammoselect",
+            "dir",
+            "--output-dir",
+            "out",
+            "--copy",
+            "--link"
+        ])
+        .is_err());
+        let cli = Cli::try_parse_from(["mammoselect", "dir", "--output-dir", "out"]).unwrap();
+        assert_eq!(ExportMode::from_cli(&cli), ExportMode::Link);
+    }
+
+    #[test]
+    fn test_output_selections_fails_for_unwritable_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let selections = HashMap::new();
+
+        let exit_code = output_selections(
+            &selections,
+            &OutputFormat::Paths,
+            ViewLabelStyle::Compact,
+            Some(temp_dir.path()),
+        );
+
+        assert_eq!(exit_code, EXIT_FAILURE);
+    }
+
     #[test]
     fn test_select_preferred_views_strict_errors_for_multiple_studies() {
         let records = vec![
@@ -159,6 +159,7 @@ enum MammogramTypeArg {
     Tomo,
     Synth,
     Sfm,
+    Cem,
 }
 
 impl From<MammogramTypeArg> for MammogramType {
@@ -168,6 +169,7 @@ impl From<MammogramTypeArg> for MammogramType {
             MammogramTypeArg::Tomo => MammogramType::Tomo,
             MammogramTypeArg::Synth => MammogramType::Synth,
             MammogramTypeArg::Sfm => MammogramType::Sfm,
+            MammogramTypeArg::Cem => MammogramType::Cem,
         }
     }
 }
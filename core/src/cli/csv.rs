@@ -0,0 +1,182 @@
+use crate::api::MammogramMetadata;
+use std::borrow::Cow;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Column names written by [`CsvReport`]
+pub const METADATA_CSV_HEADER: [&str; 16] = [
+    "type",
+    "dbt_object_kind",
+    "laterality",
+    "view_position",
+    "image_type",
+    "manufacturer",
+    "model",
+    "frames",
+    "pixel_spacing_row",
+    "pixel_spacing_col",
+    "is_for_processing",
+    "has_implant",
+    "is_secondary_capture",
+    "modality",
+    "transfer_syntax_uid",
+    "compression_type",
+];
+
+/// CSV report for mammogram metadata
+///
+/// Writes a header line followed by a single metadata row. Missing values are
+/// written as empty cells.
+pub struct CsvReport<'a> {
+    metadata: &'a MammogramMetadata,
+}
+
+impl<'a> CsvReport<'a> {
+    /// Creates a new CSV report
+    pub fn new(metadata: &'a MammogramMetadata) -> Self {
+        Self { metadata }
+    }
+
+    /// Returns the unquoted cell values, in [`METADATA_CSV_HEADER`] order
+    fn row_fields(&self) -> [String; METADATA_CSV_HEADER.len()] {
+        let metadata = self.metadata;
+        let (spacing_row, spacing_col) = match metadata.pixel_spacing {
+            Some(pixel_spacing) => (pixel_spacing.row.to_string(), pixel_spacing.col.to_string()),
+            None => (String::new(), String::new()),
+        };
+        [
+            metadata.mammogram_type.simple_name().to_string(),
+            metadata.dbt_object_kind.simple_name().to_string(),
+            metadata.laterality.simple_name().to_string(),
+            metadata.view_position.simple_name().to_string(),
+            metadata.image_type.to_string(),
+            metadata.manufacturer.clone().unwrap_or_default(),
+            metadata.model.clone().unwrap_or_default(),
+            metadata.number_of_frames.to_string(),
+            spacing_row,
+            spacing_col,
+            metadata.is_for_processing.to_string(),
+            metadata.has_implant.to_string(),
+            metadata.is_secondary_capture.to_string(),
+            metadata.modality.clone().unwrap_or_default(),
+            metadata.transfer_syntax_uid.clone().unwrap_or_default(),
+            metadata.compression_type.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+impl<'a> fmt::Display for CsvReport<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", format_csv_row(METADATA_CSV_HEADER))?;
+        write!(f, "{}", format_csv_row(self.row_fields()))
+    }
+}
+
+/// Formats metadata for several files as one CSV table
+///
+/// Writes a single header line with a leading `file_path` column, followed
+/// by one row per file in input order. A single file keeps the
+/// [`CsvReport`] layout without the path column.
+pub fn format_csv_reports(files: &[(PathBuf, MammogramMetadata)]) -> String {
+    if let [(_, metadata)] = files {
+        return CsvReport::new(metadata).to_string();
+    }
+
+    let header = std::iter::once("file_path").chain(METADATA_CSV_HEADER);
+    let mut lines = vec![format_csv_row(header)];
+    for (path, metadata) in files {
+        let fields = std::iter::once(path.display().to_string())
+            .chain(CsvReport::new(metadata).row_fields());
+        lines.push(format_csv_row(fields));
+    }
+    lines.join("\n")
+}
+
+/// Joins fields into one CSV line, quoting fields where needed
+pub fn format_csv_row<I, S>(fields: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    fields
+        .into_iter()
+        .map(|field| escape_csv_field(field.as_ref()).into_owned())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Quotes a CSV field containing commas, quotes, or line breaks
+///
+/// Embedded quotes are doubled as described in RFC 4180.
+pub fn escape_csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_metadata;
+    use crate::types::PixelSpacing;
+
+    fn test_metadata() -> MammogramMetadata {
+        MammogramMetadata {
+            manufacturer: Some("Vendor, Inc.".to_string()),
+            model: Some("Test Model".to_string()),
+            pixel_spacing: Some(PixelSpacing::new(0.07, 0.07)),
+            transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
+            ..sample_metadata()
+        }
+    }
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("plain"), "plain");
+        assert_eq!(escape_csv_field("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_csv_field(""), "");
+    }
+
+    #[test]
+    fn test_format_csv_reports_adds_path_column_for_multiple_files() {
+        let metadata = test_metadata();
+        let single = format_csv_reports(&[(PathBuf::from("a.dcm"), metadata.clone())]);
+        let multiple = format_csv_reports(&[
+            (PathBuf::from("a.dcm"), metadata.clone()),
+            (PathBuf::from("b,c.dcm"), metadata.clone()),
+        ]);
+        let lines: Vec<&str> = multiple.lines().collect();
+
+        assert_eq!(single, CsvReport::new(&metadata).to_string());
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("file_path,type,"));
+        assert!(lines[1].starts_with("a.dcm,ffdm,"));
+        assert!(lines[2].starts_with("\"b,c.dcm\",ffdm,"));
+    }
+
+    #[test]
+    fn test_csv_report_writes_header_and_single_row() {
+        let metadata = test_metadata();
+        let output = CsvReport::new(&metadata).to_string();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            "type,dbt_object_kind,laterality,view_position,image_type,manufacturer,model,frames,\
+             pixel_spacing_row,pixel_spacing_col,is_for_processing,has_implant,\
+             is_secondary_capture,modality,transfer_syntax_uid,compression_type"
+        );
+        assert_eq!(
+            lines[1],
+            format!(
+                "ffdm,none,left,cc,{},\"Vendor, Inc.\",Test Model,1,0.07,0.07,false,false,false,MG,\
+                 1.2.840.10008.1.2.1,",
+                metadata.image_type
+            )
+        );
+    }
+}
@@ -1,7 +1,14 @@
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod naming;
 pub mod report;
 
+use crate::api::MammogramMetadata;
 use clap::{Parser, ValueEnum};
-use std::path::PathBuf;
+use report::{ReportIdentifiers, TextReport};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 /// Command-line arguments for mammocat
 #[derive(Parser, Debug)]
@@ -9,19 +16,143 @@ use std::path::PathBuf;
 #[command(about = "DICOM mammography metadata extraction tool")]
 #[command(version)]
 pub struct Cli {
-    /// Path to DICOM file
-    #[arg(value_name = "FILE")]
-    pub file: PathBuf,
+    /// Paths to one or more DICOM files
+    #[arg(value_name = "FILE", required = true, num_args = 1..)]
+    pub files: Vec<PathBuf>,
 
     /// Output format
     #[arg(short, long, default_value = "text")]
     pub format: OutputFormat,
 
+    /// Units for physical lengths such as pixel spacing in the text report
+    #[arg(long, default_value = "mm")]
+    pub units: LengthUnit,
+
+    /// Write the report to this file instead of stdout
+    ///
+    /// Missing parent directories are created.
+    #[arg(short, long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    /// Classify files even when their Modality tag is not "MG"
+    ///
+    /// Useful for research datasets with mislabeled modality tags.
+    #[arg(long)]
+    pub ignore_modality: bool,
+
     /// Verbose logging
     #[arg(short, long)]
     pub verbose: bool,
 }
 
+/// Formats text reports for one or more files
+///
+/// A single file produces exactly one [`TextReport`]. With several files,
+/// each report is preceded by a `==> path <==` header line and reports are
+/// separated by a blank line.
+pub fn format_text_reports(files: &[(PathBuf, MammogramMetadata)], units: LengthUnit) -> String {
+    format_report_blocks(
+        files
+            .iter()
+            .map(|(path, metadata)| (path, TextReport::new(metadata).with_units(units)))
+            .collect(),
+    )
+}
+
+/// Formats text reports that also print each file's Study/SOP UIDs
+///
+/// Same layout as [`format_text_reports`]; UIDs missing from a file's
+/// [`ReportIdentifiers`] are left out of its report.
+pub fn format_text_reports_with_identifiers(
+    files: &[(PathBuf, MammogramMetadata, ReportIdentifiers)],
+    units: LengthUnit,
+) -> String {
+    format_report_blocks(
+        files
+            .iter()
+            .map(|(path, metadata, identifiers)| {
+                let report = TextReport::new(metadata)
+                    .with_units(units)
+                    .with_identifiers(identifiers);
+                (path, report)
+            })
+            .collect(),
+    )
+}
+
+fn format_report_blocks(reports: Vec<(&PathBuf, TextReport<'_>)>) -> String {
+    if let [(_, report)] = reports.as_slice() {
+        return report.to_string();
+    }
+
+    reports
+        .iter()
+        .map(|(path, report)| format!("==> {} <==\n{}", path.display(), report))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Formats metadata for one or more files as pretty-printed JSON
+///
+/// A single file produces one metadata object. Several files produce an
+/// array of `{"file_path": ..., "metadata": {...}}` objects in input order.
+#[cfg(feature = "json")]
+pub fn format_json_reports(files: &[(PathBuf, MammogramMetadata)]) -> serde_json::Result<String> {
+    if let [(_, metadata)] = files {
+        return serde_json::to_string_pretty(metadata);
+    }
+    serde_json::to_string_pretty(&file_reports(files))
+}
+
+/// Formats metadata for one or more files as YAML
+///
+/// Uses the same structure as [`format_json_reports`]: a single metadata
+/// mapping for one file, or a sequence of `file_path`/`metadata` mappings.
+#[cfg(feature = "yaml")]
+pub fn format_yaml_reports(files: &[(PathBuf, MammogramMetadata)]) -> serde_yaml::Result<String> {
+    if let [(_, metadata)] = files {
+        return serde_yaml::to_string(metadata);
+    }
+    serde_yaml::to_string(&file_reports(files))
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct FileReport<'a> {
+    file_path: String,
+    metadata: &'a MammogramMetadata,
+}
+
+#[cfg(feature = "json")]
+fn file_reports(files: &[(PathBuf, MammogramMetadata)]) -> Vec<FileReport<'_>> {
+    files
+        .iter()
+        .map(|(path, metadata)| FileReport {
+            file_path: path.display().to_string(),
+            metadata,
+        })
+        .collect()
+}
+
+/// Writes formatted CLI output to a file, or to stdout when `output` is None
+///
+/// Missing parent directories of the output file are created first.
+pub fn write_output(content: &str, output: Option<&Path>) -> io::Result<()> {
+    match output {
+        Some(path) => {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, content)
+        }
+        None => {
+            let mut stdout = io::stdout().lock();
+            stdout.write_all(content.as_bytes())?;
+            stdout.flush()
+        }
+    }
+}
+
 /// Output format options
 #[derive(Debug, Clone, ValueEnum)]
 pub enum OutputFormat {
@@ -29,4 +160,179 @@ pub enum OutputFormat {
     Text,
     /// JSON format
     Json,
+    /// CSV format with a header line and one metadata row
+    Csv,
+    /// YAML format
+    Yaml,
+}
+
+/// Units for physical lengths in the text report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum LengthUnit {
+    /// Millimeters
+    #[default]
+    Mm,
+    /// Micrometers
+    Um,
+}
+
+impl LengthUnit {
+    /// Converts a length in millimeters to this unit
+    ///
+    /// Micrometer values are rounded to nanometer precision so that
+    /// floating-point noise such as `94.00000000000001` is not printed.
+    pub fn from_mm(self, millimeters: f64) -> f64 {
+        match self {
+            LengthUnit::Mm => millimeters,
+            LengthUnit::Um => (millimeters * 1_000_000.0).round() / 1000.0,
+        }
+    }
+
+    /// Returns the unit symbol used in reports
+    pub fn symbol(self) -> &'static str {
+        match self {
+            LengthUnit::Mm => "mm",
+            LengthUnit::Um => "µm",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_metadata;
+    use crate::types::Laterality;
+    use tempfile::TempDir;
+
+    fn test_metadata(laterality: Laterality) -> MammogramMetadata {
+        MammogramMetadata {
+            laterality,
+            ..sample_metadata()
+        }
+    }
+
+    fn two_files() -> Vec<(PathBuf, MammogramMetadata)> {
+        vec![
+            (PathBuf::from("lcc.dcm"), test_metadata(Laterality::Left)),
+            (PathBuf::from("rcc.dcm"), test_metadata(Laterality::Right)),
+        ]
+    }
+
+    #[test]
+    fn test_cli_requires_at_least_one_file() {
+        assert!(Cli::try_parse_from(["mammocat"]).is_err());
+        let cli = Cli::try_parse_from(["mammocat", "a.dcm", "b.dcm"]).unwrap();
+        assert_eq!(
+            cli.files,
+            vec![PathBuf::from("a.dcm"), PathBuf::from("b.dcm")]
+        );
+    }
+
+    #[test]
+    fn test_format_text_reports_single_file_has_no_header() {
+        let files = &two_files()[..1];
+        let output = format_text_reports(files, LengthUnit::Mm);
+
+        assert_eq!(output, TextReport::new(&files[0].1).to_string());
+        assert!(!output.contains("==>"));
+    }
+
+    #[test]
+    fn test_format_text_reports_writes_block_per_file() {
+        let output = format_text_reports(&two_files(), LengthUnit::Mm);
+
+        assert_eq!(output.matches("Mammogram Metadata\n").count(), 2);
+        let lcc = output.find("==> lcc.dcm <==\nMammogram Metadata").unwrap();
+        let rcc = output.find("==> rcc.dcm <==\nMammogram Metadata").unwrap();
+        assert!(lcc < rcc);
+        assert!(!output.contains("Study UID"));
+    }
+
+    #[test]
+    fn test_format_text_reports_with_identifiers_keeps_uids_with_their_file() {
+        let identified = |identifiers| {
+            two_files()
+                .into_iter()
+                .zip(identifiers)
+                .map(|((path, metadata), identifiers)| (path, metadata, identifiers))
+                .collect::<Vec<_>>()
+        };
+        let files = identified([
+            ReportIdentifiers {
+                study_instance_uid: Some("1.2.826.0.1".to_string()),
+                sop_instance_uid: None,
+            },
+            ReportIdentifiers::default(),
+        ]);
+        let output = format_text_reports_with_identifiers(&files, LengthUnit::Mm);
+
+        let rcc = output.find("==> rcc.dcm <==").unwrap();
+        assert_eq!(output.matches("Study UID").count(), 1);
+        assert!(output.find("Study UID").unwrap() < rcc);
+        assert_eq!(
+            format_text_reports_with_identifiers(&files[..1], LengthUnit::Mm),
+            TextReport::new(&files[0].1)
+                .with_identifiers(&files[0].2)
+                .to_string()
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_format_json_reports_emits_array_for_multiple_files() {
+        let files = two_files();
+
+        let multiple: serde_json::Value =
+            serde_json::from_str(&format_json_reports(&files).unwrap()).unwrap();
+        let single: serde_json::Value =
+            serde_json::from_str(&format_json_reports(&files[..1]).unwrap()).unwrap();
+
+        let reports = multiple.as_array().unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0]["file_path"], "lcc.dcm");
+        assert_eq!(reports[1]["metadata"]["laterality"], "right");
+        assert_eq!(single["laterality"], "left");
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_format_yaml_reports_round_trips_metadata() {
+        let mut files = two_files();
+        files[0].1.manufacturer = Some("Vendor".to_string());
+        files[0].1.pixel_spacing = Some(crate::types::PixelSpacing::new(0.07, 0.07));
+
+        let single: MammogramMetadata =
+            serde_yaml::from_str(&format_yaml_reports(&files[..1]).unwrap()).unwrap();
+        let multiple: serde_yaml::Value =
+            serde_yaml::from_str(&format_yaml_reports(&files).unwrap()).unwrap();
+
+        assert_eq!(single, files[0].1);
+        let reports = multiple.as_sequence().unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[1]["file_path"].as_str(), Some("rcc.dcm"));
+        let second: MammogramMetadata =
+            serde_yaml::from_value(reports[1]["metadata"].clone()).unwrap();
+        assert_eq!(second, files[1].1);
+    }
+
+    #[test]
+    fn test_write_output_creates_parent_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir
+            .path()
+            .join("reports")
+            .join("study")
+            .join("out.txt");
+
+        write_output("report\n", Some(&path)).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "report\n");
+    }
+
+    #[test]
+    fn test_write_output_reports_unwritable_target() {
+        let temp_dir = TempDir::new().unwrap();
+
+        assert!(write_output("report\n", Some(temp_dir.path())).is_err());
+    }
 }
@@ -0,0 +1,264 @@
+use crate::error::{MammocatError, Result};
+use crate::selection::MammogramRecord;
+use crate::types::MammogramView;
+use std::fmt;
+use std::str::FromStr;
+
+/// Template used when no `--name-template` is given
+pub const DEFAULT_NAME_TEMPLATE: &str = "{laterality}_{view}.dcm";
+
+/// Placeholders accepted by [`NameTemplate`]
+pub const NAME_TEMPLATE_PLACEHOLDERS: [&str; 6] = [
+    "laterality",
+    "view",
+    "type",
+    "manufacturer",
+    "sop_uid",
+    "stem",
+];
+
+/// Value substituted for placeholders whose field is missing
+const MISSING_VALUE: &str = "unknown";
+
+/// File name template for exported selections
+///
+/// Placeholders are written in braces, e.g. `{laterality}_{view}.dcm`:
+///
+/// - `{laterality}`: laterality of the selected view, `left` or `right`
+/// - `{view}`: view position of the selected view, `mlo` or `cc`
+/// - `{type}`: mammogram type such as `ffdm` or `synth`
+/// - `{manufacturer}`: Manufacturer tag value
+/// - `{sop_uid}`: SOPInstanceUID
+/// - `{stem}`: source file name without its extension
+///
+/// Templates must name each view uniquely, so they need either both
+/// `{laterality}` and `{view}` or `{sop_uid}`. Missing values render as
+/// `unknown`, and path separators in values are replaced with `_` so a
+/// rendered name never leaves the output directory.
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::cli::naming::NameTemplate;
+///
+/// assert!("{view}_{sop_uid}.dcm".parse::<NameTemplate>().is_ok());
+/// assert!("{series}.dcm".parse::<NameTemplate>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameTemplate {
+    template: String,
+}
+
+impl NameTemplate {
+    /// Parses and validates a template
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown placeholders, unbalanced braces, or a
+    /// template that would not distinguish the four standard views.
+    pub fn new(template: &str) -> Result<Self> {
+        let mut placeholders = Vec::new();
+        for segment in segments(template)? {
+            if let Segment::Placeholder(name) = segment {
+                if !NAME_TEMPLATE_PLACEHOLDERS.contains(&name) {
+                    return Err(MammocatError::InvalidValue(format!(
+                        "unknown placeholder {{{name}}} in name template; expected one of {}",
+                        NAME_TEMPLATE_PLACEHOLDERS
+                            .map(|name| format!("{{{name}}}"))
+                            .join(", ")
+                    )));
+                }
+                placeholders.push(name);
+            }
+        }
+        let names_view = placeholders.contains(&"laterality") && placeholders.contains(&"view");
+        if !names_view && !placeholders.contains(&"sop_uid") {
+            return Err(MammocatError::InvalidValue(format!(
+                "name template {template:?} must include {{laterality}} and {{view}}, or {{sop_uid}}, so each view gets its own file name"
+            )));
+        }
+        Ok(Self {
+            template: template.to_string(),
+        })
+    }
+
+    /// Renders the file name for the record selected for `view`
+    pub fn render(&self, view: &MammogramView, record: &MammogramRecord) -> String {
+        let segments = segments(&self.template).expect("template validated in NameTemplate::new");
+        segments
+            .into_iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text.to_string(),
+                Segment::Placeholder(name) => sanitize(&placeholder_value(name, view, record)),
+            })
+            .collect()
+    }
+
+    /// Returns the template text
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+}
+
+impl Default for NameTemplate {
+    fn default() -> Self {
+        Self {
+            template: DEFAULT_NAME_TEMPLATE.to_string(),
+        }
+    }
+}
+
+impl FromStr for NameTemplate {
+    type Err = MammocatError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for NameTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.template)
+    }
+}
+
+enum Segment<'a> {
+    Literal(&'a str),
+    Placeholder(&'a str),
+}
+
+fn segments(template: &str) -> Result<Vec<Segment<'_>>> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+    while !rest.is_empty() {
+        match rest.find(['{', '}']) {
+            Some(index) if rest[index..].starts_with('}') => {
+                return Err(unbalanced_braces(template));
+            }
+            Some(index) => {
+                if index > 0 {
+                    segments.push(Segment::Literal(&rest[..index]));
+                }
+                let after_open = &rest[index + 1..];
+                let close = after_open
+                    .find('}')
+                    .filter(|&close| !after_open[..close].contains('{'))
+                    .ok_or_else(|| unbalanced_braces(template))?;
+                segments.push(Segment::Placeholder(&after_open[..close]));
+                rest = &after_open[close + 1..];
+            }
+            None => {
+                segments.push(Segment::Literal(rest));
+                rest = "";
+            }
+        }
+    }
+    Ok(segments)
+}
+
+fn unbalanced_braces(template: &str) -> MammocatError {
+    MammocatError::InvalidValue(format!("unbalanced braces in name template {template:?}"))
+}
+
+fn placeholder_value(name: &str, view: &MammogramView, record: &MammogramRecord) -> String {
+    let value = match name {
+        "laterality" => Some(view.laterality.simple_name().to_string()),
+        "view" => Some(view.view.short_str().to_string()),
+        "type" => Some(record.metadata.mammogram_type.serialized_name().to_string()),
+        "manufacturer" => record.metadata.manufacturer.clone(),
+        "sop_uid" => record.sop_instance_uid.clone(),
+        "stem" => record
+            .file_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned()),
+        _ => None,
+    };
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| MISSING_VALUE.to_string())
+}
+
+fn sanitize(value: &str) -> String {
+    value.replace(['/', '\\'], "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::MammogramMetadata;
+    use crate::test_support::{sample_metadata, sample_record};
+    use crate::types::{Laterality, ViewPosition};
+
+    fn lmlo() -> MammogramView {
+        MammogramView::new(Laterality::Left, ViewPosition::Mlo)
+    }
+
+    fn lmlo_record() -> MammogramRecord {
+        MammogramRecord {
+            sop_instance_uid: Some("1.2.3.4.5".to_string()),
+            ..sample_record(
+                "/data/study/IMG0001.dcm",
+                MammogramMetadata {
+                    view_position: ViewPosition::Mlo,
+                    manufacturer: Some("HOLOGIC, Inc./Selenia".to_string()),
+                    ..sample_metadata()
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn renders_every_placeholder() {
+        let template: NameTemplate =
+            "{laterality}_{view}_{type}_{manufacturer}_{sop_uid}_{stem}.dcm"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            template.render(&lmlo(), &lmlo_record()),
+            "left_mlo_ffdm_HOLOGIC, Inc._Selenia_1.2.3.4.5_IMG0001.dcm"
+        );
+        assert_eq!(
+            NameTemplate::default().render(&lmlo(), &lmlo_record()),
+            "left_mlo.dcm"
+        );
+    }
+
+    #[test]
+    fn missing_optional_fields_render_as_unknown() {
+        let mut record = lmlo_record();
+        record.metadata.manufacturer = None;
+        record.sop_instance_uid = Some("  ".to_string());
+        let template = NameTemplate::new("{manufacturer}-{sop_uid}.dcm").unwrap();
+
+        assert_eq!(template.render(&lmlo(), &record), "unknown-unknown.dcm");
+    }
+
+    #[test]
+    fn rejects_invalid_templates() {
+        let unknown = NameTemplate::new("{series}.dcm").unwrap_err();
+        assert!(unknown.to_string().contains("unknown placeholder {series}"));
+
+        assert!(NameTemplate::new("{view.dcm").is_err());
+        assert!(NameTemplate::new("view}.dcm").is_err());
+        assert!(NameTemplate::new("{{view}}.dcm").is_err());
+        assert!(NameTemplate::new("selected.dcm").is_err());
+    }
+
+    #[test]
+    fn rejects_templates_that_repeat_names_across_views() {
+        for template in [
+            "{type}.dcm",
+            "{manufacturer}.dcm",
+            "{stem}.dcm",
+            "{view}.dcm",
+        ] {
+            let error = NameTemplate::new(template).unwrap_err();
+            assert!(error.to_string().contains("must include"), "{template}");
+        }
+
+        assert!(NameTemplate::new("{laterality}-{view}-{stem}.dcm").is_ok());
+        assert!(NameTemplate::new("{stem}_{sop_uid}.dcm").is_ok());
+    }
+}
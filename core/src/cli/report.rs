@@ -1,17 +1,58 @@
+use super::LengthUnit;
 use crate::api::MammogramMetadata;
+use crate::extraction::tags::{get_string_value, SOP_INSTANCE_UID, STUDY_INSTANCE_UID};
+use crate::types::PixelSpacing;
+use dicom_object::InMemDicomObject;
 use std::fmt;
 
 const FIELD_LABEL_WIDTH: usize = "Concat Source SOP UID".len();
 
+/// DICOM identifiers printed by [`TextReport`] for cross-referencing with a PACS
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReportIdentifiers {
+    /// StudyInstanceUID, when present
+    pub study_instance_uid: Option<String>,
+    /// SOPInstanceUID, when present
+    pub sop_instance_uid: Option<String>,
+}
+
+impl ReportIdentifiers {
+    /// Reads the identifiers from a DICOM dataset
+    pub fn from_dicom(dcm: &InMemDicomObject) -> Self {
+        Self {
+            study_instance_uid: get_string_value(dcm, STUDY_INSTANCE_UID),
+            sop_instance_uid: get_string_value(dcm, SOP_INSTANCE_UID),
+        }
+    }
+}
+
 /// Text report formatter for mammogram metadata
 pub struct TextReport<'a> {
     metadata: &'a MammogramMetadata,
+    identifiers: Option<&'a ReportIdentifiers>,
+    units: LengthUnit,
 }
 
 impl<'a> TextReport<'a> {
-    /// Creates a new text report
+    /// Creates a new text report with lengths in millimeters
     pub fn new(metadata: &'a MammogramMetadata) -> Self {
-        Self { metadata }
+        Self {
+            metadata,
+            identifiers: None,
+            units: LengthUnit::default(),
+        }
+    }
+
+    /// Prints the Study and SOP UIDs that are present
+    pub fn with_identifiers(mut self, identifiers: &'a ReportIdentifiers) -> Self {
+        self.identifiers = Some(identifiers);
+        self
+    }
+
+    /// Sets the units used for physical lengths such as pixel spacing
+    pub fn with_units(mut self, units: LengthUnit) -> Self {
+        self.units = units;
+        self
     }
 }
 
@@ -20,6 +61,14 @@ impl<'a> fmt::Display for TextReport<'a> {
         writeln!(f, "Mammogram Metadata")?;
         writeln!(f, "==================")?;
         writeln!(f)?;
+        if let Some(identifiers) = self.identifiers {
+            if let Some(study_uid) = &identifiers.study_instance_uid {
+                write_field(f, "Study UID", study_uid)?;
+            }
+            if let Some(sop_uid) = &identifiers.sop_instance_uid {
+                write_field(f, "SOP UID", sop_uid)?;
+            }
+        }
         write_field(f, "Type", self.metadata.mammogram_type.simple_name())?;
         write_field(f, "DBT Object Kind", self.metadata.dbt_object_kind)?;
         write_field(f, "Laterality", self.metadata.laterality.simple_name())?;
@@ -41,9 +90,14 @@ impl<'a> fmt::Display for TextReport<'a> {
         )?;
         write_field(f, "Frames", self.metadata.number_of_frames)?;
         match self.metadata.pixel_spacing {
-            Some(pixel_spacing) => write_field(f, "Pixel Spacing", pixel_spacing)?,
+            Some(pixel_spacing) => write_field(
+                f,
+                "Pixel Spacing",
+                format_pixel_spacing(pixel_spacing, self.units),
+            )?,
             None => write_field(f, "Pixel Spacing", "unknown")?,
         }
+        write_field(f, "Photometric", self.metadata.photometric)?;
         write_field(
             f,
             "Concatenation UID",
@@ -65,12 +119,18 @@ impl<'a> fmt::Display for TextReport<'a> {
         write_field(f, "Implant Displaced", self.metadata.is_implant_displaced())?;
         write_field(f, "Spot Compression", self.metadata.is_spot_compression())?;
         write_field(f, "Magnification", self.metadata.is_magnified())?;
+        write_field(f, "Rolled", self.metadata.is_rolled())?;
         write_field(f, "Secondary Capture", self.metadata.is_secondary_capture)?;
         write_field(
             f,
             "Modality",
             self.metadata.modality.as_deref().unwrap_or("unknown"),
         )?;
+        write_field(
+            f,
+            "Character Set",
+            self.metadata.character_set.as_deref().unwrap_or("default"),
+        )?;
         write_field(
             f,
             "Transfer Syntax UID",
@@ -107,6 +167,15 @@ impl<'a> fmt::Display for TextReport<'a> {
     }
 }
 
+fn format_pixel_spacing(pixel_spacing: PixelSpacing, units: LengthUnit) -> String {
+    format!(
+        "{} x {} {}",
+        units.from_mm(pixel_spacing.row),
+        units.from_mm(pixel_spacing.col),
+        units.symbol()
+    )
+}
+
 fn write_field<T: fmt::Display>(f: &mut fmt::Formatter<'_>, label: &str, value: T) -> fmt::Result {
     writeln!(f, "{label:<FIELD_LABEL_WIDTH$}: {value}")
 }
@@ -114,29 +183,17 @@ fn write_field<T: fmt::Display>(f: &mut fmt::Formatter<'_>, label: &str, value:
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{DbtObjectKind, ImageType, Laterality, MammogramType, ViewPosition};
+    use crate::test_support::sample_metadata;
+    use crate::types::{DbtObjectKind, MammogramType};
 
     fn test_metadata() -> MammogramMetadata {
         MammogramMetadata {
-            mammogram_type: MammogramType::Ffdm,
-            dbt_object_kind: DbtObjectKind::None,
-            laterality: Laterality::Left,
-            view_position: ViewPosition::Cc,
-            view_modifiers: Default::default(),
-            image_type: ImageType::new("ORIGINAL".to_string(), "PRIMARY".to_string(), None, None),
-            is_for_processing: false,
-            has_implant: false,
             manufacturer: Some("Test Manufacturer".to_string()),
             model: Some("Test Model".to_string()),
-            number_of_frames: 1,
-            pixel_spacing: None,
-            concatenation_uid: None,
-            sop_instance_uid_of_concatenation_source: None,
-            is_secondary_capture: false,
-            modality: Some("MG".to_string()),
             transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
             transfer_syntax_name: Some("Explicit VR Little Endian".to_string()),
             compression_type: Some("uncompressed".to_string()),
+            ..sample_metadata()
         }
     }
 
@@ -155,6 +212,7 @@ mod tests {
         assert!(output.contains("Model"));
         assert!(output.contains("Frames"));
         assert!(output.contains("Pixel Spacing"));
+        assert!(output.contains("Photometric          : MONOCHROME2"));
         assert!(output.contains("Concatenation UID"));
         assert!(output.contains("Concat Source SOP UID"));
         assert!(output.contains("Transfer Syntax UID"));
@@ -197,6 +255,57 @@ mod tests {
         assert!(output.contains("Secondary Capture    : false"));
     }
 
+    #[test]
+    fn text_report_prints_pixel_spacing_in_millimeters_by_default() {
+        let mut metadata = test_metadata();
+        metadata.pixel_spacing = Some(PixelSpacing::new(0.094, 0.094));
+
+        let output = TextReport::new(&metadata).to_string();
+
+        assert!(output.contains("Pixel Spacing        : 0.094 x 0.094 mm"));
+    }
+
+    #[test]
+    fn text_report_converts_pixel_spacing_to_microns() {
+        let mut metadata = test_metadata();
+        metadata.pixel_spacing = Some(PixelSpacing::new(0.094, 0.07));
+
+        let output = TextReport::new(&metadata)
+            .with_units(LengthUnit::Um)
+            .to_string();
+
+        assert!(output.contains("Pixel Spacing        : 94 x 70 µm"));
+    }
+
+    #[test]
+    fn text_report_prints_uids_only_when_present() {
+        let metadata = test_metadata();
+        let identifiers = ReportIdentifiers {
+            study_instance_uid: Some("1.2.826.0.1".to_string()),
+            sop_instance_uid: Some("1.2.826.0.1.1".to_string()),
+        };
+
+        let output = TextReport::new(&metadata)
+            .with_identifiers(&identifiers)
+            .to_string();
+        assert!(output.contains("Study UID            : 1.2.826.0.1\n"));
+        assert!(output.contains("SOP UID              : 1.2.826.0.1.1\n"));
+
+        let partial = ReportIdentifiers {
+            study_instance_uid: None,
+            ..identifiers
+        };
+        let output = TextReport::new(&metadata)
+            .with_identifiers(&partial)
+            .to_string();
+        assert!(!output.contains("Study UID"));
+        assert!(output.contains("\nSOP UID"));
+
+        let output = TextReport::new(&metadata).to_string();
+        assert!(!output.contains("Study UID"));
+        assert!(!output.contains("\nSOP UID"));
+    }
+
     #[test]
     fn text_report_includes_slice_dbt_object_kind() {
         let mut metadata = test_metadata();
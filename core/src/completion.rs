@@ -1390,6 +1390,8 @@ fn modifier_confidence(
                         "PaddleDescription",
                         MammographyViewModifier::SpotCompression
                             | MammographyViewModifier::Magnification
+                            | MammographyViewModifier::RolledLateral
+                            | MammographyViewModifier::RolledMedial
                     )
                 )
         })
@@ -776,9 +776,9 @@ fn read_dicom_info_with_record(
     let info = dicom_info_from_file(input, path, &dcm);
     let record = if let Some(modality) = non_mg_modality(&info) {
         record_errors_as_warnings.then(|| {
-            Err(MammocatError::ExtractionError(format!(
-                "Expected modality=MG, found {modality}"
-            )))
+            Err(MammocatError::NotMammography {
+                found: modality.to_string(),
+            })
         })
     } else {
         Some(MammogramRecord::from_file_dicom(path.to_path_buf(), &dcm))
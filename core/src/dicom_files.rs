@@ -1,5 +1,6 @@
 //! Shared DICOM file discovery helpers for CLI tools.
 
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 use crate::extraction::tags::DICOM_MAGIC_BYTES;
@@ -20,10 +21,12 @@ struct InventoryFileKind {
 
 /// Collect DICOM file candidates from a directory.
 ///
-/// The scan is intentionally non-recursive to match `mammoselect` behavior.
-/// Files with `.dcm` or `.dicom` extensions are accepted directly. Files
-/// without an extension are accepted only when they contain the standard DICM
-/// magic bytes at offset 128.
+/// The scan is intentionally non-recursive to match default `mammoselect`
+/// behavior; see [`collect_dicom_files_recursively`] for `--recursive`.
+/// Files with `.dcm` or `.dicom` extensions (optionally gzip-compressed as
+/// `.dcm.gz`/`.dicom.gz`) are accepted directly. Files without an extension
+/// are accepted only when they contain the standard DICM magic bytes at
+/// offset 128.
 pub fn collect_dicom_files(directory: &Path) -> std::io::Result<Vec<PathBuf>> {
     let mut files = Vec::new();
 
@@ -43,16 +46,25 @@ pub fn collect_dicom_files(directory: &Path) -> std::io::Result<Vec<PathBuf>> {
 
 /// Collect DICOM file candidates recursively from a directory.
 ///
-/// This is used by collection-level planning, where callers commonly pass a
-/// study root containing per-series subdirectories.
+/// This is used by collection-level planning and `mammoselect --recursive`,
+/// where callers commonly pass a study root containing per-series
+/// subdirectories. Symbolic links are followed, but each directory is visited
+/// at most once by canonical path so link loops terminate.
 pub fn collect_dicom_files_recursively(directory: &Path) -> std::io::Result<Vec<PathBuf>> {
-    fn visit(directory: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    fn visit(
+        directory: &Path,
+        visited: &mut HashSet<PathBuf>,
+        files: &mut Vec<PathBuf>,
+    ) -> std::io::Result<()> {
+        if !visited.insert(directory.canonicalize()?) {
+            return Ok(());
+        }
         for entry in std::fs::read_dir(directory)? {
             let entry = entry?;
             let file_type = entry.file_type()?;
             let path = entry.path();
             if is_dir(&file_type, &path) {
-                visit(&path, files)?;
+                visit(&path, visited, files)?;
             } else if is_file(&file_type, &path) && is_dicom_candidate(&path) {
                 files.push(path);
             }
@@ -61,11 +73,88 @@ pub fn collect_dicom_files_recursively(directory: &Path) -> std::io::Result<Vec<
     }
 
     let mut files = Vec::new();
-    visit(directory, &mut files)?;
+    visit(directory, &mut HashSet::new(), &mut files)?;
     files.sort();
     Ok(files)
 }
 
+/// Lazily walk a directory, yielding DICOM file candidates as they are found.
+///
+/// Accepts the same files as [`collect_dicom_files`] and, when `recursive`
+/// is set, follows symbolic links with the same loop protection as
+/// [`collect_dicom_files_recursively`]. Only the open directory handles are
+/// held in memory, and paths come back in directory-listing order rather
+/// than sorted. I/O errors are yielded in place; the walk continues with
+/// the next entry or directory.
+pub(crate) fn walk_dicom_files(
+    directory: &Path,
+    recursive: bool,
+) -> impl Iterator<Item = std::io::Result<PathBuf>> {
+    DicomFileWalk {
+        recursive,
+        pending: vec![directory.to_path_buf()],
+        current: None,
+        visited: HashSet::new(),
+    }
+}
+
+struct DicomFileWalk {
+    recursive: bool,
+    pending: Vec<PathBuf>,
+    current: Option<std::fs::ReadDir>,
+    visited: HashSet<PathBuf>,
+}
+
+impl DicomFileWalk {
+    fn open_next_directory(&mut self) -> Option<std::io::Result<()>> {
+        let directory = self.pending.pop()?;
+        if self.recursive {
+            match directory.canonicalize() {
+                Ok(canonical) => {
+                    if !self.visited.insert(canonical) {
+                        return Some(Ok(()));
+                    }
+                }
+                Err(error) => return Some(Err(error)),
+            }
+        }
+        Some(std::fs::read_dir(directory).map(|entries| self.current = Some(entries)))
+    }
+}
+
+impl Iterator for DicomFileWalk {
+    type Item = std::io::Result<PathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(entries) = self.current.as_mut() else {
+                if let Err(error) = self.open_next_directory()? {
+                    return Some(Err(error));
+                }
+                continue;
+            };
+            let Some(entry) = entries.next() else {
+                self.current = None;
+                continue;
+            };
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(error) => return Some(Err(error)),
+            };
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(error) => return Some(Err(error)),
+            };
+            let path = entry.path();
+            if self.recursive && is_dir(&file_type, &path) {
+                self.pending.push(path);
+            } else if is_file(&file_type, &path) && is_dicom_candidate(&path) {
+                return Some(Ok(path));
+            }
+        }
+    }
+}
+
 /// Collect DICOM candidates recursively without following symbolic links.
 pub fn collect_dicom_files_recursively_no_symlinks(
     directory: &Path,
@@ -164,7 +253,7 @@ fn is_file(file_type: &std::fs::FileType, path: &Path) -> bool {
 }
 
 fn is_dicom_candidate(path: &Path) -> bool {
-    if has_dicom_candidate_extension(path) {
+    if has_dicom_candidate_extension(path) || is_gzipped_dicom_path(path) {
         return true;
     }
     path.extension().is_none() && is_dicom_file(path)
@@ -179,6 +268,7 @@ fn inventory_file_kind(path: &Path) -> InventoryFileKind {
     };
     InventoryFileKind {
         mammogram_candidate: has_dicom_candidate_extension
+            || is_gzipped_dicom_path(path)
             || (path.extension().is_none() && has_magic),
         dbt_scan_candidate: has_dicom_candidate_extension || has_magic,
     }
@@ -189,6 +279,36 @@ fn has_dicom_candidate_extension(path: &Path) -> bool {
         .is_some_and(|ext| ext.eq_ignore_ascii_case("dcm") || ext.eq_ignore_ascii_case("dicom"))
 }
 
+/// Check whether a path names a gzip-compressed DICOM file (`.dcm.gz` or `.dicom.gz`).
+///
+/// Only the file name is inspected; the file is not opened.
+pub fn is_gzipped_dicom_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gz"))
+        && path
+            .file_stem()
+            .is_some_and(|stem| has_dicom_candidate_extension(Path::new(stem)))
+}
+
+/// Length of the preamble plus `DICM` magic that preamble detection inspects
+const DICOM_HEADER_LEN: u64 = 132;
+
+/// Opens a gzip-compressed DICOM file as a streaming reader of its decompressed bytes.
+///
+/// The DICOM reader only looks at its first buffered read to detect the
+/// preamble, and a gzip decoder may return fewer bytes than that, so the
+/// header is decompressed up front and chained ahead of the rest of the stream.
+pub(crate) fn gzipped_dicom_reader(path: &Path) -> std::io::Result<impl std::io::Read> {
+    use std::io::{BufReader, Read};
+
+    let mut decoder = flate2::read::GzDecoder::new(BufReader::new(std::fs::File::open(path)?));
+    let mut header = Vec::with_capacity(DICOM_HEADER_LEN as usize);
+    (&mut decoder)
+        .take(DICOM_HEADER_LEN)
+        .read_to_end(&mut header)?;
+    Ok(std::io::Cursor::new(header).chain(decoder))
+}
+
 /// Check whether a file has the standard DICOM preamble and DICM magic bytes.
 pub fn is_dicom_file(path: &Path) -> bool {
     use std::fs::File;
@@ -208,6 +328,43 @@ mod tests {
     use super::*;
     use tempfile::tempdir;
 
+    #[test]
+    fn collect_dicom_files_only_recurses_in_recursive_mode() {
+        let directory = tempdir().unwrap();
+        let top_level = directory.path().join("top.dcm");
+        let nested = directory.path().join("series");
+        std::fs::create_dir(&nested).unwrap();
+        let nested_dicom = nested.join("image.dicom");
+        std::fs::write(&top_level, b"synthetic DICOM candidate").unwrap();
+        std::fs::write(&nested_dicom, b"synthetic DICOM candidate").unwrap();
+        std::fs::write(nested.join("notes.txt"), b"not DICOM").unwrap();
+
+        let flat = collect_dicom_files(directory.path()).unwrap();
+        let recursive = collect_dicom_files_recursively(directory.path()).unwrap();
+
+        assert_eq!(flat, vec![top_level.clone()]);
+        assert_eq!(recursive, vec![nested_dicom, top_level]);
+    }
+
+    #[test]
+    fn collect_dicom_files_accepts_gzipped_dicom_extensions() {
+        let directory = tempdir().unwrap();
+        for name in ["a.dcm.gz", "b.DICOM.GZ", "c.gz", "d.txt.gz"] {
+            std::fs::write(directory.path().join(name), b"synthetic candidate").unwrap();
+        }
+
+        let files = collect_dicom_files(directory.path()).unwrap();
+
+        assert_eq!(
+            files,
+            vec![
+                directory.path().join("a.dcm.gz"),
+                directory.path().join("b.DICOM.GZ")
+            ]
+        );
+        assert!(!is_gzipped_dicom_path(Path::new("archive.gz")));
+    }
+
     #[test]
     fn no_symlink_collector_is_recursive_and_dicom_only() {
         let directory = tempdir().unwrap();
@@ -240,6 +397,23 @@ mod tests {
         assert!(files.is_empty());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn recursive_collector_terminates_on_directory_link_loops() {
+        use std::os::unix::fs::symlink;
+
+        let directory = tempdir().unwrap();
+        let nested = directory.path().join("study").join("series");
+        std::fs::create_dir_all(&nested).unwrap();
+        let dicom = nested.join("image.dcm");
+        std::fs::write(&dicom, b"synthetic DICOM candidate").unwrap();
+        symlink(directory.path(), nested.join("loop")).unwrap();
+
+        let files = collect_dicom_files_recursively(directory.path()).unwrap();
+
+        assert_eq!(files, vec![dicom]);
+    }
+
     #[cfg(unix)]
     #[test]
     fn symlink_component_check_rejects_linked_ancestors() {
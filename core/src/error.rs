@@ -1,3 +1,4 @@
+use crate::types::SopClassKind;
 use thiserror::Error;
 
 /// Result type for mammocat operations
@@ -26,6 +27,17 @@ pub enum MammocatError {
     #[error("Selection error: {0}")]
     SelectionError(String),
 
+    /// Modality tag is present but is not "MG"
+    #[error("Not a mammogram: expected modality=MG, found {found}")]
+    NotMammography {
+        /// Modality tag value that was found
+        found: String,
+    },
+
+    /// File holds a non-image SOP class (CAD SR, presentation state, ...)
+    #[error("Non-image SOP class: {0}")]
+    NonImageSopClass(SopClassKind),
+
     /// I/O error
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
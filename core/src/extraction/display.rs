@@ -0,0 +1,110 @@
+use dicom_object::InMemDicomObject;
+
+use super::tags::{get_float_value, RESCALE_INTERCEPT, RESCALE_SLOPE, WINDOW_CENTER, WINDOW_WIDTH};
+
+/// VOI LUT window from WindowCenter/WindowWidth
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowLevel {
+    /// WindowCenter (0028,1050)
+    pub center: f64,
+    /// WindowWidth (0028,1051)
+    pub width: f64,
+}
+
+/// Display-related header fields for viewers and preprocessing
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayMetadata {
+    /// Default window, when both center and a positive width are present
+    pub window: Option<WindowLevel>,
+    /// RescaleSlope (0028,1053), when present
+    pub rescale_slope: Option<f64>,
+    /// RescaleIntercept (0028,1052), when present
+    pub rescale_intercept: Option<f64>,
+}
+
+/// Extracts display metadata from DICOM file
+///
+/// Multi-valued WindowCenter/WindowWidth (several presets) use the first
+/// value, which is the default window.
+pub fn extract_display_metadata(dcm: &InMemDicomObject) -> DisplayMetadata {
+    let window = get_float_value(dcm, WINDOW_CENTER)
+        .zip(get_float_value(dcm, WINDOW_WIDTH))
+        .filter(|(_, width)| *width > 0.0)
+        .map(|(center, width)| WindowLevel { center, width });
+    DisplayMetadata {
+        window,
+        rescale_slope: get_float_value(dcm, RESCALE_SLOPE),
+        rescale_intercept: get_float_value(dcm, RESCALE_INTERCEPT),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dicom_core::{DataElement, PrimitiveValue, VR};
+
+    fn dicom_with_window(center: &str, width: &str) -> InMemDicomObject {
+        InMemDicomObject::from_element_iter([
+            DataElement::new(WINDOW_CENTER, VR::DS, PrimitiveValue::from(center)),
+            DataElement::new(WINDOW_WIDTH, VR::DS, PrimitiveValue::from(width)),
+        ])
+    }
+
+    #[test]
+    fn test_extract_single_valued_window() {
+        let mut dcm = dicom_with_window("2047.5", "4095");
+        dcm.put(DataElement::new(
+            RESCALE_SLOPE,
+            VR::DS,
+            PrimitiveValue::from("1"),
+        ));
+        dcm.put(DataElement::new(
+            RESCALE_INTERCEPT,
+            VR::DS,
+            PrimitiveValue::from("0"),
+        ));
+
+        assert_eq!(
+            extract_display_metadata(&dcm),
+            DisplayMetadata {
+                window: Some(WindowLevel {
+                    center: 2047.5,
+                    width: 4095.0
+                }),
+                rescale_slope: Some(1.0),
+                rescale_intercept: Some(0.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_extract_multi_valued_window_uses_first_preset() {
+        let dcm = dicom_with_window("2000\\1800\\2200", "4000\\3000\\3500");
+
+        assert_eq!(
+            extract_display_metadata(&dcm).window,
+            Some(WindowLevel {
+                center: 2000.0,
+                width: 4000.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_window_requires_center_and_positive_width() {
+        assert_eq!(
+            extract_display_metadata(&InMemDicomObject::new_empty()),
+            DisplayMetadata::default()
+        );
+        assert_eq!(
+            extract_display_metadata(&dicom_with_window("2000", "0")).window,
+            None
+        );
+        assert_eq!(
+            extract_display_metadata(&dicom_with_window("", "4000")).window,
+            None
+        );
+    }
+}
@@ -4,9 +4,12 @@ use crate::types::Laterality;
 use dicom_object::InMemDicomObject;
 
 use super::tags::{
-    get_string_value, FRAME_ANATOMY_SEQUENCE, FRAME_LATERALITY, IMAGE_LATERALITY,
-    LATERALITY as LATERALITY_TAG, SHARED_FUNCTIONAL_GROUPS_SEQUENCE,
+    get_string_value, BODY_PART_EXAMINED, FRAME_ANATOMY_SEQUENCE, FRAME_LATERALITY,
+    IMAGE_LATERALITY, LATERALITY as LATERALITY_TAG, PATIENT_ORIENTATION,
+    PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE, SERIES_DESCRIPTION, SHARED_FUNCTIONAL_GROUPS_SEQUENCE,
+    VIEW_POSITION,
 };
+use super::view_code::parse_view_code;
 
 /// Extracts laterality from DICOM file
 ///
@@ -18,6 +21,10 @@ use super::tags::{
 /// 2. Fall back to Laterality tag
 /// 3. Fall back to FrameLaterality in SharedFunctionalGroupsSequence
 /// 4. Parse: "l"→Left, "r"→Right, else→Unknown
+/// 5. If none of the laterality tags are populated, parse a combined view code
+///    such as "RCC" or "LMLO" from ViewPosition, then SeriesDescription
+/// 6. Look for "RIGHT"/"LEFT" (or "RB"/"LB") in BodyPartExamined
+/// 7. Finally, infer from the PatientOrientation column direction
 pub fn extract_laterality(dcm: &InMemDicomObject) -> Result<Laterality> {
     // First try ImageLaterality
     if let Some(lat) = get_string_value(dcm, IMAGE_LATERALITY) {
@@ -41,7 +48,65 @@ pub fn extract_laterality(dcm: &InMemDicomObject) -> Result<Laterality> {
         }
     }
 
-    Ok(Laterality::Unknown)
+    // Some vendors encode laterality and view together ("RCC", "LMLO")
+    for tag in [VIEW_POSITION, SERIES_DESCRIPTION] {
+        if let Some(value) = get_string_value(dcm, tag) {
+            let (lat, _) = parse_view_code(&value);
+            if !lat.is_unknown_or_none() {
+                return Ok(lat);
+            }
+        }
+    }
+
+    // Older SFM scans often omit every laterality tag
+    if let Some(lat) = get_string_value(dcm, BODY_PART_EXAMINED)
+        .and_then(|body_part| laterality_from_body_part(&body_part))
+    {
+        return Ok(lat);
+    }
+
+    Ok(get_string_value(dcm, PATIENT_ORIENTATION)
+        .and_then(|orientation| laterality_from_patient_orientation(&orientation))
+        .unwrap_or(Laterality::Unknown))
+}
+
+/// Parses laterality from a free-text BodyPartExamined value
+///
+/// Matches the words "RIGHT"/"LEFT" and the codes "RB"/"LB". Values naming
+/// both sides (or neither) return `None`.
+fn laterality_from_body_part(body_part: &str) -> Option<Laterality> {
+    let upper = body_part.to_uppercase();
+    let tokens: Vec<&str> = upper
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .collect();
+    let right = tokens
+        .iter()
+        .any(|token| *token == "RIGHT" || *token == "RB");
+    let left = tokens
+        .iter()
+        .any(|token| *token == "LEFT" || *token == "LB");
+    match (right, left) {
+        (true, false) => Some(Laterality::Right),
+        (false, true) => Some(Laterality::Left),
+        _ => None,
+    }
+}
+
+/// Infers laterality from PatientOrientation
+///
+/// Mammograms are oriented with the chest wall along one image edge, so the
+/// column direction (second value) points across the body towards the other
+/// breast: `A\R` or `A\FR` for a left breast and `P\L` or `P\FL` for a
+/// right breast. The column direction is used because it is unaffected by
+/// horizontal flipping. Ambiguous or missing values return `None`.
+fn laterality_from_patient_orientation(orientation: &str) -> Option<Laterality> {
+    let column = orientation.split('\\').nth(1)?.trim().to_uppercase();
+    match (column.contains('R'), column.contains('L')) {
+        (true, false) => Some(Laterality::Left),
+        (false, true) => Some(Laterality::Right),
+        _ => None,
+    }
 }
 
 /// Extracts FrameLaterality from SharedFunctionalGroupsSequence
@@ -69,6 +134,79 @@ fn extract_frame_laterality(dcm: &InMemDicomObject) -> Option<String> {
         .and_then(|first_item| get_string_value(first_item, FRAME_LATERALITY))
 }
 
+/// Detects conflicting laterality between top-level and frame-level sources
+///
+/// [`extract_laterality`] stops at the first populated source, so an image
+/// labelled "B" whose frames are individually left and right is reported as
+/// bilateral. This checks every source instead: ImageLaterality, Laterality,
+/// and FrameLaterality in the shared and per-frame functional groups.
+///
+/// # Returns
+///
+/// A description of the conflict when the sources name more than one known
+/// laterality (e.g. "B" alongside "L" and "R"), `None` when they agree or
+/// only one source is present
+pub fn detect_mixed_laterality(dcm: &InMemDicomObject) -> Option<String> {
+    let mut sources: Vec<(String, Laterality)> = Vec::new();
+    for (tag, name) in [
+        (IMAGE_LATERALITY, "ImageLaterality"),
+        (LATERALITY_TAG, "Laterality"),
+    ] {
+        if let Some(value) = get_string_value(dcm, tag) {
+            sources.push((name.to_string(), parse_laterality_string(&value)));
+        }
+    }
+    if let Some(value) = extract_frame_laterality(dcm) {
+        sources.push((
+            "SharedFunctionalGroupsSequence FrameLaterality".to_string(),
+            parse_laterality_string(&value),
+        ));
+    }
+    for (index, value) in extract_per_frame_lateralities(dcm) {
+        sources.push((
+            format!("PerFrameFunctionalGroupsSequence[{index}] FrameLaterality"),
+            parse_laterality_string(&value),
+        ));
+    }
+
+    sources.retain(|(_, laterality)| !laterality.is_unknown_or_none());
+    let first = sources.first()?.1;
+    if sources.iter().all(|(_, laterality)| *laterality == first) {
+        return None;
+    }
+
+    let details = sources
+        .iter()
+        .map(|(source, laterality)| format!("{source}={laterality}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(format!("conflicting laterality sources: {details}"))
+}
+
+/// Extracts FrameLaterality from each PerFrameFunctionalGroupsSequence item
+fn extract_per_frame_lateralities(dcm: &InMemDicomObject) -> Vec<(usize, String)> {
+    let Some(items) = dcm
+        .element(PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE)
+        .ok()
+        .and_then(|per_frame_seq| per_frame_seq.items())
+    else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            item.element(FRAME_ANATOMY_SEQUENCE)
+                .ok()
+                .and_then(|frame_anatomy_seq| frame_anatomy_seq.items())
+                .and_then(|items| items.first())
+                .and_then(|first_item| get_string_value(first_item, FRAME_LATERALITY))
+                .filter(|value| !value.is_empty())
+                .map(|value| (index, value))
+        })
+        .collect()
+}
+
 /// Parses laterality from a string value
 ///
 /// Handles the standard DICOM laterality codes:
@@ -190,6 +328,137 @@ mod tests {
         assert_eq!(result, Laterality::Right);
     }
 
+    fn frame_group_item(laterality: &str) -> InMemDicomObject {
+        let frame_anatomy_item = InMemDicomObject::from_element_iter([DataElement::new(
+            FRAME_LATERALITY,
+            VR::CS,
+            dicom_core::value::PrimitiveValue::from(laterality),
+        )]);
+        InMemDicomObject::from_element_iter([DataElement::new(
+            FRAME_ANATOMY_SEQUENCE,
+            VR::SQ,
+            DataSetSequence::from(vec![frame_anatomy_item]),
+        )])
+    }
+
+    #[test]
+    fn test_detect_mixed_laterality_flags_bilateral_with_unilateral_frames() {
+        let mut dcm = InMemDicomObject::new_empty();
+        dcm.put(DataElement::new(
+            IMAGE_LATERALITY,
+            VR::CS,
+            dicom_core::value::PrimitiveValue::from("B"),
+        ));
+        dcm.put(DataElement::new(
+            PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE,
+            VR::SQ,
+            DataSetSequence::from(vec![frame_group_item("L"), frame_group_item("R")]),
+        ));
+
+        let conflict = detect_mixed_laterality(&dcm).unwrap();
+
+        assert!(conflict.contains("ImageLaterality=bilateral"));
+        assert!(conflict.contains("PerFrameFunctionalGroupsSequence[0] FrameLaterality=left"));
+        assert!(conflict.contains("PerFrameFunctionalGroupsSequence[1] FrameLaterality=right"));
+        // Top-level extraction is unchanged
+        assert_eq!(extract_laterality(&dcm).unwrap(), Laterality::Bilateral);
+    }
+
+    #[test]
+    fn test_detect_mixed_laterality_ignores_agreeing_sources() {
+        let mut dcm = InMemDicomObject::new_empty();
+        dcm.put(DataElement::new(
+            IMAGE_LATERALITY,
+            VR::CS,
+            dicom_core::value::PrimitiveValue::from("L"),
+        ));
+        dcm.put(DataElement::new(
+            PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE,
+            VR::SQ,
+            DataSetSequence::from(vec![frame_group_item("L"), frame_group_item("L")]),
+        ));
+
+        assert!(detect_mixed_laterality(&dcm).is_none());
+        assert!(detect_mixed_laterality(&InMemDicomObject::new_empty()).is_none());
+    }
+
+    #[test]
+    fn test_extract_laterality_falls_back_to_combined_view_code() {
+        let dcm = InMemDicomObject::from_element_iter([DataElement::new(
+            VIEW_POSITION,
+            VR::CS,
+            dicom_core::value::PrimitiveValue::from("RCC"),
+        )]);
+        assert_eq!(extract_laterality(&dcm).unwrap(), Laterality::Right);
+
+        let dcm = InMemDicomObject::from_element_iter([
+            DataElement::new(
+                VIEW_POSITION,
+                VR::CS,
+                dicom_core::value::PrimitiveValue::from("MLO"),
+            ),
+            DataElement::new(
+                SERIES_DESCRIPTION,
+                VR::LO,
+                dicom_core::value::PrimitiveValue::from("LMLO"),
+            ),
+        ]);
+        assert_eq!(extract_laterality(&dcm).unwrap(), Laterality::Left);
+    }
+
+    #[test]
+    fn test_extract_laterality_falls_back_to_body_part_examined() {
+        let dcm = InMemDicomObject::from_element_iter([DataElement::new(
+            BODY_PART_EXAMINED,
+            VR::CS,
+            dicom_core::value::PrimitiveValue::from("RIGHT BREAST"),
+        )]);
+        assert_eq!(extract_laterality(&dcm).unwrap(), Laterality::Right);
+
+        assert_eq!(laterality_from_body_part("LB"), Some(Laterality::Left));
+        assert_eq!(laterality_from_body_part("BREAST"), None);
+        assert_eq!(laterality_from_body_part("LEFT RIGHT"), None);
+    }
+
+    #[test]
+    fn test_extract_laterality_falls_back_to_patient_orientation() {
+        let dcm = InMemDicomObject::from_element_iter([DataElement::new(
+            PATIENT_ORIENTATION,
+            VR::CS,
+            dicom_core::value::PrimitiveValue::from("A\\FR"),
+        )]);
+        assert_eq!(extract_laterality(&dcm).unwrap(), Laterality::Left);
+
+        assert_eq!(
+            laterality_from_patient_orientation("P\\L"),
+            Some(Laterality::Right)
+        );
+        assert_eq!(laterality_from_patient_orientation("A\\F"), None);
+        assert_eq!(laterality_from_patient_orientation("A"), None);
+    }
+
+    #[test]
+    fn test_extract_laterality_tags_take_priority_over_fallbacks() {
+        let dcm = InMemDicomObject::from_element_iter([
+            DataElement::new(
+                LATERALITY_TAG,
+                VR::CS,
+                dicom_core::value::PrimitiveValue::from("L"),
+            ),
+            DataElement::new(
+                BODY_PART_EXAMINED,
+                VR::CS,
+                dicom_core::value::PrimitiveValue::from("RIGHT BREAST"),
+            ),
+            DataElement::new(
+                PATIENT_ORIENTATION,
+                VR::CS,
+                dicom_core::value::PrimitiveValue::from("P\\L"),
+            ),
+        ]);
+        assert_eq!(extract_laterality(&dcm).unwrap(), Laterality::Left);
+    }
+
     #[test]
     fn test_extract_laterality_priority() {
         // Create a DICOM object with all three laterality tags
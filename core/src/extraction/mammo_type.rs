@@ -1,4 +1,4 @@
-use crate::error::Result;
+use crate::error::{MammocatError, Result};
 use crate::types::{DbtObjectKind, ImageType, MammogramType};
 use dicom_object::InMemDicomObject;
 
@@ -22,13 +22,14 @@ use super::tags::{
 /// 3. Extract ImageType components (pixels, exam, flavor, extras)
 /// 4. Apply classification rules IN ORDER:
 ///    a) is_sfm flag → SFM
-///    b) SeriesDescription contains "s-view"/"c-view" → SYNTH
-///    c) exact ImageType component "TOMO_2D" → SYNTH
-///    d) extras contains "generated_2d" → SYNTH
-///    e) exact ImageType component "TOMO" → TOMO
-///    f) ambiguous single-frame volumetric tomo evidence → UNKNOWN
-///    g) pixels contains "ORIGINAL" → FFDM
-///    h) Machine-specific rule (fdr-3000aws) → SYNTH
+///    b) contrast-enhanced markers in ImageType or SeriesDescription → CEM
+///    c) SeriesDescription contains "s-view"/"c-view" → SYNTH
+///    d) exact ImageType component "TOMO_2D" → SYNTH
+///    e) extras contains "generated_2d" → SYNTH
+///    f) exact ImageType component "TOMO" → TOMO
+///    g) ambiguous single-frame volumetric tomo evidence → UNKNOWN
+///    h) pixels contains "ORIGINAL" → FFDM
+///    i) Machine-specific rule (fdr-3000aws) → SYNTH
 /// 5. Default → FFDM
 pub fn extract_mammogram_type(dcm: &InMemDicomObject, is_sfm: bool) -> Result<MammogramType> {
     extract_mammogram_type_impl(dcm, is_sfm, false)
@@ -45,7 +46,7 @@ pub fn extract_mammogram_type_impl(
         let modality = get_string_value(dcm, MODALITY);
         if let Some(m) = modality.as_ref() {
             if m != "MG" {
-                return Err(format!("Expected modality=MG, found {}", m).into());
+                return Err(MammocatError::NotMammography { found: m.clone() });
             }
         }
     }
@@ -82,6 +83,10 @@ pub fn extract_mammogram_type_impl(
         return Ok(MammogramType::Sfm);
     }
 
+    if has_contrast_enhanced_evidence(&img_type, &series_desc) {
+        return Ok(MammogramType::Cem);
+    }
+
     if !series_desc.is_empty() && (series_desc.contains("s-view") || series_desc.contains("c-view"))
     {
         return Ok(MammogramType::Synth);
@@ -176,6 +181,26 @@ pub fn extract_image_type(dcm: &InMemDicomObject) -> ImageType {
     }
 }
 
+/// Markers of contrast-enhanced mammography, matched after replacing `_` with a space
+const CONTRAST_ENHANCED_MARKERS: [&str; 4] = ["subtraction", "recombined", "cesm", "low energy"];
+
+/// Whether the ImageType flavor/extras or SeriesDescription mark a CEM image
+fn has_contrast_enhanced_evidence(img_type: &ImageType, series_desc: &str) -> bool {
+    let contains_marker = |value: &str| {
+        let normalized = value.to_lowercase().replace('_', " ");
+        CONTRAST_ENHANCED_MARKERS
+            .iter()
+            .any(|marker| normalized.contains(marker))
+    };
+
+    img_type.flavor.as_deref().is_some_and(contains_marker)
+        || img_type
+            .extras
+            .as_ref()
+            .is_some_and(|extras| extras.iter().any(|extra| contains_marker(extra)))
+        || contains_marker(series_desc)
+}
+
 fn image_type_component_eq(img_type: &ImageType, expected: &str) -> bool {
     component_eq(&img_type.pixels, expected)
         || component_eq(&img_type.exam, expected)
@@ -265,7 +290,8 @@ fn has_non_empty_tag(dcm: &InMemDicomObject, tag: dicom_core::Tag) -> bool {
     get_string_value(dcm, tag).is_some_and(|value| !value.is_empty())
 }
 
-fn contains_exact_token(value: &str, expected: &str) -> bool {
+/// Whether `value` has `expected` as a whole alphanumeric token, ignoring case
+pub(crate) fn contains_exact_token(value: &str, expected: &str) -> bool {
     value
         .split(|ch: char| !ch.is_ascii_alphanumeric())
         .any(|token| token.eq_ignore_ascii_case(expected))
@@ -479,6 +505,22 @@ mod tests {
         assert_eq!(extract_dbt_object_kind(&dcm, result), DbtObjectKind::None);
     }
 
+    #[test]
+    fn test_non_mg_modality_returns_not_mammography() {
+        let dcm = create_test_dicom("ORIGINAL|PRIMARY", "CT");
+
+        let error = extract_mammogram_type(&dcm, false).unwrap_err();
+
+        assert!(matches!(
+            error,
+            MammocatError::NotMammography { ref found } if found == "CT"
+        ));
+        assert_eq!(
+            extract_mammogram_type_impl(&dcm, false, true).unwrap(),
+            MammogramType::Ffdm
+        );
+    }
+
     #[test]
     fn test_original_pixels_classified_as_ffdm() {
         // Test that ORIGINAL in pixels field is classified as FFDM
@@ -487,6 +529,32 @@ mod tests {
         assert_eq!(result, MammogramType::Ffdm);
     }
 
+    #[test]
+    fn test_cesm_subtracted_image_classified_as_cem() {
+        let dcm = create_test_dicom("DERIVED|PRIMARY||SUBTRACTION", "MG");
+        let result = extract_mammogram_type(&dcm, false).unwrap();
+        assert_eq!(result, MammogramType::Cem);
+        assert_eq!(extract_dbt_object_kind(&dcm, result), DbtObjectKind::None);
+    }
+
+    #[test]
+    fn test_cesm_series_description_classified_as_cem() {
+        for description in ["CESM Low Energy", "Recombined CC", "LOW_ENERGY"] {
+            let mut dcm = create_test_dicom("ORIGINAL|PRIMARY", "MG");
+            put_str(&mut dcm, SERIES_DESCRIPTION, VR::LO, description);
+            let result = extract_mammogram_type(&dcm, false).unwrap();
+            assert_eq!(result, MammogramType::Cem, "{description}");
+        }
+    }
+
+    #[test]
+    fn test_ffdm_series_description_is_not_cem() {
+        let mut dcm = create_test_dicom("ORIGINAL|PRIMARY|", "MG");
+        put_str(&mut dcm, SERIES_DESCRIPTION, VR::LO, "L CC Screening");
+        let result = extract_mammogram_type(&dcm, false).unwrap();
+        assert_eq!(result, MammogramType::Ffdm);
+    }
+
     #[test]
     fn test_sfm_flag_takes_precedence() {
         // Test that is_sfm flag takes precedence over other rules
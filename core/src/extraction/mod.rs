@@ -3,28 +3,44 @@
 //! This module contains the classification and extraction logic for mammography
 //! metadata, implementing the same algorithms as the Python dicom-utils library.
 //!
+//! - [`display`]: Window center/width and rescale parameters for display
 //! - [`laterality`]: Laterality extraction with fallback hierarchy
 //! - [`mammo_type`]: Mammogram type and DBT object classification
+//! - [`non_diagnostic`]: Localizer/scout and specimen image detection
+//! - [`patient`]: Patient-level fields such as age in years
 //! - [`view_position`]: View position parsing from multiple DICOM fields
+//! - [`view_code`]: Combined laterality + view codes such as "LMLO"
 //! - [`view_modifiers`]: Spot compression, magnification, and implant displaced detection
 //! - [`tags`]: DICOM tag constants and helper functions
+//! - [`transfer_syntax`]: Pixel data compression family from the Transfer Syntax UID
 
+pub mod display;
 pub mod laterality;
 pub mod mammo_type;
+pub mod non_diagnostic;
+pub mod patient;
 pub mod tags;
+pub mod transfer_syntax;
+pub mod view_code;
 pub mod view_modifiers;
 pub mod view_position;
 
-pub use laterality::extract_laterality;
+pub use display::{extract_display_metadata, DisplayMetadata, WindowLevel};
+pub use laterality::{detect_mixed_laterality, extract_laterality};
 pub use mammo_type::{extract_dbt_object_kind, extract_image_type, extract_mammogram_type};
+pub use non_diagnostic::{is_localizer, is_specimen, SPECIMEN_SOP_CLASS_UIDS};
+pub use patient::{extract_patient_metadata, parse_patient_age, PatientMetadata};
 pub use tags::*;
+pub use transfer_syntax::CompressionKind;
+pub use view_code::parse_view_code;
 pub use view_modifiers::{
     extract_view_modifier_meanings, extract_view_modifiers, is_implant_displaced, is_magnified,
-    is_spot_compression,
+    is_rolled, is_spot_compression,
 };
 pub use view_position::{
-    extract_view_descriptor, extract_view_position, from_str as parse_view_position,
-    view_code_definition, view_modifier_code_definition, Confidence, Evidence,
-    MammographyViewDescriptor, ViewCodeDefinition, ViewModifierCodeDefinition,
-    VIEW_CODE_DEFINITIONS, VIEW_MODIFIER_CODE_DEFINITIONS,
+    extract_view_descriptor, extract_view_descriptor_with_numeric_codes, extract_view_position,
+    from_str as parse_view_position, from_str_with_numeric_codes, view_code_definition,
+    view_modifier_code_definition, Confidence, Evidence, MammographyViewDescriptor,
+    NumericViewCodeMap, ViewCodeDefinition, ViewModifierCodeDefinition, VIEW_CODE_DEFINITIONS,
+    VIEW_MODIFIER_CODE_DEFINITIONS,
 };
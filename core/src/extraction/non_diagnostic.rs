@@ -0,0 +1,96 @@
+use dicom_object::InMemDicomObject;
+
+use super::mammo_type::extract_image_type;
+use super::tags::{get_string_value, SERIES_DESCRIPTION, SOP_CLASS_UID};
+
+/// ImageType values marking a scout/localizer frame
+const LOCALIZER_IMAGE_TYPE_VALUES: [&str; 2] = ["LOCALIZER", "SCOUT"];
+
+/// SOP classes used for specimen imaging
+///
+/// VL Microscopic Image, VL Slide-Coordinates Microscopic Image and
+/// VL Whole Slide Microscopy Image Storage.
+pub const SPECIMEN_SOP_CLASS_UIDS: [&str; 3] = [
+    "1.2.840.10008.5.1.4.1.1.77.1.2",
+    "1.2.840.10008.5.1.4.1.1.77.1.3",
+    "1.2.840.10008.5.1.4.1.1.77.1.6",
+];
+
+/// Whether the image is a scout/localizer frame
+///
+/// Checks the ImageType flavor and extras for LOCALIZER or SCOUT,
+/// ignoring case.
+pub fn is_localizer(dcm: &InMemDicomObject) -> bool {
+    let image_type = extract_image_type(dcm);
+    image_type
+        .flavor
+        .iter()
+        .chain(image_type.extras.iter().flatten())
+        .any(|value| {
+            LOCALIZER_IMAGE_TYPE_VALUES
+                .iter()
+                .any(|marker| value.trim().eq_ignore_ascii_case(marker))
+        })
+}
+
+/// Whether the image is a specimen radiograph or micrograph
+///
+/// Detected from a specimen SOP Class UID or a SeriesDescription
+/// mentioning "specimen".
+pub fn is_specimen(dcm: &InMemDicomObject) -> bool {
+    let specimen_sop_class = get_string_value(dcm, SOP_CLASS_UID)
+        .is_some_and(|uid| SPECIMEN_SOP_CLASS_UIDS.contains(&uid.trim().trim_end_matches('\0')));
+    specimen_sop_class
+        || get_string_value(dcm, SERIES_DESCRIPTION)
+            .is_some_and(|description| description.to_lowercase().contains("specimen"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extraction::tags::IMAGE_TYPE;
+    use dicom_core::{DataElement, PrimitiveValue, VR};
+
+    fn with_element(tag: dicom_core::Tag, vr: VR, value: &str) -> InMemDicomObject {
+        InMemDicomObject::from_element_iter([DataElement::new(
+            tag,
+            vr,
+            PrimitiveValue::from(value),
+        )])
+    }
+
+    #[test]
+    fn localizer_detected_from_image_type() {
+        for (image_type, expected) in [
+            (&["ORIGINAL", "PRIMARY", "", "LOCALIZER"][..], true),
+            (&["ORIGINAL", "PRIMARY", "SCOUT"], true),
+            (&["ORIGINAL", "PRIMARY", "localizer"], true),
+            (&["ORIGINAL", "PRIMARY", ""], false),
+            (&["DERIVED", "PRIMARY", "TOMO", "GENERATED_2D"], false),
+        ] {
+            let dcm = InMemDicomObject::from_element_iter([DataElement::new(
+                IMAGE_TYPE,
+                VR::CS,
+                PrimitiveValue::Strs(image_type.iter().map(|s| s.to_string()).collect()),
+            )]);
+            assert_eq!(is_localizer(&dcm), expected, "{image_type:?}");
+        }
+        assert!(!is_localizer(&InMemDicomObject::new_empty()));
+    }
+
+    #[test]
+    fn specimen_detected_from_sop_class() {
+        let dcm = with_element(SOP_CLASS_UID, VR::UI, "1.2.840.10008.5.1.4.1.1.77.1.2");
+        assert!(is_specimen(&dcm));
+
+        let mammogram = with_element(SOP_CLASS_UID, VR::UI, "1.2.840.10008.5.1.4.1.1.1.2");
+        assert!(!is_specimen(&mammogram));
+    }
+
+    #[test]
+    fn specimen_detected_from_series_description() {
+        let dcm = with_element(SERIES_DESCRIPTION, VR::LO, "Breast Specimen");
+        assert!(is_specimen(&dcm));
+        assert!(!is_specimen(&InMemDicomObject::new_empty()));
+    }
+}
@@ -0,0 +1,114 @@
+use dicom_object::InMemDicomObject;
+
+use super::tags::{get_string_value, PATIENT_AGE};
+
+/// Patient-level metadata derived from DICOM header fields
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatientMetadata {
+    /// PatientAge converted to whole years, when present and parseable
+    pub age_years: Option<u32>,
+}
+
+/// Extracts patient-level metadata from DICOM file
+pub fn extract_patient_metadata(dcm: &InMemDicomObject) -> PatientMetadata {
+    PatientMetadata {
+        age_years: get_string_value(dcm, PATIENT_AGE)
+            .as_deref()
+            .and_then(parse_patient_age),
+    }
+}
+
+/// Parses a DICOM AS (age string) value into whole years
+///
+/// # Algorithm
+///
+/// 1. Split into a numeric part and an optional unit suffix (`Y`, `M`, `W`, `D`)
+/// 2. A missing suffix is treated as years
+/// 3. Months, weeks, and days are converted approximately (M/12, W/52, D/365)
+///    and truncated to whole years
+///
+/// Returns `None` for empty values, unknown suffixes, or non-numeric input.
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::extraction::patient::parse_patient_age;
+///
+/// assert_eq!(parse_patient_age("045Y"), Some(45));
+/// assert_eq!(parse_patient_age("018M"), Some(1));
+/// assert_eq!(parse_patient_age("unknown"), None);
+/// ```
+pub fn parse_patient_age(s: &str) -> Option<u32> {
+    let s = s.trim();
+    let (digits, unit) = match s.char_indices().last()? {
+        (index, c) if c.is_ascii_alphabetic() => (&s[..index], c.to_ascii_uppercase()),
+        _ => (s, 'Y'),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let value: u32 = digits.parse().ok()?;
+
+    match unit {
+        'Y' => Some(value),
+        'M' => Some(value / 12),
+        'W' => Some(value / 52),
+        'D' => Some(value / 365),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dicom_core::{DataElement, PrimitiveValue, VR};
+
+    #[test]
+    fn test_parse_patient_age_years() {
+        assert_eq!(parse_patient_age("045Y"), Some(45));
+        assert_eq!(parse_patient_age(" 062y "), Some(62));
+    }
+
+    #[test]
+    fn test_parse_patient_age_sub_year_units() {
+        assert_eq!(parse_patient_age("006M"), Some(0));
+        assert_eq!(parse_patient_age("030M"), Some(2));
+        assert_eq!(parse_patient_age("104W"), Some(2));
+        assert_eq!(parse_patient_age("000D"), Some(0));
+        assert_eq!(parse_patient_age("800D"), Some(2));
+    }
+
+    #[test]
+    fn test_parse_patient_age_without_suffix() {
+        assert_eq!(parse_patient_age("057"), Some(57));
+    }
+
+    #[test]
+    fn test_parse_patient_age_rejects_garbage() {
+        assert_eq!(parse_patient_age(""), None);
+        assert_eq!(parse_patient_age("Y"), None);
+        assert_eq!(parse_patient_age("abc"), None);
+        assert_eq!(parse_patient_age("45X"), None);
+        assert_eq!(parse_patient_age("-5Y"), None);
+        assert_eq!(parse_patient_age("4.5Y"), None);
+    }
+
+    #[test]
+    fn test_extract_patient_metadata() {
+        let mut dcm = InMemDicomObject::new_empty();
+        assert_eq!(extract_patient_metadata(&dcm).age_years, None);
+
+        dcm.put(DataElement::new(
+            PATIENT_AGE,
+            VR::AS,
+            PrimitiveValue::from("051Y"),
+        ));
+        assert_eq!(
+            extract_patient_metadata(&dcm),
+            PatientMetadata {
+                age_years: Some(51)
+            }
+        );
+    }
+}
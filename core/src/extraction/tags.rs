@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use dicom_core::Tag;
 use dicom_object::InMemDicomObject;
 
@@ -21,6 +22,10 @@ pub const PIXEL_SPACING: Tag = Tag(0x0028, 0x0030);
 pub const LOSSY_IMAGE_COMPRESSION: Tag = Tag(0x0028, 0x2110);
 pub const LOSSY_IMAGE_COMPRESSION_METHOD: Tag = Tag(0x0028, 0x2114);
 pub const IMAGER_PIXEL_SPACING: Tag = Tag(0x0018, 0x1164);
+pub const RESCALE_INTERCEPT: Tag = Tag(0x0028, 0x1052);
+pub const RESCALE_SLOPE: Tag = Tag(0x0028, 0x1053);
+pub const WINDOW_CENTER: Tag = Tag(0x0028, 0x1050);
+pub const WINDOW_WIDTH: Tag = Tag(0x0028, 0x1051);
 
 // View Position Tags
 pub const VIEW_POSITION: Tag = Tag(0x0018, 0x5101);
@@ -39,6 +44,7 @@ pub const PATIENT_ORIENTATION: Tag = Tag(0x0020, 0x0020);
 
 // Anatomical Tags
 pub const SHARED_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag(0x5200, 0x9229);
+pub const PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag(0x5200, 0x9230);
 pub const BODY_PART_EXAMINED: Tag = Tag(0x0018, 0x0015);
 pub const ACQUISITION_DEVICE_PROCESSING_DESCRIPTION: Tag = Tag(0x0018, 0x1400);
 pub const TOMO_CLASS: Tag = Tag(0x0018, 0x1491);
@@ -80,11 +86,13 @@ pub const INSTITUTION_ADDRESS: Tag = Tag(0x0008, 0x0081);
 // Breast-Specific Tags
 pub const PADDLE_DESCRIPTION: Tag = Tag(0x0018, 0x11A4);
 pub const BREAST_IMPLANT_PRESENT: Tag = Tag(0x0028, 0x1300);
-pub const BODY_PART_THICKNESS: Tag = Tag(0x0018, 0x1075);
+pub const BODY_PART_THICKNESS: Tag = Tag(0x0018, 0x11A0);
+pub const COMPRESSION_FORCE: Tag = Tag(0x0018, 0x11A2);
 
 // Other Tags
 pub const PRESENTATION_INTENT_TYPE: Tag = Tag(0x0008, 0x0068);
 pub const ACCESSION_NUMBER: Tag = Tag(0x0008, 0x0050);
+pub const SPECIFIC_CHARACTER_SET: Tag = Tag(0x0008, 0x0005);
 
 // Pixel Data Tag - used to stop reading before large pixel data
 pub const PIXEL_DATA_TAG: Tag = Tag(0x7FE0, 0x0010);
@@ -111,6 +119,20 @@ pub fn get_int_value(dcm: &InMemDicomObject, tag: Tag) -> Option<i32> {
         .and_then(|elem| elem.to_int::<i32>().ok())
 }
 
+/// Helper to get floating-point value from DICOM tag
+///
+/// Parses decimal strings (DS) and binary floats. For multi-valued elements
+/// the first value is returned. Returns `None` if the tag is not present or
+/// the value is not a finite number.
+pub fn get_float_value(dcm: &InMemDicomObject, tag: Tag) -> Option<f64> {
+    let value = get_string_value(dcm, tag)?;
+    value
+        .split('\\')
+        .next()
+        .and_then(|first| first.trim().parse::<f64>().ok())
+        .filter(|number| number.is_finite())
+}
+
 /// Helper to get multi-string value from DICOM tag
 ///
 /// Returns `None` if the tag is not present or cannot be converted to Vec<String>
@@ -137,6 +159,21 @@ pub fn get_u16_value(dcm: &InMemDicomObject, tag: Tag) -> Option<u16> {
         .and_then(|elem| elem.to_int::<u16>().ok())
 }
 
+/// Helper to get date value from a DICOM DA tag
+///
+/// Parses the `YYYYMMDD` DA format. Returns `None` if the tag is not present,
+/// empty, or not a valid calendar date.
+pub fn get_date_value(dcm: &InMemDicomObject, tag: Tag) -> Option<NaiveDate> {
+    get_string_value(dcm, tag).and_then(|value| parse_da(&value))
+}
+
+fn parse_da(value: &str) -> Option<NaiveDate> {
+    if value.len() != 8 || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d").ok()
+}
+
 /// Helper to get lowercase string value from DICOM tag
 ///
 /// Returns empty string if the tag is not present or cannot be converted.
@@ -173,5 +210,81 @@ mod tests {
         assert_eq!(LATERALITY, Tag(0x0020, 0x0060));
         assert_eq!(VIEW_POSITION, Tag(0x0018, 0x5101));
         assert_eq!(PADDLE_DESCRIPTION, Tag(0x0018, 0x11A4));
+        assert_eq!(BODY_PART_THICKNESS, Tag(0x0018, 0x11A0));
+        assert_eq!(COMPRESSION_FORCE, Tag(0x0018, 0x11A2));
+        assert_eq!(SPECIFIC_CHARACTER_SET, Tag(0x0008, 0x0005));
+        assert_eq!(RESCALE_INTERCEPT, Tag(0x0028, 0x1052));
+        assert_eq!(RESCALE_SLOPE, Tag(0x0028, 0x1053));
+        assert_eq!(WINDOW_CENTER, Tag(0x0028, 0x1050));
+        assert_eq!(WINDOW_WIDTH, Tag(0x0028, 0x1051));
+    }
+
+    #[test]
+    fn test_get_float_value() {
+        use dicom_core::{DataElement, PrimitiveValue, VR};
+
+        let mut dcm = InMemDicomObject::new_empty();
+        dcm.put(DataElement::new(
+            RESCALE_SLOPE,
+            VR::DS,
+            PrimitiveValue::from(" 1.5 "),
+        ));
+        dcm.put(DataElement::new(
+            RESCALE_INTERCEPT,
+            VR::DS,
+            PrimitiveValue::from("-1024\\0"),
+        ));
+        dcm.put(DataElement::new(
+            BODY_PART_THICKNESS,
+            VR::DS,
+            PrimitiveValue::from("thick"),
+        ));
+
+        assert_eq!(get_float_value(&dcm, RESCALE_SLOPE), Some(1.5));
+        assert_eq!(get_float_value(&dcm, RESCALE_INTERCEPT), Some(-1024.0));
+        assert_eq!(get_float_value(&dcm, BODY_PART_THICKNESS), None);
+        assert_eq!(get_float_value(&dcm, PIXEL_SPACING), None);
+    }
+
+    fn dicom_with_study_date(value: &str) -> InMemDicomObject {
+        use dicom_core::{DataElement, PrimitiveValue, VR};
+
+        let mut dcm = InMemDicomObject::new_empty();
+        dcm.put(DataElement::new(
+            STUDY_DATE,
+            VR::DA,
+            PrimitiveValue::from(value),
+        ));
+        dcm
+    }
+
+    #[test]
+    fn test_get_date_value_parses_da() {
+        let dcm = dicom_with_study_date("20240315");
+
+        assert_eq!(
+            get_date_value(&dcm, STUDY_DATE),
+            NaiveDate::from_ymd_opt(2024, 3, 15)
+        );
+    }
+
+    #[test]
+    fn test_get_date_value_empty_or_missing_is_none() {
+        assert_eq!(get_date_value(&dicom_with_study_date(""), STUDY_DATE), None);
+        assert_eq!(
+            get_date_value(&InMemDicomObject::new_empty(), STUDY_DATE),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_date_value_malformed_is_none() {
+        for value in ["2024-03-15", "20241315", "20240230", "2024031", "2024031X"] {
+            assert_eq!(
+                get_date_value(&dicom_with_study_date(value), STUDY_DATE),
+                None,
+                "{value} should not parse"
+            );
+        }
     }
 }
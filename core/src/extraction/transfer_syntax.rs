@@ -0,0 +1,150 @@
+use std::fmt;
+
+/// Pixel data compression family implied by a Transfer Syntax UID
+///
+/// Coarser than `MammogramMetadata::compression_type`: it answers which
+/// decoder a pipeline needs before loading pixel data, not whether the
+/// encoding is lossless.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json", serde(rename_all = "snake_case"))]
+pub enum CompressionKind {
+    /// Native (or encapsulated uncompressed) pixel data
+    Uncompressed,
+    /// RLE Lossless
+    Rle,
+    /// JPEG lossless processes 14 and 14 SV1
+    JpegLossless,
+    /// JPEG baseline and extended lossy processes
+    JpegLossy,
+    /// JPEG 2000, lossless or lossy, including HTJ2K
+    Jpeg2000,
+    /// JPEG-LS, lossless or near-lossless
+    JpegLs,
+    /// Any other or unknown transfer syntax (deflate, MPEG, JPEG XL, ...)
+    Other,
+}
+
+impl CompressionKind {
+    /// Maps a Transfer Syntax UID to its compression family
+    ///
+    /// Surrounding whitespace and trailing NUL padding are ignored.
+    /// Unrecognized UIDs map to [`CompressionKind::Other`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::CompressionKind;
+    ///
+    /// assert_eq!(
+    ///     CompressionKind::from_transfer_syntax("1.2.840.10008.1.2.4.90"),
+    ///     CompressionKind::Jpeg2000
+    /// );
+    /// assert_eq!(
+    ///     CompressionKind::from_transfer_syntax("1.2.840.10008.1.2.1"),
+    ///     CompressionKind::Uncompressed
+    /// );
+    /// ```
+    pub fn from_transfer_syntax(uid: &str) -> Self {
+        match uid.trim().trim_end_matches('\0').trim() {
+            "1.2.840.10008.1.2"
+            | "1.2.840.10008.1.2.1"
+            | "1.2.840.10008.1.2.2"
+            | "1.2.840.10008.1.2.1.98" => Self::Uncompressed,
+            "1.2.840.10008.1.2.5" => Self::Rle,
+            "1.2.840.10008.1.2.4.57" | "1.2.840.10008.1.2.4.70" => Self::JpegLossless,
+            "1.2.840.10008.1.2.4.50"
+            | "1.2.840.10008.1.2.4.51"
+            | "1.2.840.10008.1.2.4.52"
+            | "1.2.840.10008.1.2.4.53"
+            | "1.2.840.10008.1.2.4.54"
+            | "1.2.840.10008.1.2.4.55"
+            | "1.2.840.10008.1.2.4.56" => Self::JpegLossy,
+            "1.2.840.10008.1.2.4.90"
+            | "1.2.840.10008.1.2.4.91"
+            | "1.2.840.10008.1.2.4.92"
+            | "1.2.840.10008.1.2.4.93"
+            | "1.2.840.10008.1.2.4.201"
+            | "1.2.840.10008.1.2.4.202"
+            | "1.2.840.10008.1.2.4.203" => Self::Jpeg2000,
+            "1.2.840.10008.1.2.4.80" | "1.2.840.10008.1.2.4.81" => Self::JpegLs,
+            _ => Self::Other,
+        }
+    }
+
+    /// Returns simple name for display and dictionary output
+    pub fn simple_name(&self) -> &'static str {
+        match self {
+            Self::Uncompressed => "uncompressed",
+            Self::Rle => "rle",
+            Self::JpegLossless => "jpeg_lossless",
+            Self::JpegLossy => "jpeg_lossy",
+            Self::Jpeg2000 => "jpeg2000",
+            Self::JpegLs => "jpeg_ls",
+            Self::Other => "other",
+        }
+    }
+
+    /// Whether pixel data needs a codec to decode
+    ///
+    /// Unrecognized syntaxes ([`CompressionKind::Other`]) count as compressed.
+    pub fn is_compressed(&self) -> bool {
+        !matches!(self, Self::Uncompressed)
+    }
+}
+
+impl fmt::Display for CompressionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.simple_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maps_common_mammography_transfer_syntaxes() {
+        let cases = [
+            ("1.2.840.10008.1.2", CompressionKind::Uncompressed),
+            ("1.2.840.10008.1.2.1", CompressionKind::Uncompressed),
+            ("1.2.840.10008.1.2.2", CompressionKind::Uncompressed),
+            ("1.2.840.10008.1.2.5", CompressionKind::Rle),
+            ("1.2.840.10008.1.2.4.57", CompressionKind::JpegLossless),
+            ("1.2.840.10008.1.2.4.70", CompressionKind::JpegLossless),
+            ("1.2.840.10008.1.2.4.50", CompressionKind::JpegLossy),
+            ("1.2.840.10008.1.2.4.51", CompressionKind::JpegLossy),
+            ("1.2.840.10008.1.2.4.90", CompressionKind::Jpeg2000),
+            ("1.2.840.10008.1.2.4.91", CompressionKind::Jpeg2000),
+            ("1.2.840.10008.1.2.4.201", CompressionKind::Jpeg2000),
+            ("1.2.840.10008.1.2.4.80", CompressionKind::JpegLs),
+            ("1.2.840.10008.1.2.4.81", CompressionKind::JpegLs),
+            ("1.2.840.10008.1.2.1.99", CompressionKind::Other),
+            ("1.2.840.10008.1.2.4.100", CompressionKind::Other),
+            ("", CompressionKind::Other),
+        ];
+        for (uid, expected) in cases {
+            assert_eq!(
+                CompressionKind::from_transfer_syntax(uid),
+                expected,
+                "{uid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ignores_padding() {
+        assert_eq!(
+            CompressionKind::from_transfer_syntax(" 1.2.840.10008.1.2.4.90\0"),
+            CompressionKind::Jpeg2000
+        );
+    }
+
+    #[test]
+    fn test_is_compressed() {
+        assert!(!CompressionKind::Uncompressed.is_compressed());
+        assert!(CompressionKind::Rle.is_compressed());
+        assert!(CompressionKind::Other.is_compressed());
+        assert_eq!(CompressionKind::JpegLs.to_string(), "jpeg_ls");
+    }
+}
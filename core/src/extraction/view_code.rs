@@ -0,0 +1,81 @@
+use crate::registry::parse_laterality_value;
+use crate::types::{Laterality, ViewPosition};
+
+use super::view_position::from_str;
+
+/// Parses a combined laterality + view code such as "LMLO" or "R CC"
+///
+/// # Algorithm
+///
+/// 1. If the whole value is a view code (e.g. "MLO"), laterality is unknown
+/// 2. Otherwise strip a leading "L"/"R" and separators, then parse the
+///    remainder as a view position
+/// 3. Values that do not split this way return unknown for both parts
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::extraction::view_code::parse_view_code;
+/// use mammocat_core::{Laterality, ViewPosition};
+///
+/// assert_eq!(parse_view_code("LMLO"), (Laterality::Left, ViewPosition::Mlo));
+/// assert_eq!(parse_view_code("MLO"), (Laterality::Unknown, ViewPosition::Mlo));
+/// ```
+pub fn parse_view_code(value: &str) -> (Laterality, ViewPosition) {
+    let trimmed = value.trim();
+    let whole = from_str(trimmed, true);
+    if !whole.is_unknown() {
+        return (Laterality::Unknown, whole);
+    }
+
+    let unknown = (Laterality::Unknown, ViewPosition::Unknown);
+    let Some(prefix) = trimmed.get(..1) else {
+        return unknown;
+    };
+    let laterality = match parse_laterality_value(prefix) {
+        Some(laterality @ (Laterality::Left | Laterality::Right)) => laterality,
+        _ => return unknown,
+    };
+    let view = from_str(trimmed[1..].trim_start_matches([' ', '-', '_']), true);
+    if view.is_unknown() {
+        return unknown;
+    }
+    (laterality, view)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_combined_view_codes() {
+        assert_eq!(
+            parse_view_code("LMLO"),
+            (Laterality::Left, ViewPosition::Mlo)
+        );
+        assert_eq!(
+            parse_view_code("R CC"),
+            (Laterality::Right, ViewPosition::Cc)
+        );
+        assert_eq!(
+            parse_view_code("lmlo"),
+            (Laterality::Left, ViewPosition::Mlo)
+        );
+    }
+
+    #[test]
+    fn view_code_without_laterality_prefix_keeps_laterality_unknown() {
+        assert_eq!(
+            parse_view_code("MLO"),
+            (Laterality::Unknown, ViewPosition::Mlo)
+        );
+        assert_eq!(
+            parse_view_code("BREAST"),
+            (Laterality::Unknown, ViewPosition::Unknown)
+        );
+        assert_eq!(
+            parse_view_code(""),
+            (Laterality::Unknown, ViewPosition::Unknown)
+        );
+    }
+}
@@ -32,6 +32,12 @@ pub fn is_magnified(dcm: &InMemDicomObject) -> bool {
     extract_view_modifiers(dcm).contains(&MammographyViewModifier::Magnification)
 }
 
+pub fn is_rolled(dcm: &InMemDicomObject) -> bool {
+    extract_view_modifiers(dcm)
+        .into_iter()
+        .any(MammographyViewModifier::is_rolled)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,5 +49,53 @@ mod tests {
         assert!(!is_implant_displaced(&dcm));
         assert!(!is_spot_compression(&dcm));
         assert!(!is_magnified(&dcm));
+        assert!(!is_rolled(&dcm));
+    }
+
+    #[test]
+    fn image_in_paddle_description_is_not_magnification() {
+        use crate::extraction::tags::PADDLE_DESCRIPTION;
+        use dicom_core::{DataElement, PrimitiveValue, VR};
+
+        for (paddle, expected) in [("IMAGE RECEPTOR PADDLE", false), ("MAG SPOT 18x24", true)] {
+            let dcm = InMemDicomObject::from_element_iter([DataElement::new(
+                PADDLE_DESCRIPTION,
+                VR::LO,
+                PrimitiveValue::from(paddle),
+            )]);
+            assert_eq!(is_magnified(&dcm), expected, "{paddle}");
+        }
+    }
+
+    #[test]
+    fn rolled_views_are_detected_from_each_source() {
+        use crate::extraction::tags::{
+            CODE_MEANING, PADDLE_DESCRIPTION, VIEW_MODIFIER_CODE_SEQUENCE, VIEW_POSITION,
+        };
+        use dicom_core::value::DataSetSequence;
+        use dicom_core::{DataElement, PrimitiveValue, VR};
+
+        let view_position = InMemDicomObject::from_element_iter([DataElement::new(
+            VIEW_POSITION,
+            VR::CS,
+            PrimitiveValue::from("RL"),
+        )]);
+        let paddle = InMemDicomObject::from_element_iter([DataElement::new(
+            PADDLE_DESCRIPTION,
+            VR::LO,
+            PrimitiveValue::from("ROLL MED"),
+        )]);
+        let modifier_sequence = InMemDicomObject::from_element_iter([DataElement::new(
+            VIEW_MODIFIER_CODE_SEQUENCE,
+            VR::SQ,
+            DataSetSequence::from(vec![InMemDicomObject::from_element_iter([
+                DataElement::new(CODE_MEANING, VR::LO, PrimitiveValue::from("Rolled Medial")),
+            ])]),
+        )]);
+
+        for dcm in [view_position, paddle, modifier_sequence] {
+            assert!(is_rolled(&dcm));
+            assert!(!is_spot_compression(&dcm));
+        }
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::error::Result;
 use crate::registry::{is_retired_snomed_coding_scheme, retired_view_code_matches};
@@ -52,7 +52,72 @@ impl Default for MammographyViewDescriptor {
     }
 }
 
+/// Vendor-specific mapping from numeric ViewPosition codes to base views
+///
+/// Some legacy systems store ViewPosition as an integer such as `"1"` instead
+/// of a DICOM defined term. These codes have no standard meaning and differ
+/// between vendors, so the map is empty by default and numeric values remain
+/// unknown unless a caller configures codes for a known source.
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::extraction::view_position::{from_str_with_numeric_codes, NumericViewCodeMap};
+/// use mammocat_core::ViewPosition;
+///
+/// let codes = NumericViewCodeMap::new()
+///     .with_code(0, ViewPosition::Mlo)
+///     .with_code(1, ViewPosition::Cc);
+/// assert_eq!(from_str_with_numeric_codes("1", true, &codes), ViewPosition::Cc);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NumericViewCodeMap {
+    codes: BTreeMap<u32, ViewPosition>,
+}
+
+impl NumericViewCodeMap {
+    /// Creates an empty mapping (numeric codes are not interpreted)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder: Map a numeric code to a base view position
+    pub fn with_code(mut self, code: u32, view: ViewPosition) -> Self {
+        self.codes.insert(code, view);
+        self
+    }
+
+    /// Returns whether no numeric codes are configured
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// Looks up a purely numeric value, ignoring surrounding whitespace
+    pub fn get(&self, value: &str) -> Option<ViewPosition> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() || !trimmed.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        trimmed
+            .parse::<u32>()
+            .ok()
+            .and_then(|code| self.codes.get(&code).copied())
+    }
+}
+
 pub fn extract_view_descriptor(dcm: &InMemDicomObject) -> MammographyViewDescriptor {
+    extract_view_descriptor_with_numeric_codes(dcm, &NumericViewCodeMap::default())
+}
+
+/// Extracts the view descriptor, consulting `numeric_codes` for numeric ViewPosition values
+///
+/// The mapping is only used when the ViewPosition value is purely numeric and
+/// does not parse as a defined term. Mapped views are recorded as heuristic
+/// evidence, so coded ViewCodeSequence values still take precedence.
+pub fn extract_view_descriptor_with_numeric_codes(
+    dcm: &InMemDicomObject,
+    numeric_codes: &NumericViewCodeMap,
+) -> MammographyViewDescriptor {
     let mut descriptor = MammographyViewDescriptor::default();
     let mut base_candidates = Vec::new();
 
@@ -88,6 +153,7 @@ pub fn extract_view_descriptor(dcm: &InMemDicomObject) -> MammographyViewDescrip
         } else {
             let loose_view = compact_alias
                 .and_then(|(view, _)| view)
+                .or_else(|| numeric_codes.get(&raw_view))
                 .unwrap_or_else(|| from_str(&raw_view, false));
             if !loose_view.is_unknown() {
                 add_base_candidate(
@@ -133,19 +199,10 @@ pub fn extract_view_descriptor(dcm: &InMemDicomObject) -> MammographyViewDescrip
     }
 
     if let Some(paddle) = get_string_value(dcm, PADDLE_DESCRIPTION) {
-        if paddle.contains("SPOT") || paddle.contains("SPT") {
-            add_modifier(
-                &mut descriptor,
-                MammographyViewModifier::SpotCompression,
-                Confidence::Heuristic,
-                "PaddleDescription",
-                &paddle,
-            );
-        }
-        if paddle.contains("MAG") {
+        for modifier in paddle_modifiers(&paddle) {
             add_modifier(
                 &mut descriptor,
-                MammographyViewModifier::Magnification,
+                modifier,
                 Confidence::Heuristic,
                 "PaddleDescription",
                 &paddle,
@@ -187,10 +244,49 @@ pub fn extract_view_descriptor(dcm: &InMemDicomObject) -> MammographyViewDescrip
         }
     }
 
+    if base_candidates.is_empty() {
+        if let Some(candidate) = base_view_from_modifier_sequence(dcm, &mut descriptor) {
+            base_candidates.push(candidate);
+        }
+    }
+
     descriptor.view_position = resolve_base_view(&base_candidates, &mut descriptor.conflicts);
     descriptor
 }
 
+/// Last-resort base view from a ViewModifierCodeSequence item
+///
+/// Some exporters put the base view code (e.g. "cranio-caudal exaggerated
+/// laterally") in the modifier sequence instead of ViewCodeSequence. Only
+/// consulted when no other source yields a base view.
+fn base_view_from_modifier_sequence(
+    dcm: &InMemDicomObject,
+    descriptor: &mut MammographyViewDescriptor,
+) -> Option<BaseCandidate> {
+    let items = dcm.element(VIEW_MODIFIER_CODE_SEQUENCE).ok()?.items()?;
+    items.iter().find_map(|item| {
+        let meaning = get_string_value(item, CODE_MEANING);
+        let view = match_view_tuple(item).map(|(view, _)| view).or_else(|| {
+            meaning
+                .as_deref()
+                .map(|value| from_str(value, true))
+                .filter(|view| !view.is_unknown())
+        })?;
+        descriptor.evidence.push(Evidence {
+            source: "ViewModifierCodeSequence".to_string(),
+            value: meaning
+                .or_else(|| get_string_value(item, CODE_VALUE))
+                .unwrap_or_default(),
+            confidence: Confidence::Heuristic,
+        });
+        Some(BaseCandidate {
+            view,
+            confidence: Confidence::Heuristic,
+            authoritative_code: false,
+        })
+    })
+}
+
 pub fn extract_view_position(dcm: &InMemDicomObject) -> Result<ViewPosition> {
     Ok(extract_view_descriptor(dcm).view_position)
 }
@@ -409,6 +505,19 @@ pub fn from_str(value: &str, strict: bool) -> ViewPosition {
         .unwrap_or(ViewPosition::Unknown)
 }
 
+/// Parses a view position, falling back to `numeric_codes` for purely numeric values
+pub fn from_str_with_numeric_codes(
+    value: &str,
+    strict: bool,
+    numeric_codes: &NumericViewCodeMap,
+) -> ViewPosition {
+    let view = from_str(value, strict);
+    if !view.is_unknown() {
+        return view;
+    }
+    numeric_codes.get(value).unwrap_or(ViewPosition::Unknown)
+}
+
 fn modifier_from_text(value: &str, strict: bool) -> Option<MammographyViewModifier> {
     let normalized = normalize_text(value);
     let exact = VIEW_MODIFIER_CODE_DEFINITIONS
@@ -466,7 +575,7 @@ fn compact_base_view(value: &str) -> Option<ViewPosition> {
 fn description_contains_modifier(value: &str, modifier: MammographyViewModifier) -> bool {
     let normalized = normalize_text(value);
     let definition = view_modifier_code_definition(modifier);
-    normalized.contains(&normalize_text(definition.code_meaning))
+    contains_phrase(&normalized, &normalize_text(definition.code_meaning))
         || match modifier {
             MammographyViewModifier::SpotCompression => {
                 normalized.contains("spot") || contains_token(&normalized, "spt")
@@ -483,6 +592,32 @@ fn description_contains_modifier(value: &str, modifier: MammographyViewModifier)
         }
 }
 
+/// Modifiers implied by a PaddleDescription, matched on whole tokens so that
+/// e.g. "IMAGE RECEPTOR" does not read as a magnification paddle
+fn paddle_modifiers(paddle: &str) -> Vec<MammographyViewModifier> {
+    let normalized = normalize_text(paddle);
+    let has_token = |tokens: &[&str]| {
+        tokens
+            .iter()
+            .any(|token| contains_token(&normalized, token))
+    };
+    let mut modifiers = Vec::new();
+    if has_token(&["spot", "spt"]) {
+        modifiers.push(MammographyViewModifier::SpotCompression);
+    }
+    if has_token(&["roll", "rolled"]) {
+        if has_token(&["lat", "lateral"]) {
+            modifiers.push(MammographyViewModifier::RolledLateral);
+        } else if has_token(&["med", "medial"]) {
+            modifiers.push(MammographyViewModifier::RolledMedial);
+        }
+    }
+    if has_token(&["mag", "magnification"]) {
+        modifiers.push(MammographyViewModifier::Magnification);
+    }
+    modifiers
+}
+
 fn element_is_empty(item: &InMemDicomObject, tag: dicom_core::Tag) -> bool {
     get_string_value(item, tag).is_none_or(|value| value.is_empty())
 }
@@ -509,6 +644,11 @@ fn contains_token(value: &str, token: &str) -> bool {
         .any(|part| part == token)
 }
 
+/// Whether `phrase` occurs in normalized `value` on token boundaries
+fn contains_phrase(value: &str, phrase: &str) -> bool {
+    format!(" {value} ").contains(&format!(" {phrase} "))
+}
+
 fn contains_view_token(value: &str, view: &str) -> bool {
     value
         .split(|character: char| !character.is_ascii_alphanumeric())
@@ -538,6 +678,75 @@ mod tests {
         ])
     }
 
+    #[test]
+    fn numeric_view_codes_are_unknown_by_default() {
+        let dcm = InMemDicomObject::from_element_iter([DataElement::new(
+            VIEW_POSITION_TAG,
+            VR::CS,
+            PrimitiveValue::from("1"),
+        )]);
+
+        assert_eq!(from_str("1", false), ViewPosition::Unknown);
+        assert_eq!(
+            extract_view_descriptor(&dcm).view_position,
+            ViewPosition::Unknown
+        );
+    }
+
+    #[test]
+    fn configured_numeric_view_code_maps_to_cc() {
+        let codes = NumericViewCodeMap::new().with_code(1, ViewPosition::Cc);
+        let dcm = InMemDicomObject::from_element_iter([DataElement::new(
+            VIEW_POSITION_TAG,
+            VR::CS,
+            PrimitiveValue::from("1"),
+        )]);
+
+        assert_eq!(
+            from_str_with_numeric_codes("1", true, &codes),
+            ViewPosition::Cc
+        );
+        assert_eq!(
+            from_str_with_numeric_codes("2", true, &codes),
+            ViewPosition::Unknown
+        );
+        assert_eq!(
+            from_str_with_numeric_codes("MLO", true, &codes),
+            ViewPosition::Mlo
+        );
+        let descriptor = extract_view_descriptor_with_numeric_codes(&dcm, &codes);
+        assert_eq!(descriptor.view_position, ViewPosition::Cc);
+        assert_eq!(descriptor.evidence[0].confidence, Confidence::Heuristic);
+    }
+
+    #[test]
+    fn combined_laterality_view_codes_resolve_view_position() {
+        for (tag, value) in [
+            (VIEW_POSITION_TAG, "RCC"),
+            (VIEW_POSITION_TAG, "R CC"),
+            (SERIES_DESCRIPTION, "LMLO"),
+        ] {
+            let dcm = InMemDicomObject::from_element_iter([DataElement::new(
+                tag,
+                VR::LO,
+                PrimitiveValue::from(value),
+            )]);
+            let expected = crate::extraction::parse_view_code(value).1;
+            assert_eq!(extract_view_position(&dcm).unwrap(), expected, "{value}");
+            assert!(!expected.is_unknown());
+        }
+    }
+
+    #[test]
+    fn numeric_view_code_map_ignores_non_numeric_values() {
+        let codes = NumericViewCodeMap::new().with_code(1, ViewPosition::Cc);
+
+        assert_eq!(codes.get(" 1 "), Some(ViewPosition::Cc));
+        assert_eq!(codes.get("1a"), None);
+        assert_eq!(codes.get("-1"), None);
+        assert_eq!(codes.get(""), None);
+    }
+
     #[test]
     fn parses_every_cid_4014_code() {
         for definition in VIEW_CODE_DEFINITIONS {
@@ -709,6 +918,57 @@ mod tests {
         assert!(descriptor.conflicts.is_empty());
     }
 
+    #[test]
+    fn base_view_code_in_modifier_sequence_is_last_resort() {
+        let mut dcm = InMemDicomObject::new_empty();
+        dcm.put(DataElement::new(
+            VIEW_MODIFIER_CODE_SEQUENCE,
+            VR::SQ,
+            DataSetSequence::from(vec![
+                coded_item("SCT", "399161006", "Cleavage"),
+                coded_item("SCT", "399192008", "cranio-caudal exaggerated laterally"),
+            ]),
+        ));
+
+        let descriptor = extract_view_descriptor(&dcm);
+
+        assert_eq!(descriptor.view_position, ViewPosition::Xccl);
+        assert!(descriptor
+            .modifiers
+            .contains(&MammographyViewModifier::Cleavage));
+        assert!(descriptor.evidence.iter().any(|evidence| {
+            evidence.source == "ViewModifierCodeSequence"
+                && evidence.confidence == Confidence::Heuristic
+        }));
+
+        dcm.put(DataElement::new(
+            VIEW_POSITION_TAG,
+            VR::CS,
+            PrimitiveValue::from("CC"),
+        ));
+        let descriptor = extract_view_descriptor(&dcm);
+        assert_eq!(descriptor.view_position, ViewPosition::Cc);
+        assert!(descriptor.conflicts.is_empty());
+    }
+
+    #[test]
+    fn cleavage_only_modifier_sequence_keeps_view_unknown() {
+        let mut dcm = InMemDicomObject::new_empty();
+        dcm.put(DataElement::new(
+            VIEW_MODIFIER_CODE_SEQUENCE,
+            VR::SQ,
+            DataSetSequence::from(vec![coded_item("SCT", "399161006", "Cleavage")]),
+        ));
+
+        let descriptor = extract_view_descriptor(&dcm);
+
+        assert_eq!(descriptor.view_position, ViewPosition::Unknown);
+        assert_eq!(
+            descriptor.modifiers,
+            BTreeSet::from([MammographyViewModifier::Cleavage])
+        );
+    }
+
     #[test]
     fn modifier_does_not_replace_base_view() {
         let mut dcm = InMemDicomObject::new_empty();
@@ -866,6 +1126,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn short_codes_only_match_whole_words() {
+        for (raw, expected_view, expected_modifiers) in [
+            ("exaggerated laterally", ViewPosition::Unknown, vec![]),
+            (
+                "cranio-caudal exaggerated laterally",
+                ViewPosition::Xccl,
+                vec![],
+            ),
+            ("XCCL laterally", ViewPosition::Xccl, vec![]),
+            ("LAT", ViewPosition::Unknown, vec![]),
+            ("calcification", ViewPosition::Unknown, vec![]),
+            ("tangentially", ViewPosition::Unknown, vec![]),
+            ("mlo cleavages", ViewPosition::Mlo, vec![]),
+            (
+                "left breast at",
+                ViewPosition::Unknown,
+                vec![MammographyViewModifier::AxillaryTail],
+            ),
+            (
+                "R CC CV",
+                ViewPosition::Cc,
+                vec![MammographyViewModifier::Cleavage],
+            ),
+        ] {
+            for tag in [VIEW_POSITION_TAG, SERIES_DESCRIPTION] {
+                let mut dcm = InMemDicomObject::new_empty();
+                dcm.put(DataElement::new(tag, VR::LO, PrimitiveValue::from(raw)));
+
+                let descriptor = extract_view_descriptor(&dcm);
+
+                assert_eq!(descriptor.view_position, expected_view, "{raw}");
+                assert_eq!(
+                    descriptor.modifiers.iter().copied().collect::<Vec<_>>(),
+                    expected_modifiers,
+                    "{raw}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn paddle_description_matches_whole_tokens() {
+        for (paddle, expected) in [
+            ("IMAGE RECEPTOR PADDLE", vec![]),
+            (
+                "MAG SPOT 18x24",
+                vec![
+                    MammographyViewModifier::Magnification,
+                    MammographyViewModifier::SpotCompression,
+                ],
+            ),
+            ("SPT", vec![MammographyViewModifier::SpotCompression]),
+            ("SPOTLESS 24x30", vec![]),
+            ("ROLL LAT", vec![MammographyViewModifier::RolledLateral]),
+        ] {
+            let mut dcm = InMemDicomObject::new_empty();
+            dcm.put(DataElement::new(
+                PADDLE_DESCRIPTION,
+                VR::LO,
+                PrimitiveValue::from(paddle),
+            ));
+
+            let descriptor = extract_view_descriptor(&dcm);
+
+            assert_eq!(
+                descriptor.modifiers.iter().copied().collect::<Vec<_>>(),
+                expected,
+                "{paddle}"
+            );
+        }
+    }
+
     #[test]
     fn modifier_abbreviations_from_real_files_are_heuristic() {
         for (raw, expected_modifier) in [
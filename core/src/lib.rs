@@ -11,12 +11,17 @@ pub mod selection;
 pub mod types;
 pub mod validation;
 
+#[cfg(test)]
+mod test_support;
+
 // Python bindings module (optional)
 #[cfg(feature = "python")]
 pub mod python;
 
 pub use api::{MammogramExtractor, MammogramMetadata};
-pub use cli::report::TextReport;
+#[cfg(feature = "csv")]
+pub use cli::csv::CsvReport;
+pub use cli::report::{ReportIdentifiers, TextReport};
 pub use completion::{
     apply_completion_plan, complete_file, plan_completion, CompletionFileOptions, CompletionIssue,
     CompletionOptions, CompletionPlan, CompletionReport, FieldAddition, InferredValue,
@@ -30,9 +35,14 @@ pub use dbt::{
 pub use dicom_files::{
     collect_dicom_files, collect_dicom_files_recursively,
     collect_dicom_files_recursively_no_symlinks, ensure_no_symlink_components, is_dicom_file,
+    is_gzipped_dicom_path,
 };
 pub use error::{MammocatError, Result};
-pub use extraction::{extract_view_descriptor, Evidence, MammographyViewDescriptor};
+pub use extraction::{
+    extract_display_metadata, extract_patient_metadata, extract_view_descriptor, parse_patient_age,
+    CompressionKind, DisplayMetadata, Evidence, MammographyViewDescriptor, PatientMetadata,
+    WindowLevel,
+};
 pub use planning::{
     plan_mammography_collection, DbtCompositionInput, DbtPlan, DbtVolumeCandidate, MammographyPlan,
     MammographyPlanConfig, MammographyPlanOptions, MammographyPlanSelection,
@@ -43,14 +53,23 @@ pub use registry::{
     MetadataConsumer, SopApplicability, WriterRepresentation, CANONICAL_METADATA_REGISTRY,
     CANONICAL_METADATA_RULES, SUPPORTED_SOP_CLASSES,
 };
+#[cfg(feature = "json")]
+pub use selection::RecordCache;
 pub use selection::{
-    get_preferred_views, get_preferred_views_filtered,
+    by_laterality, classify_studies_chronologically, dedupe_by_sop_uid, explain_missing,
+    explain_missing_with_order, extract_and_record, get_all_views, get_preferred_views,
+    get_preferred_views_explained, get_preferred_views_filtered,
     get_preferred_views_filtered_with_study_mode,
-    get_preferred_views_filtered_with_study_mode_and_warnings, get_preferred_views_with_order,
-    get_preferred_views_with_order_and_warnings, refine_dbt_object_classification,
-    refine_dbt_object_classification_with_diagnostics, DbtRefinementDiagnostic,
-    DbtRefinementReason, MammogramRecord, PreferredViewSelection,
-    PreferredViewSelectionWithWarnings, SelectionWarning, StudySelectionMode,
+    get_preferred_views_filtered_with_study_mode_and_warnings, get_preferred_views_normalized,
+    get_preferred_views_ordered, get_preferred_views_ref, get_preferred_views_with_order,
+    get_preferred_views_with_order_and_warnings, get_ranked_views, has_both_standard_views,
+    iter_records, merge_records_dedup, missing_views, ordered_selection, process_directory,
+    refine_dbt_object_classification, refine_dbt_object_classification_with_diagnostics,
+    reselect_excluding, study_completeness, DbtRefinementDiagnostic, DbtRefinementReason,
+    MammogramDataset, MammogramRecord, MissingReason, OrderedViewSelection, PreferredViewSelection,
+    PreferredViewSelectionExplained, PreferredViewSelectionWithWarnings, ProcessResult,
+    RecordDedupKey, SelectionReason, SelectionSnapshot, SelectionWarning, StudyRole,
+    StudySelectionMode, ViewSnapshot,
 };
 pub use types::*;
 pub use validation::{
@@ -1,9 +1,10 @@
 use clap::Parser;
 use dicom_object::OpenFileOptions;
 use log::info;
-use mammocat_core::cli::{Cli, OutputFormat};
+use mammocat_core::cli::report::ReportIdentifiers;
+use mammocat_core::cli::{format_text_reports_with_identifiers, write_output, Cli, OutputFormat};
 use mammocat_core::extraction::tags::PIXEL_DATA_TAG;
-use mammocat_core::{MammogramExtractor, TextReport};
+use mammocat_core::MammogramExtractor;
 use std::process;
 
 fn main() {
@@ -20,40 +21,57 @@ fn main() {
             .init();
     }
 
-    info!("Reading DICOM file: {}", cli.file.display());
+    let mut files = Vec::with_capacity(cli.files.len());
+    let mut identifiers = Vec::with_capacity(cli.files.len());
+    for file in &cli.files {
+        info!("Reading DICOM file: {}", file.display());
 
-    // Open DICOM file (metadata only, skip pixel data for performance)
-    let dcm = match OpenFileOptions::new()
-        .read_until(PIXEL_DATA_TAG)
-        .open_file(&cli.file)
-    {
-        Ok(obj) => obj,
-        Err(e) => {
-            eprintln!("Error: Failed to read DICOM file: {}", e);
-            process::exit(1);
-        }
-    };
+        // Open DICOM file (metadata only, skip pixel data for performance)
+        let dcm = match OpenFileOptions::new()
+            .read_until(PIXEL_DATA_TAG)
+            .open_file(file)
+        {
+            Ok(obj) => obj,
+            Err(e) => {
+                eprintln!("Error: Failed to read DICOM file: {}", e);
+                process::exit(1);
+            }
+        };
 
-    // Extract metadata
-    let metadata = match MammogramExtractor::extract_file(&dcm) {
-        Ok(m) => m,
-        Err(e) => {
-            eprintln!("Error: Failed to extract metadata: {}", e);
-            process::exit(1);
-        }
-    };
+        // Extract metadata
+        let metadata = match MammogramExtractor::extract_file_with_options_and_modality_policy(
+            &dcm,
+            false,
+            cli.ignore_modality,
+        ) {
+            Ok(m) => m,
+            Err(e) => {
+                eprintln!("Error: Failed to extract metadata: {}", e);
+                process::exit(1);
+            }
+        };
+        files.push((file.clone(), metadata));
+        identifiers.push(ReportIdentifiers::from_dicom(&dcm));
+    }
 
-    // Output based on format
-    match cli.format {
+    // Format based on output format
+    let content = match cli.format {
         OutputFormat::Text => {
-            let report = TextReport::new(&metadata);
-            println!("{}", report);
+            let files: Vec<_> = files
+                .into_iter()
+                .zip(identifiers)
+                .map(|((path, metadata), identifiers)| (path, metadata, identifiers))
+                .collect();
+            format!(
+                "{}\n",
+                format_text_reports_with_identifiers(&files, cli.units)
+            )
         }
         OutputFormat::Json => {
             #[cfg(feature = "json")]
             {
-                match serde_json::to_string_pretty(&metadata) {
-                    Ok(json) => println!("{}", json),
+                match mammocat_core::cli::format_json_reports(&files) {
+                    Ok(json) => format!("{}\n", json),
                     Err(e) => {
                         eprintln!("Error: Failed to serialize to JSON: {}", e);
                         process::exit(1);
@@ -67,5 +85,40 @@ fn main() {
                 process::exit(1);
             }
         }
+        OutputFormat::Yaml => {
+            #[cfg(feature = "yaml")]
+            {
+                match mammocat_core::cli::format_yaml_reports(&files) {
+                    Ok(yaml) => yaml,
+                    Err(e) => {
+                        eprintln!("Error: Failed to serialize to YAML: {}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+            #[cfg(not(feature = "yaml"))]
+            {
+                eprintln!("Error: YAML output requires the 'yaml' feature");
+                eprintln!("Rebuild with: cargo build --features yaml");
+                process::exit(1);
+            }
+        }
+        OutputFormat::Csv => {
+            #[cfg(feature = "csv")]
+            {
+                format!("{}\n", mammocat_core::cli::csv::format_csv_reports(&files))
+            }
+            #[cfg(not(feature = "csv"))]
+            {
+                eprintln!("Error: CSV output requires the 'csv' feature");
+                eprintln!("Rebuild with: cargo build --features csv");
+                process::exit(1);
+            }
+        }
+    };
+
+    if let Err(e) = write_output(&content, cli.output.as_deref()) {
+        eprintln!("Error: Failed to write output: {}", e);
+        process::exit(1);
     }
 }
@@ -12,7 +12,7 @@ use crate::dbt::{
 use crate::dicom_files::{collect_dicom_files_recursively, collect_recursive_file_inventory};
 use crate::error::{MammocatError, Result};
 use crate::selection::{
-    get_preferred_views_filtered_refined_with_study_mode_and_warnings,
+    get_preferred_views_filtered_refined_with_study_mode_and_warnings, read_records,
     refine_dbt_object_classification_with_diagnostics, DbtRefinementDiagnostic, MammogramRecord,
     StudySelectionMode,
 };
@@ -33,8 +33,17 @@ const FILTER_REASON_ALLOWED_TYPES: &str = "allowed_types";
 const FILTER_REASON_ALLOWED_DBT_OBJECT_KINDS: &str = "allowed_dbt_object_kinds";
 const FILTER_REASON_EXCLUDE_IMPLANTS: &str = "exclude_implants";
 const FILTER_REASON_ONLY_STANDARD_VIEWS: &str = "only_standard_views";
+const FILTER_REASON_EXCLUDE_SPOT_MAG: &str = "exclude_spot_mag";
 const FILTER_REASON_EXCLUDE_FOR_PROCESSING: &str = "exclude_for_processing";
+const FILTER_REASON_REQUIRE_FOR_PRESENTATION: &str = "require_for_presentation";
+const FILTER_REASON_MIN_FRAMES: &str = "min_frames";
+const FILTER_REASON_MIN_IMAGE_AREA: &str = "min_image_area";
+const FILTER_REASON_ALLOWED_MANUFACTURERS: &str = "allowed_manufacturers";
+const FILTER_REASON_EXCLUDED_MODELS: &str = "excluded_models";
+const FILTER_REASON_EXCLUDED_PATIENT_NAME: &str = "exclude_patient_name_patterns";
 const FILTER_REASON_EXCLUDE_SECONDARY_CAPTURE: &str = "exclude_secondary_capture";
+const FILTER_REASON_EXCLUDE_LOCALIZERS: &str = "exclude_localizers";
+const FILTER_REASON_EXCLUDE_SPECIMENS: &str = "exclude_specimens";
 const FILTER_REASON_EXCLUDE_NON_MG: &str = "exclude_non_mg";
 const FILTER_REASON_MISSING_MODALITY: &str = "missing_modality";
 const FILTER_REASON_EXCLUDE_LOSSY_COMPRESSED: &str = "exclude_lossy_compressed";
@@ -239,14 +248,11 @@ pub fn plan_mammography_collection(
     } else {
         let dicom_files = collect_dicom_files_recursively(input)?;
         let input_dicom_files = dicom_files.len();
-        let mut records = Vec::new();
-        let mut warnings = Vec::new();
-        for file_path in dicom_files {
-            match MammogramRecord::from_file(file_path.clone()) {
-                Ok(record) => records.push(record),
-                Err(error) => warnings.push(format!("skipping {}: {error}", file_path.display())),
-            }
-        }
+        let (records, skipped) = read_records(dicom_files);
+        let warnings = skipped
+            .into_iter()
+            .map(|(file_path, error)| format!("skipping {}: {error}", file_path.display()))
+            .collect();
         (input_dicom_files, records, warnings, None)
     };
 
@@ -726,12 +732,46 @@ fn filter_reasons(record: &MammogramRecord, config: &FilterConfig) -> Vec<String
     if config.exclude_non_standard_views && !record.metadata.is_standard_view() {
         reasons.push(FILTER_REASON_ONLY_STANDARD_VIEWS.to_string());
     }
+    if config.exclude_spot_mag && record.is_spot_or_mag() {
+        reasons.push(FILTER_REASON_EXCLUDE_SPOT_MAG.to_string());
+    }
     if config.exclude_for_processing && record.metadata.is_for_processing {
         reasons.push(FILTER_REASON_EXCLUDE_FOR_PROCESSING.to_string());
     }
+    if config.require_for_presentation && !record.metadata.presentation_intent.is_for_presentation()
+    {
+        reasons.push(FILTER_REASON_REQUIRE_FOR_PRESENTATION.to_string());
+    }
+    if config
+        .min_frames
+        .is_some_and(|min_frames| record.metadata.number_of_frames < min_frames)
+    {
+        reasons.push(FILTER_REASON_MIN_FRAMES.to_string());
+    }
+    if config
+        .min_image_area
+        .is_some_and(|min_area| record.image_area().is_none_or(|area| area < min_area))
+    {
+        reasons.push(FILTER_REASON_MIN_IMAGE_AREA.to_string());
+    }
+    if !config.manufacturer_allowed(record.metadata.manufacturer.as_deref()) {
+        reasons.push(FILTER_REASON_ALLOWED_MANUFACTURERS.to_string());
+    }
+    if config.model_excluded(record.metadata.model.as_deref()) {
+        reasons.push(FILTER_REASON_EXCLUDED_MODELS.to_string());
+    }
+    if config.patient_name_excluded(record.patient_name.as_deref()) {
+        reasons.push(FILTER_REASON_EXCLUDED_PATIENT_NAME.to_string());
+    }
     if config.exclude_secondary_capture && record.metadata.is_secondary_capture {
         reasons.push(FILTER_REASON_EXCLUDE_SECONDARY_CAPTURE.to_string());
     }
+    if config.exclude_localizers && record.metadata.is_localizer {
+        reasons.push(FILTER_REASON_EXCLUDE_LOCALIZERS.to_string());
+    }
+    if config.exclude_specimens && record.metadata.is_specimen {
+        reasons.push(FILTER_REASON_EXCLUDE_SPECIMENS.to_string());
+    }
     if config.exclude_non_mg_modality {
         match &record.metadata.modality {
             Some(modality) if modality.eq_ignore_ascii_case("MG") => {}
@@ -749,7 +789,8 @@ fn filter_reasons(record: &MammogramRecord, config: &FilterConfig) -> Vec<String
 mod tests {
     use super::*;
     use crate::api::MammogramMetadata;
-    use crate::types::{ImageType, Laterality, ViewPosition};
+    use crate::test_support::{sample_metadata, sample_record};
+    use crate::types::{Laterality, ViewPosition};
 
     const STUDY_UID: &str = "1.2.826.0.1";
     const SERIES_UID: &str = "1.2.826.0.1.1";
@@ -780,44 +821,28 @@ mod tests {
         dbt_object_kind: DbtObjectKind,
     ) -> MammogramRecord {
         MammogramRecord {
-            file_path: PathBuf::from(file_name),
-            metadata: MammogramMetadata {
-                mammogram_type,
-                dbt_object_kind,
-                laterality,
-                view_position,
-                view_modifiers: Default::default(),
-                image_type: ImageType::new(
-                    "ORIGINAL".to_string(),
-                    "PRIMARY".to_string(),
-                    None,
-                    None,
-                ),
-                is_for_processing: false,
-                has_implant: false,
-                manufacturer: None,
-                model: None,
-                number_of_frames: if dbt_object_kind == DbtObjectKind::Volume {
-                    50
-                } else {
-                    1
-                },
-                pixel_spacing: None,
-                concatenation_uid: None,
-                sop_instance_uid_of_concatenation_source: None,
-                is_secondary_capture: false,
-                modality: Some("MG".to_string()),
-                transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
-                transfer_syntax_name: Some("Explicit VR Little Endian".to_string()),
-                compression_type: Some("uncompressed".to_string()),
-            },
             study_instance_uid: Some(STUDY_UID.to_string()),
             series_instance_uid: Some(SERIES_UID.to_string()),
             sop_instance_uid: Some(format!("{SERIES_UID}.{file_name}")),
-            rows: Some(2560),
-            columns: Some(3328),
             transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
-            is_lossy_compressed: false,
+            ..sample_record(
+                file_name,
+                MammogramMetadata {
+                    mammogram_type,
+                    dbt_object_kind,
+                    laterality,
+                    view_position,
+                    number_of_frames: if dbt_object_kind == DbtObjectKind::Volume {
+                        50
+                    } else {
+                        1
+                    },
+                    transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
+                    transfer_syntax_name: Some("Explicit VR Little Endian".to_string()),
+                    compression_type: Some("uncompressed".to_string()),
+                    ..sample_metadata()
+                },
+            )
         }
     }
 
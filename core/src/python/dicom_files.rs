@@ -0,0 +1,25 @@
+//! Python wrapper for DICOM file discovery.
+
+use pyo3::prelude::*;
+
+use super::utils::path_to_pathbuf;
+
+/// List DICOM file candidates in a directory, sorted by path.
+///
+/// Files with `.dcm`/`.dicom` extensions are accepted directly; files without
+/// an extension only when they carry the DICM magic bytes. Raises `OSError`
+/// when the directory cannot be read.
+#[pyfunction]
+#[pyo3(name = "scan_directory", signature = (path, recursive=false))]
+pub fn py_scan_directory(path: &Bound<'_, PyAny>, recursive: bool) -> PyResult<Vec<String>> {
+    let directory = path_to_pathbuf(path)?;
+    let files = if recursive {
+        crate::collect_dicom_files_recursively(&directory)?
+    } else {
+        crate::collect_dicom_files(&directory)?
+    };
+    Ok(files
+        .iter()
+        .map(|file| file.display().to_string())
+        .collect())
+}
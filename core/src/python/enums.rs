@@ -4,6 +4,7 @@ use pyo3::prelude::*;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
+use super::errors::convert_error;
 use super::macros::impl_py_from;
 use crate::types::{
     DbtObjectKind, ImageType, Laterality, MammogramType, MammogramView, MammographyViewModifier,
@@ -42,6 +43,19 @@ impl PyMammogramType {
     const SFM: Self = Self {
         inner: MammogramType::Sfm,
     };
+    #[classattr]
+    const CEM: Self = Self {
+        inner: MammogramType::Cem,
+    };
+
+    /// Returns every variant in declaration order
+    #[staticmethod]
+    fn all() -> Vec<Self> {
+        MammogramType::all()
+            .iter()
+            .map(|&inner| Self { inner })
+            .collect()
+    }
 
     fn is_unknown(&self) -> bool {
         self.inner.is_unknown()
@@ -189,6 +203,15 @@ impl PyLaterality {
         inner: Laterality::Bilateral,
     };
 
+    /// Returns every variant in declaration order
+    #[staticmethod]
+    fn all() -> Vec<Self> {
+        Laterality::all()
+            .iter()
+            .map(|&inner| Self { inner })
+            .collect()
+    }
+
     fn is_unknown(&self) -> bool {
         self.inner.is_unknown()
     }
@@ -302,6 +325,15 @@ impl PyViewPosition {
         inner: ViewPosition::Specimen,
     };
 
+    /// Returns every variant in declaration order
+    #[staticmethod]
+    fn all() -> Vec<Self> {
+        ViewPosition::all()
+            .iter()
+            .map(|&inner| Self { inner })
+            .collect()
+    }
+
     fn is_unknown(&self) -> bool {
         self.inner.is_unknown()
     }
@@ -488,12 +520,23 @@ impl PyPreferenceOrder {
         inner: PreferenceOrder::Synthetic2dFirst,
     };
 
-    fn __str__(&self) -> &'static str {
-        match self.inner {
-            PreferenceOrder::Default => "default",
-            PreferenceOrder::TomoFirst => "tomo-first",
-            PreferenceOrder::Synthetic2dFirst => "synthetic-2d-first",
-        }
+    /// Builds a custom ranking from mammogram types, most preferred first
+    ///
+    /// Args:
+    ///     ranking: TOMO, FFDM, SYNTH, SFM, and CEM, each exactly once
+    ///
+    /// Raises:
+    ///     InvalidValueError: If the ranking is not a permutation of the known types
+    #[staticmethod]
+    fn custom(ranking: Vec<PyMammogramType>) -> PyResult<Self> {
+        let ranking: Vec<MammogramType> = ranking.into_iter().map(|t| t.inner).collect();
+        PreferenceOrder::custom(&ranking)
+            .map(|inner| Self { inner })
+            .map_err(convert_error)
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
     }
 
     fn __repr__(&self) -> String {
@@ -511,7 +554,7 @@ impl PyPreferenceOrder {
     }
 
     #[getter]
-    fn value(&self) -> &str {
+    fn value(&self) -> String {
         self.__str__()
     }
 }
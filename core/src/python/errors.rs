@@ -44,6 +44,13 @@ create_exception!(
     "Generic metadata extraction error"
 );
 
+create_exception!(
+    mammocat,
+    PyNotMammographyError,
+    PyExtractionError,
+    "File is not a mammogram (Modality is not MG)"
+);
+
 create_exception!(
     mammocat,
     PySelectionError,
@@ -58,7 +65,13 @@ pub fn convert_error(err: crate::error::MammocatError) -> PyErr {
         crate::error::MammocatError::TagNotFound(msg) => PyTagNotFoundError::new_err(msg),
         crate::error::MammocatError::InvalidValue(msg) => PyInvalidValueError::new_err(msg),
         crate::error::MammocatError::ExtractionError(msg) => PyExtractionError::new_err(msg),
+        err @ crate::error::MammocatError::NotMammography { .. } => {
+            PyNotMammographyError::new_err(err.to_string())
+        }
         crate::error::MammocatError::SelectionError(msg) => PySelectionError::new_err(msg),
+        err @ crate::error::MammocatError::NonImageSopClass(_) => {
+            PyExtractionError::new_err(err.to_string())
+        }
         crate::error::MammocatError::IoError(e) => {
             PyDicomError::new_err(format!("IO error: {}", e))
         }
@@ -1,7 +1,9 @@
 //! Python wrapper for MammogramExtractor
 
-use dicom_object::OpenFileOptions;
+use dicom_core::Tag;
+use dicom_object::{FileDicomObject, InMemDicomObject, OpenFileOptions};
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 use super::errors::convert_error;
 use super::metadata::PyMammogramMetadata;
@@ -37,16 +39,7 @@ impl PyMammogramExtractor {
     #[staticmethod]
     #[pyo3(signature = (path))]
     fn extract_from_file(path: &Bound<'_, PyAny>) -> PyResult<PyMammogramMetadata> {
-        // Convert path to PathBuf
-        let path_buf = path_to_pathbuf(path)?;
-
-        // Open DICOM file
-        let dcm = OpenFileOptions::new()
-            .read_until(crate::extraction::tags::PIXEL_DATA_TAG)
-            .open_file(&path_buf)
-            .map_err(|e| {
-                pyo3::exceptions::PyIOError::new_err(format!("Failed to open DICOM file: {}", e))
-            })?;
+        let dcm = open_dicom_header(path)?;
 
         // Extract metadata
         let metadata = crate::api::MammogramExtractor::extract_file(&dcm).map_err(convert_error)?;
@@ -59,6 +52,8 @@ impl PyMammogramExtractor {
     /// Args:
     ///     path: Path to the DICOM file (str or pathlib.Path)
     ///     is_sfm: Whether to treat as SFM instead of FFDM (default: False)
+    ///     ignore_modality: Classify the file even when its Modality tag is
+    ///         not "MG", e.g. for mislabeled research data (default: False)
     ///
     /// Returns:
     ///     MammogramMetadata: Extracted metadata
@@ -75,26 +70,103 @@ impl PyMammogramExtractor {
     ///     ...     "mammogram.dcm", is_sfm=True
     ///     ... )
     #[staticmethod]
-    #[pyo3(signature = (path, is_sfm=false))]
+    #[pyo3(signature = (path, is_sfm=false, ignore_modality=false))]
     fn extract_from_file_with_options(
         path: &Bound<'_, PyAny>,
         is_sfm: bool,
+        ignore_modality: bool,
     ) -> PyResult<PyMammogramMetadata> {
-        // Convert path to PathBuf
-        let path_buf = path_to_pathbuf(path)?;
-
-        // Open DICOM file
-        let dcm = OpenFileOptions::new()
-            .read_until(crate::extraction::tags::PIXEL_DATA_TAG)
-            .open_file(&path_buf)
-            .map_err(|e| {
-                pyo3::exceptions::PyIOError::new_err(format!("Failed to open DICOM file: {}", e))
-            })?;
+        let dcm = open_dicom_header(path)?;
 
         // Extract metadata with options
-        let metadata = crate::api::MammogramExtractor::extract_file_with_options(&dcm, is_sfm)
+        let metadata =
+            crate::api::MammogramExtractor::extract_file_with_options_and_modality_policy(
+                &dcm,
+                is_sfm,
+                ignore_modality,
+            )
             .map_err(convert_error)?;
 
         Ok(metadata.into())
     }
+
+    /// Read a single DICOM tag as a string
+    ///
+    /// Escape hatch for tags mammocat does not model, such as KVP or
+    /// CompressionForce, without installing pydicom. Multi-valued elements
+    /// are joined with a backslash. Pixel data is not read.
+    ///
+    /// Args:
+    ///     path: Path to the DICOM file (str or pathlib.Path)
+    ///     group: Tag group number, e.g. 0x0018
+    ///     element: Tag element number, e.g. 0x11A0
+    ///
+    /// Returns:
+    ///     str | None: Trimmed tag value, or None if the tag is absent or
+    ///     cannot be represented as a string
+    ///
+    /// Raises:
+    ///     IOError: If the file cannot be read or parsed
+    ///
+    /// Example:
+    ///     >>> from mammocat import MammogramExtractor
+    ///     >>> # BodyPartThickness (0018,11A0), in millimeters
+    ///     >>> MammogramExtractor.get_tag("mammogram.dcm", 0x0018, 0x11A0)
+    ///     '52'
+    ///     >>> MammogramExtractor.get_tag("no_thickness.dcm", 0x0018, 0x11A0) is None
+    ///     True
+    #[staticmethod]
+    #[pyo3(signature = (path, group, element))]
+    fn get_tag(path: &Bound<'_, PyAny>, group: u16, element: u16) -> PyResult<Option<String>> {
+        let dcm = open_dicom_header(path)?;
+        Ok(crate::extraction::tags::get_string_value(
+            &dcm,
+            Tag(group, element),
+        ))
+    }
+
+    /// Read display windowing and rescale parameters from a DICOM file
+    ///
+    /// Multi-valued WindowCenter/WindowWidth use the first (default) preset.
+    /// The window is only reported when both values are present and the
+    /// width is positive. Pixel data is not read.
+    ///
+    /// Args:
+    ///     path: Path to the DICOM file (str or pathlib.Path)
+    ///
+    /// Returns:
+    ///     dict: ``window_center``, ``window_width``, ``rescale_slope``, and
+    ///     ``rescale_intercept``, each a float or None
+    ///
+    /// Raises:
+    ///     IOError: If the file cannot be read or parsed
+    ///
+    /// Example:
+    ///     >>> from mammocat import MammogramExtractor
+    ///     >>> display = MammogramExtractor.extract_display_metadata("mammogram.dcm")
+    ///     >>> display["window_center"], display["window_width"]
+    ///     (2047.5, 4095.0)
+    #[staticmethod]
+    #[pyo3(signature = (path))]
+    fn extract_display_metadata(py: Python, path: &Bound<'_, PyAny>) -> PyResult<Py<PyDict>> {
+        let dcm = open_dicom_header(path)?;
+        let display = crate::extraction::extract_display_metadata(&dcm);
+        let dict = PyDict::new_bound(py);
+        dict.set_item("window_center", display.window.map(|window| window.center))?;
+        dict.set_item("window_width", display.window.map(|window| window.width))?;
+        dict.set_item("rescale_slope", display.rescale_slope)?;
+        dict.set_item("rescale_intercept", display.rescale_intercept)?;
+        Ok(dict.unbind())
+    }
+}
+
+/// Opens a DICOM file, reading headers only (stops before pixel data).
+fn open_dicom_header(path: &Bound<'_, PyAny>) -> PyResult<FileDicomObject<InMemDicomObject>> {
+    let path_buf = path_to_pathbuf(path)?;
+    OpenFileOptions::new()
+        .read_until(crate::extraction::tags::PIXEL_DATA_TAG)
+        .open_file(&path_buf)
+        .map_err(|e| {
+            pyo3::exceptions::PyIOError::new_err(format!("Failed to open DICOM file: {}", e))
+        })
 }
@@ -25,7 +25,18 @@ impl PyFilterConfig {
         require_common_modality=false,
         exclude_lossy_compressed=false,
         deprioritize_lossy_compressed=true,
-        allowed_dbt_object_kinds=None
+        allowed_dbt_object_kinds=None,
+        require_for_presentation=false,
+        min_frames=None,
+        exclude_spot_mag=false,
+        min_image_area=None,
+        allowed_manufacturers=None,
+        excluded_models=None,
+        prefer_latest_study=false,
+        exclude_patient_name_patterns=None,
+        exclude_phantoms=false,
+        exclude_localizers=true,
+        exclude_specimens=true
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -39,6 +50,17 @@ impl PyFilterConfig {
         exclude_lossy_compressed: bool,
         deprioritize_lossy_compressed: bool,
         allowed_dbt_object_kinds: Option<Vec<PyDbtObjectKind>>,
+        require_for_presentation: bool,
+        min_frames: Option<i32>,
+        exclude_spot_mag: bool,
+        min_image_area: Option<u32>,
+        allowed_manufacturers: Option<Vec<String>>,
+        excluded_models: Option<Vec<String>>,
+        prefer_latest_study: bool,
+        exclude_patient_name_patterns: Option<Vec<String>>,
+        exclude_phantoms: bool,
+        exclude_localizers: bool,
+        exclude_specimens: bool,
     ) -> Self {
         let rust_allowed =
             allowed_types.map(|types| types.into_iter().map(|t| t.inner).collect::<HashSet<_>>());
@@ -49,20 +71,32 @@ impl PyFilterConfig {
                 .collect::<HashSet<_>>()
         });
 
-        Self {
-            inner: FilterConfig {
-                allowed_types: rust_allowed,
-                allowed_dbt_object_kinds: rust_allowed_dbt_object_kinds,
-                exclude_implants,
-                exclude_non_standard_views,
-                exclude_for_processing,
-                exclude_secondary_capture,
-                exclude_non_mg_modality,
-                exclude_lossy_compressed,
-                deprioritize_lossy_compressed,
-                require_common_modality,
-            },
-        }
+        let inner = FilterConfig {
+            allowed_types: rust_allowed,
+            allowed_dbt_object_kinds: rust_allowed_dbt_object_kinds,
+            exclude_implants,
+            exclude_non_standard_views,
+            exclude_spot_mag,
+            exclude_for_processing,
+            require_for_presentation,
+            min_frames,
+            min_image_area,
+            allowed_manufacturers: allowed_manufacturers
+                .map(|values| values.into_iter().collect::<HashSet<_>>()),
+            excluded_models: excluded_models
+                .map(|values| values.into_iter().collect::<HashSet<_>>()),
+            exclude_patient_name_patterns,
+            exclude_phantoms,
+            exclude_secondary_capture,
+            exclude_localizers,
+            exclude_specimens,
+            exclude_non_mg_modality,
+            exclude_lossy_compressed,
+            deprioritize_lossy_compressed,
+            require_common_modality,
+            prefer_latest_study,
+        };
+        Self { inner }
     }
 
     #[staticmethod]
@@ -107,16 +141,72 @@ impl PyFilterConfig {
         self.inner.exclude_non_standard_views
     }
 
+    #[getter]
+    fn exclude_spot_mag(&self) -> bool {
+        self.inner.exclude_spot_mag
+    }
+
     #[getter]
     fn exclude_for_processing(&self) -> bool {
         self.inner.exclude_for_processing
     }
 
+    #[getter]
+    fn require_for_presentation(&self) -> bool {
+        self.inner.require_for_presentation
+    }
+
+    #[getter]
+    fn min_frames(&self) -> Option<i32> {
+        self.inner.min_frames
+    }
+
+    #[getter]
+    fn min_image_area(&self) -> Option<u32> {
+        self.inner.min_image_area
+    }
+
+    #[getter]
+    fn allowed_manufacturers(&self) -> Option<Vec<String>> {
+        self.inner
+            .allowed_manufacturers
+            .as_ref()
+            .map(|values| values.iter().cloned().collect())
+    }
+
+    #[getter]
+    fn excluded_models(&self) -> Option<Vec<String>> {
+        self.inner
+            .excluded_models
+            .as_ref()
+            .map(|values| values.iter().cloned().collect())
+    }
+
+    #[getter]
+    fn exclude_patient_name_patterns(&self) -> Option<Vec<String>> {
+        self.inner.exclude_patient_name_patterns.clone()
+    }
+
+    #[getter]
+    fn exclude_phantoms(&self) -> bool {
+        self.inner.exclude_phantoms
+    }
+
     #[getter]
     fn exclude_secondary_capture(&self) -> bool {
         self.inner.exclude_secondary_capture
     }
 
+    #[getter]
+    fn exclude_localizers(&self) -> bool {
+        self.inner.exclude_localizers
+    }
+
+    #[getter]
+    fn exclude_specimens(&self) -> bool {
+        self.inner.exclude_specimens
+    }
+
     #[getter]
     fn exclude_non_mg_modality(&self) -> bool {
         self.inner.exclude_non_mg_modality
@@ -137,6 +227,11 @@ impl PyFilterConfig {
         self.inner.deprioritize_lossy_compressed
     }
 
+    #[getter]
+    fn prefer_latest_study(&self) -> bool {
+        self.inner.prefer_latest_study
+    }
+
     fn __repr__(&self) -> String {
         format!("FilterConfig({:?})", self.inner)
     }
@@ -1,15 +1,31 @@
 //! Python wrapper for MammogramMetadata
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyType};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 use super::enums::{
     PyDbtObjectKind, PyImageType, PyLaterality, PyMammogramType, PyMammogramView,
-    PyMammographyViewModifier, PyViewPosition,
+    PyMammographyViewModifier, PyPhotometricInterpretation, PyViewPosition,
 };
 use super::utils::option_string_to_py;
 
 /// Python wrapper for MammogramMetadata
+///
+/// Instances compare equal when every extracted field matches, are hashable,
+/// and can be pickled, e.g. to pass them between `multiprocessing` workers.
+///
+/// Example:
+///     >>> import pickle
+///     >>> from mammocat import MammogramRecord
+///     >>> metadata = MammogramRecord.from_file("image.dcm").metadata
+///     >>> restored = pickle.loads(pickle.dumps(metadata))
+///     >>> restored == metadata
+///     True
+///     >>> len({metadata, restored})
+///     1
 #[pyclass(name = "MammogramMetadata", module = "mammocat")]
 #[derive(Clone)]
 pub struct PyMammogramMetadata {
@@ -65,6 +81,12 @@ impl PyMammogramMetadata {
         self.inner.is_for_processing
     }
 
+    /// PresentationIntentType ("FOR PRESENTATION", "FOR PROCESSING", or "UNKNOWN")
+    #[getter]
+    fn presentation_intent(&self) -> String {
+        self.inner.presentation_intent.to_string()
+    }
+
     /// Whether breast implant is present
     #[getter]
     fn has_implant(&self) -> bool {
@@ -83,6 +105,12 @@ impl PyMammogramMetadata {
         self.inner.is_magnified()
     }
 
+    /// Whether this is a rolled view
+    #[getter]
+    fn is_rolled(&self) -> bool {
+        self.inner.is_rolled()
+    }
+
     /// Whether this is an implant displaced view
     #[getter]
     fn is_implant_displaced(&self) -> bool {
@@ -110,14 +138,55 @@ impl PyMammogramMetadata {
     /// Pixel spacing in millimeters, when available.
     #[getter]
     fn pixel_spacing(&self, py: Python) -> PyResult<PyObject> {
-        let Some(pixel_spacing) = self.inner.pixel_spacing else {
-            return Ok(py.None());
-        };
+        pixel_spacing_to_py(py, self.inner.pixel_spacing)
+    }
 
-        let dict = PyDict::new_bound(py);
-        dict.set_item("row", pixel_spacing.row)?;
-        dict.set_item("column", pixel_spacing.col)?;
-        Ok(dict.unbind().into())
+    /// Detector-plane ImagerPixelSpacing in millimeters, when present.
+    #[getter]
+    fn imager_pixel_spacing(&self, py: Python) -> PyResult<PyObject> {
+        pixel_spacing_to_py(py, self.inner.imager_pixel_spacing)
+    }
+
+    /// Ratio of ImagerPixelSpacing to PixelSpacing, when both are available.
+    #[getter]
+    fn spacing_magnification_factor(&self) -> Option<f64> {
+        self.inner.spacing_magnification_factor()
+    }
+
+    /// Photometric interpretation (MONOCHROME1, MONOCHROME2, etc.)
+    #[getter]
+    fn photometric(&self) -> PyPhotometricInterpretation {
+        self.inner.photometric.into()
+    }
+
+    /// Whether pixel values must be inverted for display (MONOCHROME1)
+    #[getter]
+    fn needs_inversion(&self) -> bool {
+        self.inner.needs_inversion()
+    }
+
+    /// RescaleSlope for stored pixel values, when present
+    #[getter]
+    fn rescale_slope(&self) -> Option<f64> {
+        self.inner.rescale_slope
+    }
+
+    /// RescaleIntercept for stored pixel values, when present
+    #[getter]
+    fn rescale_intercept(&self) -> Option<f64> {
+        self.inner.rescale_intercept
+    }
+
+    /// BodyPartThickness (compressed breast thickness) in mm, when present
+    #[getter]
+    fn body_part_thickness_mm(&self) -> Option<f64> {
+        self.inner.body_part_thickness_mm
+    }
+
+    /// CompressionForce in newtons, when present
+    #[getter]
+    fn compression_force_n(&self) -> Option<f64> {
+        self.inner.compression_force_n
     }
 
     /// DICOM ConcatenationUID, when present
@@ -141,12 +210,30 @@ impl PyMammogramMetadata {
         self.inner.is_secondary_capture
     }
 
+    /// Whether this is a scout/localizer frame
+    #[getter]
+    fn is_localizer(&self) -> bool {
+        self.inner.is_localizer
+    }
+
+    /// Whether this is a specimen image
+    #[getter]
+    fn is_specimen(&self) -> bool {
+        self.inner.is_specimen
+    }
+
     /// DICOM Modality (should be "MG" for mammography)
     #[getter]
     fn modality(&self, py: Python) -> PyObject {
         option_string_to_py(py, self.inner.modality.clone())
     }
 
+    /// DICOM SpecificCharacterSet used to decode text tags
+    #[getter]
+    fn character_set(&self, py: Python) -> PyObject {
+        option_string_to_py(py, self.inner.character_set.clone())
+    }
+
     /// DICOM Transfer Syntax UID from file meta information
     #[getter]
     fn transfer_syntax_uid(&self, py: Python) -> PyObject {
@@ -200,21 +287,33 @@ impl PyMammogramMetadata {
         )?;
         dict.set_item("image_type", format!("{}", self.inner.image_type))?;
         dict.set_item("is_for_processing", self.is_for_processing())?;
+        dict.set_item("presentation_intent", self.presentation_intent())?;
         dict.set_item("has_implant", self.has_implant())?;
         dict.set_item("is_spot_compression", self.is_spot_compression())?;
         dict.set_item("is_magnified", self.is_magnified())?;
         dict.set_item("is_implant_displaced", self.is_implant_displaced())?;
+        dict.set_item("is_rolled", self.is_rolled())?;
         dict.set_item("manufacturer", self.manufacturer(py))?;
         dict.set_item("model", self.model(py))?;
         dict.set_item("number_of_frames", self.number_of_frames())?;
         dict.set_item("pixel_spacing", self.pixel_spacing(py)?)?;
+        dict.set_item("imager_pixel_spacing", self.imager_pixel_spacing(py)?)?;
+        dict.set_item("photometric", self.inner.photometric.to_string())?;
+        dict.set_item("needs_inversion", self.needs_inversion())?;
+        dict.set_item("rescale_slope", self.rescale_slope())?;
+        dict.set_item("rescale_intercept", self.rescale_intercept())?;
+        dict.set_item("body_part_thickness_mm", self.body_part_thickness_mm())?;
+        dict.set_item("compression_force_n", self.compression_force_n())?;
         dict.set_item("concatenation_uid", self.concatenation_uid(py))?;
         dict.set_item(
             "sop_instance_uid_of_concatenation_source",
             self.sop_instance_uid_of_concatenation_source(py),
         )?;
         dict.set_item("is_secondary_capture", self.is_secondary_capture())?;
+        dict.set_item("is_localizer", self.is_localizer())?;
+        dict.set_item("is_specimen", self.is_specimen())?;
         dict.set_item("modality", self.modality(py))?;
+        dict.set_item("character_set", self.character_set(py))?;
         dict.set_item("transfer_syntax_uid", self.transfer_syntax_uid(py))?;
         dict.set_item("transfer_syntax_name", self.transfer_syntax_name(py))?;
         dict.set_item("compression_type", self.compression_type(py))?;
@@ -234,6 +333,54 @@ impl PyMammogramMetadata {
     fn __str__(&self) -> String {
         self.__repr__()
     }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    /// Hashes the identifying fields; float fields are left out
+    fn __hash__(&self) -> u64 {
+        let metadata = &self.inner;
+        let mut hasher = DefaultHasher::new();
+        (
+            metadata.mammogram_type,
+            metadata.dbt_object_kind,
+            metadata.laterality,
+            metadata.view_position,
+            metadata.image_type.to_string(),
+            metadata.is_for_processing,
+            &metadata.manufacturer,
+            &metadata.model,
+            metadata.number_of_frames,
+            &metadata.modality,
+        )
+            .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __reduce__(slf: &Bound<'_, Self>) -> PyResult<(PyObject, (String,))> {
+        let from_state = slf.get_type().getattr("_from_state")?.unbind();
+        Ok((from_state, (slf.borrow().__getstate__()?,)))
+    }
+
+    fn __getstate__(&self) -> PyResult<String> {
+        serde_json::to_string(&self.inner).map_err(|error| {
+            PyValueError::new_err(format!("failed to serialize metadata: {error}"))
+        })
+    }
+
+    fn __setstate__(&mut self, state: &str) -> PyResult<()> {
+        self.inner = metadata_from_state(state)?;
+        Ok(())
+    }
+
+    /// Rebuilds metadata from a `__getstate__` value; used by pickle
+    #[classmethod]
+    fn _from_state(_cls: &Bound<'_, PyType>, state: &str) -> PyResult<Self> {
+        Ok(Self {
+            inner: metadata_from_state(state)?,
+        })
+    }
 }
 
 impl From<crate::api::MammogramMetadata> for PyMammogramMetadata {
@@ -241,3 +388,22 @@ impl From<crate::api::MammogramMetadata> for PyMammogramMetadata {
         Self { inner }
     }
 }
+
+fn metadata_from_state(state: &str) -> PyResult<crate::api::MammogramMetadata> {
+    serde_json::from_str(state)
+        .map_err(|error| PyValueError::new_err(format!("failed to deserialize metadata: {error}")))
+}
+
+fn pixel_spacing_to_py(
+    py: Python,
+    pixel_spacing: Option<crate::types::PixelSpacing>,
+) -> PyResult<PyObject> {
+    let Some(pixel_spacing) = pixel_spacing else {
+        return Ok(py.None());
+    };
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("row", pixel_spacing.row)?;
+    dict.set_item("column", pixel_spacing.col)?;
+    Ok(dict.unbind().into())
+}
@@ -9,6 +9,7 @@
 use pyo3::prelude::*;
 
 mod dbt;
+mod dicom_files;
 mod enums;
 mod errors;
 mod extractor;
@@ -23,6 +24,7 @@ mod utils;
 mod validation;
 
 pub use dbt::*;
+pub use dicom_files::*;
 pub use enums::*;
 pub use errors::*;
 pub use extractor::*;
@@ -53,6 +55,10 @@ fn _mammocat(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "ExtractionError",
         py.get_type_bound::<errors::PyExtractionError>(),
     )?;
+    m.add(
+        "NotMammographyError",
+        py.get_type_bound::<errors::PyNotMammographyError>(),
+    )?;
     m.add(
         "SelectionError",
         py.get_type_bound::<errors::PySelectionError>(),
@@ -78,12 +84,17 @@ fn _mammocat(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyMammogramExtractor>()?;
 
     // Register functions
+    m.add_function(wrap_pyfunction!(py_scan_directory, m)?)?;
     m.add_function(wrap_pyfunction!(py_scan_dbt_study, m)?)?;
     m.add_function(wrap_pyfunction!(py_convert_dbt_study, m)?)?;
     m.add_function(wrap_pyfunction!(py_plan_mammography_collection, m)?)?;
     m.add_function(wrap_pyfunction!(py_get_preferred_views, m)?)?;
     m.add_function(wrap_pyfunction!(py_get_preferred_views_with_order, m)?)?;
     m.add_function(wrap_pyfunction!(py_get_preferred_views_filtered, m)?)?;
+    m.add_function(wrap_pyfunction!(py_get_ranked_views, m)?)?;
+    m.add_function(wrap_pyfunction!(py_process_directory, m)?)?;
+    m.add_function(wrap_pyfunction!(py_study_completeness, m)?)?;
+    m.add_function(wrap_pyfunction!(py_missing_views, m)?)?;
     validation::register(m)?;
 
     // Register constants
@@ -18,6 +18,9 @@ pub struct PyMammogramRecord {
     pub(crate) inner: crate::selection::MammogramRecord,
 }
 
+/// Parsed records plus `(path, error_message)` for files that failed
+type BatchRecords = (Vec<PyMammogramRecord>, Vec<(String, String)>);
+
 #[pymethods]
 impl PyMammogramRecord {
     /// Create a record from a DICOM file path
@@ -75,6 +78,46 @@ impl PyMammogramRecord {
         Ok(PyMammogramRecord { inner: record })
     }
 
+    /// Create records for many DICOM files in one call
+    ///
+    /// Files are read in Rust with the GIL released. Unreadable files do not
+    /// raise; they are reported alongside the successfully parsed records.
+    ///
+    /// Args:
+    ///     paths: List of DICOM file paths (str or pathlib.Path)
+    ///
+    /// Returns:
+    ///     tuple[list[MammogramRecord], list[tuple[str, str]]]: Records in input
+    ///     order, and ``(path, error_message)`` for each file that failed
+    ///
+    /// Example:
+    ///     >>> from mammocat import MammogramRecord
+    ///     >>> records, failures = MammogramRecord.from_files(["lcc.dcm", "missing.dcm"])
+    ///     >>> [r.file_path for r in records]
+    ///     ['lcc.dcm']
+    ///     >>> [path for path, _ in failures]
+    ///     ['missing.dcm']
+    #[staticmethod]
+    fn from_files(py: Python<'_>, paths: Vec<Bound<'_, PyAny>>) -> PyResult<BatchRecords> {
+        let path_bufs = paths
+            .iter()
+            .map(path_to_pathbuf)
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let (records, failures) = py.allow_threads(|| {
+            let mut records = Vec::new();
+            let mut failures = Vec::new();
+            for path in path_bufs {
+                match crate::selection::MammogramRecord::from_file(path.clone()) {
+                    Ok(record) => records.push(PyMammogramRecord { inner: record }),
+                    Err(e) => failures.push((path.display().to_string(), e.to_string())),
+                }
+            }
+            (records, failures)
+        });
+        Ok((records, failures))
+    }
+
     /// Path to the DICOM file
     #[getter]
     fn file_path(&self) -> String {
@@ -131,6 +174,42 @@ impl PyMammogramRecord {
         self.inner.is_lossy_compressed
     }
 
+    /// Study Date as an ISO 8601 string (YYYY-MM-DD), if available
+    #[getter]
+    fn study_date(&self, py: Python) -> PyObject {
+        option_string_to_py(py, self.inner.study_date.map(|date| date.to_string()))
+    }
+
+    /// Patient ID, if available
+    #[getter]
+    fn patient_id(&self, py: Python) -> PyObject {
+        option_string_to_py(py, self.inner.patient_id.clone())
+    }
+
+    /// Patient's Name, if available
+    #[getter]
+    fn patient_name(&self, py: Python) -> PyObject {
+        option_string_to_py(py, self.inner.patient_name.clone())
+    }
+
+    /// Pixel data compression family from the Transfer Syntax UID
+    ///
+    /// One of "uncompressed", "rle", "jpeg_lossless", "jpeg_lossy",
+    /// "jpeg2000", "jpeg_ls", or "other".
+    #[getter]
+    fn compression(&self) -> &'static str {
+        self.inner.compression().simple_name()
+    }
+
+    /// SOP class family from SOPClassUID
+    ///
+    /// One of "mammo_image", "tomo_image", "cad_sr", "presentation_state",
+    /// "key_object_selection", "secondary_capture", or "other".
+    #[getter]
+    fn sop_class(&self) -> &'static str {
+        self.inner.sop_class.simple_name()
+    }
+
     /// Whether this is an implant displaced view
     #[getter]
     fn is_implant_displaced(&self) -> bool {
@@ -149,6 +228,12 @@ impl PyMammogramRecord {
         self.inner.is_magnified()
     }
 
+    /// Whether this is a rolled view
+    #[getter]
+    fn is_rolled(&self) -> bool {
+        self.inner.is_rolled()
+    }
+
     /// Compute image area (rows * columns)
     ///
     /// Returns:
@@ -157,6 +242,15 @@ impl PyMammogramRecord {
         self.inner.image_area()
     }
 
+    /// Compute physical image size in millimeters
+    ///
+    /// Returns:
+    ///     Optional[tuple[float, float]]: (rows * row spacing, columns * column
+    ///         spacing), or None if dimensions or pixel spacing are not available
+    fn physical_size_mm(&self) -> Option<(f64, f64)> {
+        self.inner.physical_size_mm()
+    }
+
     /// Check if this is a spot compression or magnification view
     ///
     /// These views are deprioritized during selection.
@@ -171,12 +265,17 @@ impl PyMammogramRecord {
     ///
     /// Priority order:
     /// 1. Standard views beat non-standard views
-    /// 2. Non-spot/mag views beat spot/mag views
-    /// 3. Implant displaced beats non-displaced (same study only)
-    /// 4. Lossless beats lossy compressed
-    /// 5. Type preference (FFDM > SYNTH > TOMO > SFM)
-    /// 6. Higher resolution beats lower resolution
-    /// 7. Fallback to SOP Instance UID comparison
+    /// 2. Single-breast images beat bilateral acquisitions
+    /// 3. Views without deprioritized CID 4015 modifiers beat modified views
+    /// 4. Newer StudyDate beats older or undated (only with `prefer_latest_study`,
+    ///    which this method leaves off)
+    /// 5. Records are partitioned by StudyInstanceUID for stable cross-study ordering
+    /// 6. Implant displaced beats non-displaced within a study
+    /// 7. Lossless beats lossy compressed
+    /// 8. Type preference (FFDM > SYNTH > TOMO > SFM > CEM)
+    /// 9. Higher resolution beats lower resolution
+    /// 10. Higher bit depth (BitsStored) beats lower bit depth
+    /// 11. Stable source identifiers break remaining ties
     ///
     /// Args:
     ///     other: Another MammogramRecord to compare against
@@ -191,12 +290,17 @@ impl PyMammogramRecord {
     ///
     /// Priority order:
     /// 1. Standard views beat non-standard views
-    /// 2. Non-spot/mag views beat spot/mag views
-    /// 3. Implant displaced beats non-displaced (same study only)
-    /// 4. Lossless beats lossy compressed
-    /// 5. Type preference (according to the provided preference order)
-    /// 6. Higher resolution beats lower resolution
-    /// 7. Fallback to SOP Instance UID comparison
+    /// 2. Single-breast images beat bilateral acquisitions
+    /// 3. Views without deprioritized CID 4015 modifiers beat modified views
+    /// 4. Newer StudyDate beats older or undated (only with `prefer_latest_study`,
+    ///    which this method leaves off)
+    /// 5. Records are partitioned by StudyInstanceUID for stable cross-study ordering
+    /// 6. Implant displaced beats non-displaced within a study
+    /// 7. Lossless beats lossy compressed
+    /// 8. Type preference (according to the provided preference order)
+    /// 9. Higher resolution beats lower resolution
+    /// 10. Higher bit depth (BitsStored) beats lower bit depth
+    /// 11. Stable source identifiers break remaining ties
     ///
     /// Args:
     ///     other: Another MammogramRecord to compare against
@@ -225,8 +329,14 @@ impl PyMammogramRecord {
         dict.set_item("columns", self.columns(py))?;
         dict.set_item("transfer_syntax_uid", self.transfer_syntax_uid(py))?;
         dict.set_item("is_lossy_compressed", self.is_lossy_compressed())?;
+        dict.set_item("study_date", self.study_date(py))?;
+        dict.set_item("patient_id", self.patient_id(py))?;
+        dict.set_item("patient_name", self.patient_name(py))?;
+        dict.set_item("compression", self.compression())?;
+        dict.set_item("sop_class", self.sop_class())?;
         dict.set_item("is_implant_displaced", self.is_implant_displaced())?;
         dict.set_item("is_spot_compression", self.is_spot_compression())?;
+        dict.set_item("is_rolled", self.is_rolled())?;
         dict.set_item("is_magnified", self.is_magnified())?;
         Ok(dict.unbind())
     }
@@ -8,6 +8,7 @@ use super::enums::{PyMammogramView, PyPreferenceOrder};
 use super::errors::convert_error;
 use super::filter::PyFilterConfig;
 use super::record::PyMammogramRecord;
+use super::utils::path_to_pathbuf;
 use crate::selection::{
     self as core_selection, MammogramRecord, SelectionWarning, StudySelectionMode,
 };
@@ -21,7 +22,7 @@ type PreferredViewSelectionWithWarnings = (PreferredViewSelection, Vec<Selection
 ///
 /// For each of the 4 standard views (L-MLO, R-MLO, L-CC, R-CC), selects the
 /// most preferred mammogram based on comparison logic using the default
-/// preference order (FFDM > SYNTH > TOMO > SFM).
+/// preference order (FFDM > SYNTH > TOMO > SFM > CEM).
 ///
 /// Args:
 ///     records: List of MammogramRecord objects to select from
@@ -100,6 +101,50 @@ pub fn py_get_preferred_views_with_order(
     hashmap_to_py_dict(py, result)
 }
 
+/// Rank all candidates for each standard view, best first
+///
+/// Uses the same comparison logic as `get_preferred_views_with_order`, so the
+/// first record of each list is the record that function would select.
+///
+/// Args:
+///     records: List of MammogramRecord objects to rank
+///     preference_order: The preference ordering strategy to use
+///
+/// Returns:
+///     dict: Dictionary mapping MammogramView to a list of MammogramRecord,
+///         best first (empty if no candidates)
+///
+/// Example:
+///     >>> from mammocat import MammogramRecord, get_ranked_views, PreferenceOrder
+///     >>> from pathlib import Path
+///     >>> records = [MammogramRecord.from_file(f) for f in Path("dicoms").glob("*.dcm")]
+///     >>> ranked = get_ranked_views(records, PreferenceOrder.DEFAULT)
+///     >>> for view, candidates in ranked.items():
+///     ...     print(f"{view}: {[str(r.file_path) for r in candidates]}")
+#[pyfunction]
+#[pyo3(name = "get_ranked_views")]
+#[pyo3(signature = (records, preference_order))]
+pub fn py_get_ranked_views(
+    py: Python,
+    records: Vec<PyMammogramRecord>,
+    preference_order: PyPreferenceOrder,
+) -> PyResult<Py<PyDict>> {
+    let rust_records: Vec<_> = records.into_iter().map(|r| r.inner).collect();
+    let ranked = core_selection::get_ranked_views(&rust_records, preference_order.inner);
+
+    let dict = PyDict::new_bound(py);
+    for (view, candidates) in ranked.into_iter() {
+        let py_view = PyMammogramView::from(view).into_py(py);
+        let py_candidates: Vec<PyMammogramRecord> = candidates
+            .into_iter()
+            .map(PyMammogramRecord::from)
+            .collect();
+        dict.set_item(py_view, py_candidates.into_py(py))?;
+    }
+
+    Ok(dict.unbind())
+}
+
 /// Select preferred views with filtering
 ///
 /// Applies filters before selecting preferred views. For each of the 4 standard views
@@ -158,6 +203,98 @@ pub fn py_get_preferred_views_filtered(
     hashmap_to_py_dict(py, result)
 }
 
+/// Scan a directory, read every DICOM file, and select preferred views
+///
+/// Files that cannot be read as mammogram records are reported instead of
+/// raising. Selection warnings are emitted as `UserWarning`.
+///
+/// Args:
+///     path: Directory to scan
+///     filter_config: FilterConfig applied before selection (default: FilterConfig())
+///     preference_order: The preference ordering strategy (default: PreferenceOrder.DEFAULT)
+///     recursive: Whether to descend into subdirectories
+///
+/// Returns:
+///     dict: ``selections`` (MammogramView to MammogramRecord or None),
+///         ``records_processed`` (int), and ``skipped`` (list of
+///         ``(path, reason)`` tuples)
+///
+/// Example:
+///     >>> from mammocat import process_directory
+///     >>> result = process_directory("dicoms", recursive=True)
+///     >>> for path, reason in result["skipped"]:
+///     ...     print(f"skipped {path}: {reason}")
+#[pyfunction]
+#[pyo3(name = "process_directory")]
+#[pyo3(signature = (path, filter_config=None, preference_order=None, recursive=false))]
+pub fn py_process_directory(
+    py: Python,
+    path: &Bound<'_, PyAny>,
+    filter_config: Option<PyFilterConfig>,
+    preference_order: Option<PyPreferenceOrder>,
+    recursive: bool,
+) -> PyResult<Py<PyDict>> {
+    let directory = path_to_pathbuf(path)?;
+    let filter_config = filter_config.map(|config| config.inner).unwrap_or_default();
+    let preference_order = preference_order
+        .map(|order| order.inner)
+        .unwrap_or_default();
+    let result =
+        core_selection::process_directory(&directory, &filter_config, preference_order, recursive)
+            .map_err(convert_error)?;
+
+    emit_selection_warnings(py, &result.warnings)?;
+    let skipped: Vec<(String, String)> = result
+        .skipped
+        .into_iter()
+        .map(|(file_path, reason)| (file_path.display().to_string(), reason))
+        .collect();
+    let dict = PyDict::new_bound(py);
+    dict.set_item("selections", hashmap_to_py_dict(py, result.selections)?)?;
+    dict.set_item("records_processed", result.records_processed)?;
+    dict.set_item("skipped", skipped)?;
+    Ok(dict.unbind())
+}
+
+/// Fraction of the four standard views with a selected record
+///
+/// Args:
+///     selections: Dictionary mapping MammogramView to MammogramRecord (or None),
+///         as returned by the get_preferred_views functions
+///
+/// Returns:
+///     float: 1.0 for a complete bilateral study, 0.75 with one view missing,
+///     0.0 when no standard view is selected
+///
+/// Example:
+///     >>> from mammocat import get_preferred_views, study_completeness
+///     >>> score = study_completeness(get_preferred_views(records))
+#[pyfunction]
+#[pyo3(name = "study_completeness")]
+pub fn py_study_completeness(selections: &Bound<'_, PyDict>) -> PyResult<f32> {
+    Ok(core_selection::study_completeness(&py_dict_to_selection(
+        selections,
+    )?))
+}
+
+/// Standard views without a selected record, in standard view order
+///
+/// Args:
+///     selections: Dictionary mapping MammogramView to MammogramRecord (or None)
+///
+/// Returns:
+///     list[MammogramView]: Missing views (empty for a complete study)
+#[pyfunction]
+#[pyo3(name = "missing_views")]
+pub fn py_missing_views(selections: &Bound<'_, PyDict>) -> PyResult<Vec<PyMammogramView>> {
+    Ok(
+        core_selection::missing_views(&py_dict_to_selection(selections)?)
+            .into_iter()
+            .map(PyMammogramView::from)
+            .collect(),
+    )
+}
+
 fn select_unfiltered_views(
     records: &[MammogramRecord],
     preference_order: PreferenceOrder,
@@ -187,6 +324,17 @@ fn emit_selection_warnings(py: Python, warnings: &[SelectionWarning]) -> PyResul
     Ok(())
 }
 
+/// Convert a Python selection dict back to HashMap<MammogramView, Option<MammogramRecord>>
+fn py_dict_to_selection(dict: &Bound<'_, PyDict>) -> PyResult<PreferredViewSelection> {
+    dict.iter()
+        .map(|(view, record)| {
+            let view: PyMammogramView = view.extract()?;
+            let record: Option<PyMammogramRecord> = record.extract()?;
+            Ok((view.inner, record.map(|record| record.inner)))
+        })
+        .collect()
+}
+
 /// Convert HashMap<MammogramView, Option<MammogramRecord>> to Python dict
 fn hashmap_to_py_dict(py: Python, map: PreferredViewSelection) -> PyResult<Py<PyDict>> {
     let dict = PyDict::new_bound(py);
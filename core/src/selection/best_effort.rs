@@ -0,0 +1,285 @@
+//! Best-effort record reading for truncated or partially corrupt files
+
+use crate::dicom_files::gzipped_dicom_reader;
+use crate::extraction::tags::PIXEL_DATA_TAG;
+use crate::selection::record::MammogramRecord;
+use dicom::object::meta::FileMetaTable;
+use dicom::parser::dataset::read::DataSetReader;
+use dicom::parser::dataset::DataToken;
+use dicom::transfer_syntax::{TransferSyntaxIndex, TransferSyntaxRegistry};
+use dicom_core::header::DataElementHeader;
+use dicom_core::DataElement;
+use dicom_object::{FileDicomObject, InMemDicomObject, OpenFileOptions};
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+
+impl MammogramRecord {
+    /// Reads whatever metadata a DICOM file yields, collecting warnings
+    /// instead of failing
+    ///
+    /// When the header cannot be parsed in full (e.g. a file truncated
+    /// mid-transfer), the top-level elements read before the damage are kept
+    /// and extraction runs on those. Fields that could not be determined,
+    /// such as an absent ViewPosition, are reported as warnings alongside
+    /// the partial record.
+    ///
+    /// # Returns
+    ///
+    /// The record when one could be built, plus every warning raised. The
+    /// record is `None` only when not even the file meta could be read or
+    /// when the salvaged data is not a mammogram image.
+    pub fn from_file_best_effort(path: PathBuf) -> (Option<Self>, Vec<String>) {
+        Self::from_file_best_effort_with_modality_policy(path, false)
+    }
+
+    /// Best-effort variant of [`MammogramRecord::from_file_with_modality_policy`]
+    ///
+    /// See [`MammogramRecord::from_file_best_effort`].
+    pub fn from_file_best_effort_with_modality_policy(
+        path: PathBuf,
+        ignore_modality: bool,
+    ) -> (Option<Self>, Vec<String>) {
+        let mut warnings = Vec::new();
+        let header = match open_reader(&path) {
+            Ok(reader) => OpenFileOptions::new()
+                .read_until(PIXEL_DATA_TAG)
+                .from_reader(reader),
+            Err(error) => return (None, vec![format!("failed to read file: {error}")]),
+        };
+
+        let dcm = match header {
+            Ok(dcm) => dcm,
+            Err(error) => {
+                warnings.push(format!("DICOM header is incomplete: {error}"));
+                match open_reader(&path).map(|reader| salvage_header(reader, &mut warnings)) {
+                    Ok(Some(dcm)) => dcm,
+                    Ok(None) => return (None, warnings),
+                    Err(error) => {
+                        warnings.push(format!("failed to read file: {error}"));
+                        return (None, warnings);
+                    }
+                }
+            }
+        };
+
+        match Self::from_file_dicom_with_modality_policy(path, &dcm, ignore_modality) {
+            Ok(record) => {
+                warnings.extend(missing_field_warnings(&record));
+                (Some(record), warnings)
+            }
+            Err(error) => {
+                warnings.push(error.to_string());
+                (None, warnings)
+            }
+        }
+    }
+}
+
+/// Opens the file as a stream, decompressing `.gz` paths on the fly
+fn open_reader(path: &Path) -> std::io::Result<Box<dyn Read>> {
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gz"))
+    {
+        Ok(Box::new(gzipped_dicom_reader(path)?))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+/// Rebuilds a header from the top-level elements read before the first error
+///
+/// Reading stops at the damage or at PixelData, whichever comes first.
+/// Sequence contents are skipped, so values only available inside
+/// sequences (e.g. FrameLaterality) are lost when salvaging.
+fn salvage_header(
+    mut reader: impl Read,
+    warnings: &mut Vec<String>,
+) -> Option<FileDicomObject<InMemDicomObject>> {
+    let mut preamble = Vec::with_capacity(132);
+    if let Err(error) = (&mut reader).take(132).read_to_end(&mut preamble) {
+        warnings.push(format!("file cannot be read: {error}"));
+        return None;
+    }
+    let dataset_start = if preamble.len() >= 132 && &preamble[128..132] == b"DICM" {
+        128
+    } else {
+        0
+    };
+    let mut reader = Cursor::new(preamble.split_off(dataset_start)).chain(reader);
+    let meta = match FileMetaTable::from_reader(&mut reader) {
+        Ok(meta) => meta,
+        Err(error) => {
+            warnings.push(format!("file meta cannot be read: {error}"));
+            return None;
+        }
+    };
+    let Some(transfer_syntax) = TransferSyntaxRegistry.get(meta.transfer_syntax()) else {
+        warnings.push(format!(
+            "unsupported transfer syntax: {}",
+            meta.transfer_syntax()
+        ));
+        return None;
+    };
+    let dataset = match DataSetReader::new_with_ts(reader, transfer_syntax) {
+        Ok(dataset) => dataset,
+        Err(error) => {
+            warnings.push(format!("dataset cannot be read: {error}"));
+            return None;
+        }
+    };
+
+    let mut elements = Vec::new();
+    let mut header: Option<DataElementHeader> = None;
+    let mut sequence_depth = 0_usize;
+    for token in dataset {
+        let token = match token {
+            Ok(token) => token,
+            Err(error) => {
+                warnings.push(format!(
+                    "kept {} top-level elements read before the damage: {error}",
+                    elements.len()
+                ));
+                break;
+            }
+        };
+        match token {
+            DataToken::ElementHeader(element) if sequence_depth == 0 => {
+                if element.tag == PIXEL_DATA_TAG {
+                    break;
+                }
+                header = Some(element);
+            }
+            DataToken::PrimitiveValue(value) if sequence_depth == 0 => {
+                if let Some(element) = header.take() {
+                    elements.push(DataElement::new(element.tag, element.vr, value));
+                }
+            }
+            DataToken::PixelSequenceStart if sequence_depth == 0 => break,
+            DataToken::SequenceStart { .. } | DataToken::PixelSequenceStart => {
+                sequence_depth += 1;
+            }
+            DataToken::SequenceEnd => sequence_depth = sequence_depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    Some(InMemDicomObject::from_element_iter(elements).with_exact_meta(meta))
+}
+
+/// Describes identifying fields the record could not determine
+fn missing_field_warnings(record: &MammogramRecord) -> Vec<String> {
+    let metadata = &record.metadata;
+    let mut warnings = Vec::new();
+    if metadata.laterality.is_unknown_or_none() {
+        warnings.push("laterality could not be determined".to_string());
+    }
+    if metadata.view_position.is_unknown() {
+        warnings.push("view position could not be determined".to_string());
+    }
+    let missing_tags = [
+        ("StudyInstanceUID", record.study_instance_uid.is_none()),
+        ("SOPInstanceUID", record.sop_instance_uid.is_none()),
+        ("Rows", record.rows.is_none()),
+        ("Columns", record.columns.is_none()),
+    ];
+    for (tag, missing) in missing_tags {
+        if missing {
+            warnings.push(format!("{tag} is missing"));
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MammogramFixture;
+    use crate::types::{Laterality, MammogramType, ViewPosition};
+    use dicom_core::VR;
+    use dicom_dictionary_std::tags;
+    use tempfile::tempdir;
+
+    fn mammogram(view_position: Option<&str>) -> FileDicomObject<InMemDicomObject> {
+        let fixture =
+            MammogramFixture::new("1.2.826.0.60").with(tags::IMAGE_LATERALITY, VR::CS, "R");
+        match view_position {
+            Some(view_position) => fixture.with(tags::VIEW_POSITION, VR::CS, view_position),
+            None => fixture,
+        }
+        .into_file_object()
+    }
+
+    #[test]
+    fn missing_view_position_still_yields_partial_record() {
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("no_view.dcm");
+        mammogram(None).write_to_file(&path).unwrap();
+
+        let (record, warnings) = MammogramRecord::from_file_best_effort(path);
+
+        let record = record.unwrap();
+        assert_eq!(record.metadata.mammogram_type, MammogramType::Ffdm);
+        assert_eq!(record.metadata.laterality, Laterality::Right);
+        assert_eq!(record.metadata.view_position, ViewPosition::Unknown);
+        assert_eq!(warnings, ["view position could not be determined"]);
+    }
+
+    #[test]
+    fn truncated_header_keeps_elements_before_the_damage() {
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("truncated.dcm");
+        mammogram(Some("CC")).write_to_file(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        // Cut inside the value of the last element, Columns
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+
+        assert!(MammogramRecord::from_file(path.clone()).is_err());
+        let (record, warnings) = MammogramRecord::from_file_best_effort(path);
+
+        let record = record.unwrap();
+        assert_eq!(record.sop_instance_uid.as_deref(), Some("1.2.826.0.60"));
+        assert_eq!(record.metadata.laterality, Laterality::Right);
+        assert_eq!(record.metadata.view_position, ViewPosition::Cc);
+        assert_eq!(record.rows, Some(32));
+        assert_eq!(record.columns, None);
+        assert!(warnings[0].starts_with("DICOM header is incomplete"));
+        assert_eq!(warnings.last().unwrap(), "Columns is missing");
+    }
+
+    #[test]
+    fn truncated_gzipped_header_is_salvaged_from_the_stream() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("truncated.dcm");
+        mammogram(Some("CC")).write_to_file(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let gz_path = directory.path().join("truncated.dcm.gz");
+        let mut encoder = GzEncoder::new(File::create(&gz_path).unwrap(), Compression::default());
+        encoder.write_all(&bytes[..bytes.len() - 1]).unwrap();
+        encoder.finish().unwrap();
+
+        let (record, warnings) = MammogramRecord::from_file_best_effort(gz_path);
+
+        let record = record.unwrap();
+        assert_eq!(record.metadata.view_position, ViewPosition::Cc);
+        assert_eq!(record.columns, None);
+        assert!(warnings[0].starts_with("DICOM header is incomplete"));
+    }
+
+    #[test]
+    fn unreadable_file_yields_no_record() {
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("garbage.dcm");
+        std::fs::write(&path, b"not a dicom file").unwrap();
+
+        let (record, warnings) = MammogramRecord::from_file_best_effort(path);
+
+        assert!(record.is_none());
+        assert!(!warnings.is_empty());
+    }
+}
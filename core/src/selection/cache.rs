@@ -0,0 +1,370 @@
+//! On-disk cache of parsed records for repeated runs over immutable archives
+
+use crate::api::MammogramMetadata;
+use crate::error::{MammocatError, Result};
+use crate::selection::record::MammogramRecord;
+use crate::types::SopClassKind;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Bump whenever [`CachedRecord`] or [`MammogramMetadata`] gains a field, so
+/// entries written without it are re-parsed instead of read as defaults.
+const CACHE_FORMAT_VERSION: u32 = 2;
+
+/// Cache of parsed [`MammogramRecord`]s keyed by file path, mtime, and size
+///
+/// Lookups only stat the file, so a hit never opens or parses it. Any change
+/// to the modification time or size invalidates the entry. Entries are kept
+/// in a sorted map so saved cache files are deterministic.
+///
+/// # Example
+///
+/// ```no_run
+/// use mammocat_core::{MammogramRecord, RecordCache};
+/// use std::path::PathBuf;
+///
+/// let mut cache = RecordCache::load("mammocat-cache.json").unwrap();
+/// let record = MammogramRecord::from_file_cached(PathBuf::from("image.dcm"), &mut cache).unwrap();
+/// cache.save().unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct RecordCache {
+    path: Option<PathBuf>,
+    entries: BTreeMap<String, CachedRecord>,
+    hits: usize,
+    misses: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct CacheFile {
+    version: u32,
+    entries: BTreeMap<String, CachedRecord>,
+}
+
+/// Version header read before the entries, whose layout depends on it
+#[derive(Debug, serde::Deserialize)]
+struct CacheVersion {
+    version: u32,
+}
+
+/// Record fields persisted in the cache; `file_path` comes from the lookup
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct CachedRecord {
+    metadata: MammogramMetadata,
+    study_instance_uid: Option<String>,
+    series_instance_uid: Option<String>,
+    sop_instance_uid: Option<String>,
+    rows: Option<u16>,
+    columns: Option<u16>,
+    #[serde(default)]
+    bits_stored: Option<u16>,
+    transfer_syntax_uid: Option<String>,
+    is_lossy_compressed: bool,
+    /// ISO 8601 date, since chrono is built without serde support
+    study_date: Option<String>,
+    #[serde(default)]
+    patient_id: Option<String>,
+    #[serde(default)]
+    patient_name: Option<String>,
+    #[serde(default)]
+    sop_class: SopClassKind,
+}
+
+impl CachedRecord {
+    fn from_record(record: &MammogramRecord) -> Self {
+        Self {
+            metadata: record.metadata.clone(),
+            study_instance_uid: record.study_instance_uid.clone(),
+            series_instance_uid: record.series_instance_uid.clone(),
+            sop_instance_uid: record.sop_instance_uid.clone(),
+            rows: record.rows,
+            columns: record.columns,
+            bits_stored: record.bits_stored,
+            transfer_syntax_uid: record.transfer_syntax_uid.clone(),
+            is_lossy_compressed: record.is_lossy_compressed,
+            study_date: record.study_date.map(|date| date.to_string()),
+            patient_id: record.patient_id.clone(),
+            patient_name: record.patient_name.clone(),
+            sop_class: record.sop_class,
+        }
+    }
+
+    fn into_record(self, file_path: PathBuf) -> MammogramRecord {
+        MammogramRecord {
+            file_path,
+            metadata: self.metadata,
+            study_instance_uid: self.study_instance_uid,
+            series_instance_uid: self.series_instance_uid,
+            sop_instance_uid: self.sop_instance_uid,
+            rows: self.rows,
+            columns: self.columns,
+            bits_stored: self.bits_stored,
+            transfer_syntax_uid: self.transfer_syntax_uid,
+            is_lossy_compressed: self.is_lossy_compressed,
+            study_date: self.study_date.and_then(|date| date.parse().ok()),
+            patient_id: self.patient_id,
+            patient_name: self.patient_name,
+            sop_class: self.sop_class,
+        }
+    }
+}
+
+impl RecordCache {
+    /// Creates an empty in-memory cache with no backing file
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a cache from `path`, starting empty if the file does not exist
+    ///
+    /// Caches written by an older format version are also discarded, so every
+    /// file is re-parsed once. The path is remembered for [`RecordCache::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed, or
+    /// was written by a newer cache format version.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match fs::read_to_string(&path) {
+            Ok(contents) => {
+                let invalid = |e: serde_json::Error| {
+                    MammocatError::InvalidValue(format!(
+                        "invalid record cache {}: {e}",
+                        path.display()
+                    ))
+                };
+                let version = serde_json::from_str::<CacheVersion>(&contents)
+                    .map_err(invalid)?
+                    .version;
+                if version > CACHE_FORMAT_VERSION {
+                    return Err(MammocatError::InvalidValue(format!(
+                        "unsupported record cache version {} in {}",
+                        version,
+                        path.display()
+                    )));
+                }
+                if version < CACHE_FORMAT_VERSION {
+                    BTreeMap::new()
+                } else {
+                    serde_json::from_str::<CacheFile>(&contents)
+                        .map_err(invalid)?
+                        .entries
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            path: Some(path),
+            entries,
+            hits: 0,
+            misses: 0,
+        })
+    }
+
+    /// Writes the cache back to the file it was loaded from
+    ///
+    /// Does nothing for caches created with [`RecordCache::new`].
+    pub fn save(&self) -> Result<()> {
+        match &self.path {
+            Some(path) => self.save_to(path),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes the cache to `path` as JSON
+    pub fn save_to(&self, path: impl AsRef<Path>) -> Result<()> {
+        let file = CacheFile {
+            version: CACHE_FORMAT_VERSION,
+            entries: self.entries.clone(),
+        };
+        let contents = serde_json::to_string(&file)
+            .map_err(|e| MammocatError::InvalidValue(format!("cannot serialize cache: {e}")))?;
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Number of cached records
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache holds no records
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of lookups served from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of lookups that required parsing the file
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+impl MammogramRecord {
+    /// Creates a record from a DICOM file path, reusing `cache` when possible
+    ///
+    /// On a hit the file is only stat-ed, never opened. On a miss the file is
+    /// parsed with [`MammogramRecord::from_file`] and the result is stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be stat-ed or parsed.
+    pub fn from_file_cached(path: PathBuf, cache: &mut RecordCache) -> Result<Self> {
+        let key = cache_key(&path)?;
+        if let Some(entry) = cache.entries.get(&key) {
+            cache.hits += 1;
+            return Ok(entry.clone().into_record(path));
+        }
+
+        cache.misses += 1;
+        let record = Self::from_file(path)?;
+        cache
+            .entries
+            .insert(key, CachedRecord::from_record(&record));
+        Ok(record)
+    }
+}
+
+/// Builds the `path|mtime|size` cache key for a file
+fn cache_key(path: &Path) -> Result<String> {
+    let file_metadata = fs::metadata(path)?;
+    let modified_nanos = file_metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    let absolute_path = fs::canonicalize(path)?;
+    Ok(format!(
+        "{}|{}|{}",
+        absolute_path.display(),
+        modified_nanos,
+        file_metadata.len()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MammogramFixture;
+    use dicom_core::VR;
+    use dicom_dictionary_std::tags;
+    use std::fs::File;
+    use tempfile::TempDir;
+
+    fn write_test_dicom(path: &Path) {
+        MammogramFixture::new("1.2.826.0.77")
+            .with(tags::STUDY_DATE, VR::DA, "20240131")
+            .view("L", "MLO")
+            .write(path);
+    }
+
+    /// Overwrites a file with junk while keeping its size and mtime
+    fn corrupt_preserving_stat(path: &Path) {
+        let file_metadata = fs::metadata(path).unwrap();
+        let modified = file_metadata.modified().unwrap();
+        fs::write(path, vec![0_u8; file_metadata.len() as usize]).unwrap();
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[test]
+    fn second_lookup_hits_cache_without_reparsing() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("lmlo.dcm");
+        write_test_dicom(&path);
+        let mut cache = RecordCache::new();
+
+        let first = MammogramRecord::from_file_cached(path.clone(), &mut cache).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (0, 1));
+
+        // A re-parse would fail on this content, so success proves a cache hit
+        corrupt_preserving_stat(&path);
+        let second = MammogramRecord::from_file_cached(path.clone(), &mut cache).unwrap();
+
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+        assert_eq!(second.file_path, path);
+        assert_eq!(second.metadata, first.metadata);
+        assert_eq!(second.sop_instance_uid, first.sop_instance_uid);
+        assert_eq!(second.study_date, first.study_date);
+        assert!(MammogramRecord::from_file(path).is_err());
+    }
+
+    #[test]
+    fn cache_round_trips_through_disk() {
+        let temp_dir = TempDir::new().unwrap();
+        let dicom_path = temp_dir.path().join("lmlo.dcm");
+        let cache_path = temp_dir.path().join("cache.json");
+        write_test_dicom(&dicom_path);
+
+        let mut cache = RecordCache::load(&cache_path).unwrap();
+        assert!(cache.is_empty());
+        let first = MammogramRecord::from_file_cached(dicom_path.clone(), &mut cache).unwrap();
+        cache.save().unwrap();
+
+        let mut reloaded = RecordCache::load(&cache_path).unwrap();
+        let second = MammogramRecord::from_file_cached(dicom_path, &mut reloaded).unwrap();
+
+        assert_eq!(reloaded.len(), 1);
+        assert_eq!(reloaded.hits(), 1);
+        assert_eq!(second.metadata, first.metadata);
+        assert_eq!(second.rows, first.rows);
+    }
+
+    #[test]
+    fn modified_file_invalidates_entry() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("lmlo.dcm");
+        write_test_dicom(&path);
+        let mut cache = RecordCache::new();
+        MammogramRecord::from_file_cached(path.clone(), &mut cache).unwrap();
+
+        let modified = fs::metadata(&path).unwrap().modified().unwrap();
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(modified - std::time::Duration::from_secs(60))
+            .unwrap();
+        MammogramRecord::from_file_cached(path, &mut cache).unwrap();
+
+        assert_eq!((cache.hits(), cache.misses()), (0, 2));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn load_rejects_unknown_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("cache.json");
+        fs::write(&cache_path, r#"{"version":99,"entries":{}}"#).unwrap();
+
+        assert!(RecordCache::load(&cache_path).is_err());
+    }
+
+    #[test]
+    fn load_discards_entries_from_older_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("cache.json");
+        fs::write(
+            &cache_path,
+            r#"{"version":1,"entries":{"stale.dcm":{"unparsed":true}}}"#,
+        )
+        .unwrap();
+
+        let cache = RecordCache::load(&cache_path).unwrap();
+
+        assert!(cache.is_empty());
+    }
+}
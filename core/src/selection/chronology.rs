@@ -0,0 +1,152 @@
+//! Current-versus-prior classification of studies by study date
+
+use crate::selection::record::MammogramRecord;
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+
+/// Role of a study relative to the other studies of the same patient
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub enum StudyRole {
+    /// Most recent study for the patient
+    Current,
+    /// Earlier study; 1 is the most recent prior, 2 the one before, etc.
+    Prior(usize),
+}
+
+impl StudyRole {
+    /// Returns whether this is a prior comparison study
+    pub fn is_prior(&self) -> bool {
+        matches!(self, StudyRole::Prior(_))
+    }
+}
+
+/// Classifies each study as the current study or a numbered prior
+///
+/// # Algorithm
+///
+/// 1. Group records by PatientID (records without one form a single group)
+/// 2. Within each group, collect distinct StudyInstanceUIDs with their
+///    earliest StudyDate; records without a StudyInstanceUID are skipped
+/// 3. Order studies newest first, with undated studies after dated ones and
+///    StudyInstanceUID as the tie-break
+/// 4. The first study is [`StudyRole::Current`]; the rest are
+///    [`StudyRole::Prior`] numbered from 1
+///
+/// # Arguments
+///
+/// * `records` - Records spanning one or more studies and patients
+///
+/// # Returns
+///
+/// `(StudyInstanceUID, role)` pairs grouped by patient, current study first
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::{classify_studies_chronologically, StudyRole};
+///
+/// # let records = vec![]; // Would normally load from files
+/// let roles = classify_studies_chronologically(&records);
+/// let priors: Vec<_> = roles.iter().filter(|(_, role)| role.is_prior()).collect();
+/// # assert!(priors.is_empty());
+/// ```
+pub fn classify_studies_chronologically(records: &[MammogramRecord]) -> Vec<(String, StudyRole)> {
+    let mut patients: BTreeMap<Option<&str>, BTreeMap<&str, Option<NaiveDate>>> = BTreeMap::new();
+    for record in records {
+        let Some(study_uid) = non_empty(&record.study_instance_uid) else {
+            continue;
+        };
+        let study_date = patients
+            .entry(non_empty(&record.patient_id))
+            .or_default()
+            .entry(study_uid)
+            .or_insert(record.study_date);
+        *study_date = match (*study_date, record.study_date) {
+            (Some(current), Some(date)) => Some(current.min(date)),
+            (current, date) => current.or(date),
+        };
+    }
+
+    let mut roles = Vec::new();
+    for studies in patients.into_values() {
+        let mut studies: Vec<(&str, Option<NaiveDate>)> = studies.into_iter().collect();
+        // `Option` orders None first, so reversing the date puts undated studies last
+        studies.sort_by(|(a_uid, a_date), (b_uid, b_date)| {
+            b_date.cmp(a_date).then_with(|| a_uid.cmp(b_uid))
+        });
+        roles.extend(studies.into_iter().enumerate().map(|(index, (uid, _))| {
+            let role = if index == 0 {
+                StudyRole::Current
+            } else {
+                StudyRole::Prior(index)
+            };
+            (uid.to_string(), role)
+        }));
+    }
+    roles
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{sample_metadata, sample_record};
+
+    fn make_record(patient_id: Option<&str>, study_uid: &str, study_date: &str) -> MammogramRecord {
+        MammogramRecord {
+            study_instance_uid: Some(study_uid.to_string()),
+            study_date: study_date.parse().ok(),
+            patient_id: patient_id.map(str::to_string),
+            ..sample_record(format!("{study_uid}.dcm"), sample_metadata())
+        }
+    }
+
+    #[test]
+    fn orders_two_studies_into_current_and_prior() {
+        let records = vec![
+            make_record(Some("P1"), "1.1", "2022-03-01"),
+            make_record(Some("P1"), "1.2", "2024-03-01"),
+            make_record(Some("P1"), "1.1", "2022-03-01"),
+        ];
+
+        let roles = classify_studies_chronologically(&records);
+
+        assert_eq!(
+            roles,
+            vec![
+                ("1.2".to_string(), StudyRole::Current),
+                ("1.1".to_string(), StudyRole::Prior(1)),
+            ]
+        );
+        assert!(roles[1].1.is_prior());
+    }
+
+    #[test]
+    fn classifies_each_patient_separately() {
+        let records = vec![
+            make_record(Some("P2"), "2.1", "2020-01-01"),
+            make_record(Some("P1"), "1.1", "2019-01-01"),
+            make_record(Some("P1"), "1.2", "2021-01-01"),
+            make_record(Some("P1"), "1.3", ""),
+        ];
+
+        let roles = classify_studies_chronologically(&records);
+
+        assert_eq!(
+            roles,
+            vec![
+                ("1.2".to_string(), StudyRole::Current),
+                ("1.1".to_string(), StudyRole::Prior(1)),
+                ("1.3".to_string(), StudyRole::Prior(2)),
+                ("2.1".to_string(), StudyRole::Current),
+            ]
+        );
+    }
+}
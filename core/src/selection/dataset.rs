@@ -0,0 +1,259 @@
+//! Chainable queries over a collection of mammogram records
+
+use crate::dicom_files::{collect_dicom_files, collect_dicom_files_recursively};
+use crate::error::Result;
+use crate::selection::process::read_records;
+use crate::selection::record::MammogramRecord;
+use crate::selection::views::{
+    apply_filters, get_preferred_views_with_order, refine_dbt_object_classification,
+    PreferredViewSelection,
+};
+use crate::types::{FilterConfig, Laterality, MammogramType, PreferenceOrder};
+use log::warn;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A collection of mammogram records with grouping, filtering, and selection helpers
+///
+/// Wraps `Vec<MammogramRecord>` so common queries can be chained instead of
+/// re-implemented by each caller. Each query returns a new dataset and leaves
+/// the original unchanged.
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::{FilterConfig, Laterality, MammogramDataset};
+///
+/// # let records = vec![]; // Would normally load from files
+/// let dataset = MammogramDataset::new(records);
+/// let left = dataset
+///     .filter(&FilterConfig::default().exclude_implants(true))
+///     .by_laterality(Laterality::Left);
+/// assert!(left.is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MammogramDataset {
+    records: Vec<MammogramRecord>,
+}
+
+impl MammogramDataset {
+    /// Creates a dataset from records
+    pub fn new(records: Vec<MammogramRecord>) -> Self {
+        Self { records }
+    }
+
+    /// Reads every DICOM candidate in a directory into a dataset
+    ///
+    /// Files that cannot be read as mammogram records are logged and skipped;
+    /// use [`crate::process_directory`] to receive the skip reasons instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read.
+    pub fn from_directory(dir: &Path, recursive: bool) -> Result<Self> {
+        let files = if recursive {
+            collect_dicom_files_recursively(dir)?
+        } else {
+            collect_dicom_files(dir)?
+        };
+        let (records, skipped) = read_records(files);
+        for (path, error) in skipped {
+            warn!("skipping {}: {}", path.display(), error);
+        }
+        Ok(Self::new(records))
+    }
+
+    /// Returns the records in this dataset
+    pub fn records(&self) -> &[MammogramRecord] {
+        &self.records
+    }
+
+    /// Consumes the dataset and returns its records
+    pub fn into_records(self) -> Vec<MammogramRecord> {
+        self.records
+    }
+
+    /// Returns the number of records
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether the dataset has no records
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Keeps the records that pass `config`
+    ///
+    /// Collection-context DBT refinement is applied first, as in
+    /// [`crate::get_preferred_views_filtered`], so DBT object-kind filters see
+    /// the same classification that selection does. The returned records
+    /// carry that refined classification.
+    pub fn filter(&self, config: &FilterConfig) -> Self {
+        let refined_records = refine_dbt_object_classification(&self.records);
+        Self::new(apply_filters(&refined_records, config))
+    }
+
+    /// Splits the dataset by StudyInstanceUID
+    ///
+    /// Records without a StudyInstanceUID are grouped under an empty key.
+    pub fn group_by_study(&self) -> HashMap<String, Self> {
+        let mut groups: HashMap<String, Self> = HashMap::new();
+        for record in &self.records {
+            let study_uid = record.study_instance_uid.clone().unwrap_or_default();
+            groups
+                .entry(study_uid)
+                .or_default()
+                .records
+                .push(record.clone());
+        }
+        groups
+    }
+
+    /// Keeps the records whose laterality is exactly `laterality`
+    pub fn by_laterality(&self, laterality: Laterality) -> Self {
+        self.records
+            .iter()
+            .filter(|record| record.metadata.laterality == laterality)
+            .cloned()
+            .collect()
+    }
+
+    /// Selects preferred views with [`get_preferred_views_with_order`]
+    pub fn select_preferred(&self, order: PreferenceOrder) -> PreferredViewSelection {
+        get_preferred_views_with_order(&self.records, order)
+    }
+
+    /// Counts records per mammogram type
+    pub fn counts_by_type(&self) -> HashMap<MammogramType, usize> {
+        let mut counts = HashMap::new();
+        for record in &self.records {
+            *counts.entry(record.metadata.mammogram_type).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+impl From<Vec<MammogramRecord>> for MammogramDataset {
+    fn from(records: Vec<MammogramRecord>) -> Self {
+        Self::new(records)
+    }
+}
+
+impl FromIterator<MammogramRecord> for MammogramDataset {
+    fn from_iter<I: IntoIterator<Item = MammogramRecord>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::MammogramMetadata;
+    use crate::test_support::{sample_metadata, sample_record};
+    use crate::types::{MammogramView, ViewPosition};
+
+    fn record(
+        study_uid: Option<&str>,
+        laterality: Laterality,
+        view_position: ViewPosition,
+        mammogram_type: MammogramType,
+    ) -> MammogramRecord {
+        let view = MammogramView::new(laterality, view_position);
+        let sop_uid = format!(
+            "{}.{}.{}",
+            study_uid.unwrap_or("missing"),
+            view,
+            mammogram_type
+        );
+        MammogramRecord {
+            study_instance_uid: study_uid.map(str::to_string),
+            sop_instance_uid: Some(sop_uid.clone()),
+            ..sample_record(
+                format!("{sop_uid}.dcm"),
+                MammogramMetadata {
+                    mammogram_type,
+                    laterality,
+                    view_position,
+                    ..sample_metadata()
+                },
+            )
+        }
+    }
+
+    fn mixed_dataset() -> MammogramDataset {
+        MammogramDataset::new(vec![
+            record(
+                Some("1.2.3.1"),
+                Laterality::Left,
+                ViewPosition::Cc,
+                MammogramType::Ffdm,
+            ),
+            record(
+                Some("1.2.3.1"),
+                Laterality::Left,
+                ViewPosition::Cc,
+                MammogramType::Synth,
+            ),
+            record(
+                Some("1.2.3.1"),
+                Laterality::Right,
+                ViewPosition::Mlo,
+                MammogramType::Ffdm,
+            ),
+            record(
+                Some("1.2.3.2"),
+                Laterality::Right,
+                ViewPosition::Cc,
+                MammogramType::Tomo,
+            ),
+            record(
+                None,
+                Laterality::Left,
+                ViewPosition::Mlo,
+                MammogramType::Sfm,
+            ),
+        ])
+    }
+
+    #[test]
+    fn group_by_study_splits_records_by_study_uid() {
+        let groups = mixed_dataset().group_by_study();
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups["1.2.3.1"].len(), 3);
+        assert_eq!(groups["1.2.3.2"].len(), 1);
+        assert_eq!(groups[""].len(), 1);
+        assert_eq!(groups["1.2.3.1"].counts_by_type()[&MammogramType::Ffdm], 2);
+    }
+
+    #[test]
+    fn counts_by_type_counts_every_record() {
+        let dataset = mixed_dataset();
+        let counts = dataset.counts_by_type();
+
+        assert_eq!(counts[&MammogramType::Ffdm], 2);
+        assert_eq!(counts[&MammogramType::Synth], 1);
+        assert_eq!(counts[&MammogramType::Tomo], 1);
+        assert_eq!(counts[&MammogramType::Sfm], 1);
+        assert!(!counts.contains_key(&MammogramType::Cem));
+        assert_eq!(counts.values().sum::<usize>(), dataset.len());
+    }
+
+    #[test]
+    fn queries_chain_into_selection() {
+        let dataset = mixed_dataset();
+        let left = dataset
+            .filter(&FilterConfig::default().with_allowed_types([MammogramType::Synth].into()))
+            .by_laterality(Laterality::Left);
+
+        assert_eq!(left.len(), 1);
+        let selection = left.select_preferred(PreferenceOrder::Default);
+        let lcc = MammogramView::new(Laterality::Left, ViewPosition::Cc);
+        assert_eq!(
+            selection[&lcc].as_ref().unwrap().metadata.mammogram_type,
+            MammogramType::Synth
+        );
+        assert_eq!(dataset.len(), 5);
+    }
+}
@@ -0,0 +1,206 @@
+//! Duplicate SOP instance handling across record sets
+
+use crate::selection::record::{MammogramRecord, RecordDedupKey};
+use std::collections::{HashMap, HashSet};
+
+/// Merges record sets and removes duplicate SOP instances
+///
+/// Useful when the same study has been copied into multiple folders. Records
+/// sharing a [`MammogramRecord::dedup_key`] are collapsed into one, keeping
+/// the record with the most populated metadata (the earliest record wins
+/// ties). Records without a SOPInstanceUID only match when they share a file
+/// path, e.g. the same file listed in two sets.
+///
+/// # Arguments
+///
+/// * `sets` - Record sets to merge, e.g. one per source folder
+///
+/// # Returns
+///
+/// Deduplicated union in first-seen order
+pub fn merge_records_dedup(sets: &[Vec<MammogramRecord>]) -> Vec<MammogramRecord> {
+    let mut merged: Vec<MammogramRecord> = Vec::new();
+    let mut index_by_key: HashMap<RecordDedupKey, usize> = HashMap::new();
+
+    for record in sets.iter().flatten() {
+        let key = record.dedup_key();
+        match index_by_key.get(&key) {
+            Some(&index) => {
+                if populated_field_count(record) > populated_field_count(&merged[index]) {
+                    merged[index] = record.clone();
+                }
+            }
+            None => {
+                index_by_key.insert(key, merged.len());
+                merged.push(record.clone());
+            }
+        }
+    }
+
+    merged
+}
+
+/// Removes repeated SOP instances, keeping the first occurrence
+///
+/// Records are matched on the same [`MammogramRecord::dedup_key`] as
+/// [`merge_records_dedup`], but no attempt is made to pick the most complete
+/// copy, and records without a SOPInstanceUID (keyed by file path) are
+/// always retained.
+///
+/// # Arguments
+///
+/// * `records` - Records that may list the same instance more than once
+///
+/// # Returns
+///
+/// Records in input order with later duplicates dropped
+pub fn dedupe_by_sop_uid(records: &[MammogramRecord]) -> Vec<MammogramRecord> {
+    let mut seen: HashSet<RecordDedupKey> = HashSet::new();
+    records
+        .iter()
+        .filter(|record| match record.dedup_key() {
+            key @ RecordDedupKey::SopInstanceUid(_) => seen.insert(key),
+            RecordDedupKey::FilePath(_) => true,
+        })
+        .cloned()
+        .collect()
+}
+
+/// Counts known identifiers, dimensions, and metadata values on a record.
+fn populated_field_count(record: &MammogramRecord) -> usize {
+    let metadata = &record.metadata;
+    let known_flags = [
+        !metadata.mammogram_type.is_unknown(),
+        !metadata.laterality.is_unknown(),
+        !metadata.view_position.is_unknown(),
+        metadata.pixel_spacing.is_some(),
+        record.rows.is_some(),
+        record.columns.is_some(),
+    ];
+    let optional_values = [
+        &metadata.manufacturer,
+        &metadata.model,
+        &metadata.concatenation_uid,
+        &metadata.sop_instance_uid_of_concatenation_source,
+        &metadata.modality,
+        &metadata.character_set,
+        &metadata.transfer_syntax_uid,
+        &metadata.transfer_syntax_name,
+        &metadata.compression_type,
+        &record.study_instance_uid,
+        &record.series_instance_uid,
+        &record.transfer_syntax_uid,
+    ];
+
+    known_flags.iter().filter(|known| **known).count()
+        + optional_values
+            .iter()
+            .filter(|value| value.as_deref().is_some_and(|value| !value.is_empty()))
+            .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{sample_metadata, sample_record};
+    use std::path::PathBuf;
+
+    fn make_record(folder: &str, sop_uid: Option<&str>) -> MammogramRecord {
+        MammogramRecord {
+            sop_instance_uid: sop_uid.map(str::to_string),
+            ..sample_record(
+                format!("{folder}/{}.dcm", sop_uid.unwrap_or("unknown")),
+                sample_metadata(),
+            )
+        }
+    }
+
+    #[test]
+    fn dedupe_by_sop_uid_keeps_first_occurrence() {
+        let records = vec![
+            make_record("a", Some("1.1")),
+            make_record("b", Some("1.1")),
+            make_record("a", Some("1.2")),
+        ];
+
+        let deduped = dedupe_by_sop_uid(&records);
+
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].file_path, PathBuf::from("a/1.1.dcm"));
+        assert_eq!(deduped[1].sop_instance_uid.as_deref(), Some("1.2"));
+    }
+
+    #[test]
+    fn dedupe_by_sop_uid_retains_records_without_uid() {
+        let records = vec![make_record("a", None), make_record("a", None)];
+
+        assert_eq!(dedupe_by_sop_uid(&records).len(), 2);
+    }
+
+    #[test]
+    fn dedupe_and_merge_share_record_identity() {
+        let records = vec![
+            make_record("a", Some("1.1")),
+            make_record("b", Some(" 1.1 ")),
+            make_record("c", Some("  ")),
+        ];
+
+        let deduped = dedupe_by_sop_uid(&records);
+        let merged = merge_records_dedup(std::slice::from_ref(&records));
+
+        assert_eq!(deduped.len(), 2);
+        let keys = |records: &[MammogramRecord]| {
+            records
+                .iter()
+                .map(MammogramRecord::dedup_key)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(keys(&deduped), keys(&merged));
+    }
+
+    #[test]
+    fn merges_overlapping_sets_into_deduplicated_union() {
+        let first = vec![make_record("a", Some("1.1")), make_record("a", Some("1.2"))];
+        let second = vec![make_record("b", Some("1.2")), make_record("b", Some("1.3"))];
+
+        let merged = merge_records_dedup(&[first, second]);
+
+        let sop_uids: Vec<_> = merged
+            .iter()
+            .map(|record| record.sop_instance_uid.as_deref().unwrap())
+            .collect();
+        assert_eq!(sop_uids, vec!["1.1", "1.2", "1.3"]);
+        assert_eq!(merged[1].file_path, PathBuf::from("a/1.2.dcm"));
+    }
+
+    #[test]
+    fn duplicate_with_more_metadata_replaces_sparser_record() {
+        let sparse = make_record("a", Some("1.1"));
+        let mut rich = make_record("b", Some("1.1"));
+        rich.metadata.manufacturer = Some("Vendor".to_string());
+        rich.series_instance_uid = Some("1.2.3.4".to_string());
+
+        let merged = merge_records_dedup(&[vec![sparse], vec![rich]]);
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].file_path, PathBuf::from("b/1.1.dcm"));
+    }
+
+    #[test]
+    fn records_without_sop_uid_are_kept() {
+        let first = vec![make_record("a", None)];
+        let second = vec![make_record("b", None), make_record("b", Some(" "))];
+
+        let merged = merge_records_dedup(&[first, second]);
+
+        assert_eq!(merged.len(), 3);
+    }
+
+    #[test]
+    fn records_without_sop_uid_dedupe_by_path() {
+        let merged =
+            merge_records_dedup(&[vec![make_record("a", None)], vec![make_record("a", None)]]);
+
+        assert_eq!(merged.len(), 1);
+    }
+}
@@ -3,20 +3,40 @@
 //! Implements mammogram record comparison and view selection algorithms
 //! matching the Python dicom-utils behavior.
 
+mod best_effort;
+#[cfg(feature = "json")]
+mod cache;
+mod chronology;
+mod dataset;
+mod dedup;
+mod process;
 mod record;
+mod snapshot;
 mod views;
 
-pub use record::MammogramRecord;
+#[cfg(feature = "json")]
+pub use cache::RecordCache;
+pub use chronology::{classify_studies_chronologically, StudyRole};
+pub use dataset::MammogramDataset;
+pub use dedup::{dedupe_by_sop_uid, merge_records_dedup};
+pub(crate) use process::read_records;
+pub use process::{iter_records, process_directory, ProcessResult};
 #[cfg(test)]
 pub(crate) use record::LOSSY_TRANSFER_SYNTAX_UIDS;
+pub use record::{extract_and_record, MammogramRecord, RecordDedupKey, SelectionReason};
 pub(crate) use record::{lossy_compression_source, LossyCompressionSource};
+pub use snapshot::{SelectionSnapshot, ViewSnapshot};
 pub(crate) use views::get_preferred_views_filtered_refined_with_study_mode_and_warnings;
 pub use views::{
-    get_preferred_views, get_preferred_views_filtered,
+    by_laterality, explain_missing, explain_missing_with_order, get_all_views, get_preferred_views,
+    get_preferred_views_explained, get_preferred_views_filtered,
     get_preferred_views_filtered_with_study_mode,
-    get_preferred_views_filtered_with_study_mode_and_warnings, get_preferred_views_with_order,
-    get_preferred_views_with_order_and_warnings, refine_dbt_object_classification,
-    refine_dbt_object_classification_with_diagnostics, DbtRefinementDiagnostic,
-    DbtRefinementReason, PreferredViewSelection, PreferredViewSelectionWithWarnings,
+    get_preferred_views_filtered_with_study_mode_and_warnings, get_preferred_views_normalized,
+    get_preferred_views_ordered, get_preferred_views_ref, get_preferred_views_with_order,
+    get_preferred_views_with_order_and_warnings, get_ranked_views, has_both_standard_views,
+    missing_views, ordered_selection, refine_dbt_object_classification,
+    refine_dbt_object_classification_with_diagnostics, reselect_excluding, study_completeness,
+    DbtRefinementDiagnostic, DbtRefinementReason, MissingReason, OrderedViewSelection,
+    PreferredViewSelection, PreferredViewSelectionExplained, PreferredViewSelectionWithWarnings,
     SelectionWarning, StudySelectionMode,
 };
@@ -0,0 +1,295 @@
+//! Directory-level pipeline: discovery, record extraction, and selection
+
+use crate::dicom_files::{collect_dicom_files, collect_dicom_files_recursively, walk_dicom_files};
+use crate::error::Result;
+use crate::selection::record::MammogramRecord;
+use crate::selection::views::{
+    get_preferred_views_filtered_with_study_mode_and_warnings, PreferredViewSelection,
+    SelectionWarning, StudySelectionMode,
+};
+use crate::types::{FilterConfig, PreferenceOrder};
+use std::path::{Path, PathBuf};
+
+/// Outcome of [`process_directory`]
+#[derive(Debug, Clone)]
+pub struct ProcessResult {
+    /// Preferred record for each standard view
+    pub selections: PreferredViewSelection,
+    /// Number of files successfully read as mammogram records
+    pub records_processed: usize,
+    /// Files that could not be read as records, with the reason
+    pub skipped: Vec<(PathBuf, String)>,
+    /// Non-fatal warnings raised during selection
+    pub warnings: Vec<SelectionWarning>,
+}
+
+/// Scans a directory, reads every DICOM candidate, and selects preferred views
+///
+/// Files that fail to parse, and non-image objects such as CAD SR or
+/// presentation states (even when labelled `MG`), are reported in
+/// [`ProcessResult::skipped`] instead of aborting the run. When
+/// records span several studies, the most complete study is selected and a
+/// warning is recorded.
+///
+/// # Arguments
+///
+/// * `dir` - Directory to scan
+/// * `filter` - Filter configuration applied before selection
+/// * `order` - The preference ordering strategy to use
+/// * `recursive` - Whether to descend into subdirectories
+///
+/// # Returns
+///
+/// Selections plus per-file skip reasons
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read.
+pub fn process_directory(
+    dir: &Path,
+    filter: &FilterConfig,
+    order: PreferenceOrder,
+    recursive: bool,
+) -> Result<ProcessResult> {
+    let files = if recursive {
+        collect_dicom_files_recursively(dir)?
+    } else {
+        collect_dicom_files(dir)?
+    };
+    let (records, skipped) = read_records(files);
+    let (selections, warnings) = get_preferred_views_filtered_with_study_mode_and_warnings(
+        &records,
+        filter,
+        order,
+        StudySelectionMode::MostComplete,
+    )?;
+
+    Ok(ProcessResult {
+        selections,
+        records_processed: records.len(),
+        skipped,
+        warnings,
+    })
+}
+
+/// Lazily reads a mammogram record for each DICOM candidate in a directory
+///
+/// Unlike [`process_directory`], nothing is collected up front: the
+/// directory is walked and each file is parsed only when the iterator is
+/// advanced, so memory use stays flat for very large directories. Records
+/// are yielded in directory-listing order, not sorted by path.
+///
+/// # Arguments
+///
+/// * `dir` - Directory to scan
+/// * `recursive` - Whether to descend into subdirectories
+///
+/// # Returns
+///
+/// An iterator yielding one result per DICOM candidate. Files that fail to
+/// parse, non-image SOP classes such as CAD SR, and directories that cannot
+/// be read are yielded as errors without ending the iteration.
+pub fn iter_records(dir: &Path, recursive: bool) -> impl Iterator<Item = Result<MammogramRecord>> {
+    walk_dicom_files(dir, recursive).map(|file_path| {
+        file_path
+            .map_err(Into::into)
+            .and_then(|file_path| MammogramRecord::from_image_file(file_path, false))
+    })
+}
+
+/// Reads records from files, collecting the paths that failed with their error.
+///
+/// Non-image SOP classes are skipped with [`crate::MammocatError::NonImageSopClass`].
+pub(crate) fn read_records(
+    files: impl IntoIterator<Item = PathBuf>,
+) -> (Vec<MammogramRecord>, Vec<(PathBuf, String)>) {
+    let mut records = Vec::new();
+    let mut skipped = Vec::new();
+    for file_path in files {
+        match MammogramRecord::from_image_file(file_path.clone(), false) {
+            Ok(record) => records.push(record),
+            Err(error) => skipped.push((file_path, error.to_string())),
+        }
+    }
+    (records, skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MammogramFixture;
+    use crate::types::{Laterality, MammogramView, ViewPosition};
+    use tempfile::tempdir;
+
+    #[test]
+    fn process_directory_selects_valid_files_and_reports_skipped() {
+        let directory = tempdir().unwrap();
+        MammogramFixture::new("1.2.3.1")
+            .view("L", "CC")
+            .write(&directory.path().join("lcc.dcm"));
+        MammogramFixture::new("1.2.3.2")
+            .view("R", "MLO")
+            .write(&directory.path().join("rmlo.dcm"));
+        let broken = directory.path().join("broken.dcm");
+        std::fs::write(&broken, b"not a DICOM file").unwrap();
+        let nested = directory.path().join("series");
+        std::fs::create_dir(&nested).unwrap();
+        MammogramFixture::new("1.2.3.3")
+            .view("L", "MLO")
+            .write(&nested.join("lmlo.dcm"));
+
+        let result = process_directory(
+            directory.path(),
+            &FilterConfig::default(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(result.records_processed, 2);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].0, broken);
+        assert!(!result.skipped[0].1.is_empty());
+        let lcc = MammogramView::new(Laterality::Left, ViewPosition::Cc);
+        assert!(result.selections[&lcc].is_some());
+        let lmlo = MammogramView::new(Laterality::Left, ViewPosition::Mlo);
+        assert!(result.selections[&lmlo].is_none());
+
+        let recursive = process_directory(
+            directory.path(),
+            &FilterConfig::default(),
+            PreferenceOrder::Default,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(recursive.records_processed, 3);
+        assert!(recursive.selections[&lmlo].is_some());
+    }
+
+    #[test]
+    fn process_directory_skips_non_image_objects() {
+        use dicom_core::VR;
+        use dicom_dictionary_std::{tags, uids};
+
+        let directory = tempdir().unwrap();
+        MammogramFixture::new("1.2.3.1")
+            .view("L", "CC")
+            .write(&directory.path().join("lcc.dcm"));
+        let cad = directory.path().join("cad.dcm");
+        MammogramFixture::new("1.2.3.9")
+            .with(tags::SOP_CLASS_UID, VR::UI, uids::MAMMOGRAPHY_CADSR_STORAGE)
+            .view("L", "CC")
+            .write(&cad);
+
+        let result = process_directory(
+            directory.path(),
+            &FilterConfig::default(),
+            PreferenceOrder::Default,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(result.records_processed, 1);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].0, cad);
+        let lcc = MammogramView::new(Laterality::Left, ViewPosition::Cc);
+        assert_eq!(
+            result.selections[&lcc]
+                .as_ref()
+                .and_then(|record| record.sop_instance_uid.as_deref()),
+            Some("1.2.3.1")
+        );
+    }
+
+    #[test]
+    fn process_directory_errors_for_missing_directory() {
+        let directory = tempdir().unwrap();
+
+        let result = process_directory(
+            &directory.path().join("missing"),
+            &FilterConfig::default(),
+            PreferenceOrder::Default,
+            false,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn iter_records_matches_eager_read() {
+        let directory = tempdir().unwrap();
+        let nested = directory.path().join("series");
+        std::fs::create_dir(&nested).unwrap();
+        MammogramFixture::new("1.2.3.1")
+            .view("L", "CC")
+            .write(&directory.path().join("lcc.dcm"));
+        MammogramFixture::new("1.2.3.2")
+            .view("R", "CC")
+            .write(&directory.path().join("rcc.dcm"));
+        MammogramFixture::new("1.2.3.3")
+            .view("L", "MLO")
+            .write(&nested.join("lmlo.dcm"));
+        std::fs::write(directory.path().join("broken.dcm"), b"not dicom").unwrap();
+
+        for recursive in [false, true] {
+            let files = if recursive {
+                collect_dicom_files_recursively(directory.path()).unwrap()
+            } else {
+                collect_dicom_files(directory.path()).unwrap()
+            };
+            let (eager, skipped) = read_records(files);
+
+            let mut lazy_uids = Vec::new();
+            let mut lazy_errors = 0;
+            for record in iter_records(directory.path(), recursive) {
+                match record {
+                    Ok(record) => lazy_uids.push(record.sop_instance_uid.unwrap()),
+                    Err(_) => lazy_errors += 1,
+                }
+            }
+            let mut eager_uids: Vec<_> = eager
+                .into_iter()
+                .map(|record| record.sop_instance_uid.unwrap())
+                .collect();
+            lazy_uids.sort();
+            eager_uids.sort();
+
+            assert_eq!(lazy_uids, eager_uids);
+            assert_eq!(lazy_errors, skipped.len());
+            assert_eq!(lazy_uids.len(), if recursive { 3 } else { 2 });
+        }
+    }
+
+    #[test]
+    fn iter_records_yields_error_for_non_image_objects() {
+        use crate::error::MammocatError;
+        use crate::types::SopClassKind;
+        use dicom_core::VR;
+        use dicom_dictionary_std::{tags, uids};
+
+        let directory = tempdir().unwrap();
+        MammogramFixture::new("1.2.3.9")
+            .with(tags::SOP_CLASS_UID, VR::UI, uids::MAMMOGRAPHY_CADSR_STORAGE)
+            .view("L", "CC")
+            .write(&directory.path().join("cad.dcm"));
+
+        let results: Vec<_> = iter_records(directory.path(), false).collect();
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(MammocatError::NonImageSopClass(SopClassKind::CadSr))
+        ));
+    }
+
+    #[test]
+    fn iter_records_yields_error_for_missing_directory() {
+        let directory = tempdir().unwrap();
+
+        let mut records = iter_records(&directory.path().join("missing"), false);
+
+        assert!(records.next().unwrap().is_err());
+        assert!(records.next().is_none());
+    }
+}
@@ -1,13 +1,22 @@
 use crate::api::{MammogramExtractor, MammogramMetadata};
-use crate::error::Result;
+use crate::dicom_files::gzipped_dicom_reader;
+use crate::error::{MammocatError, Result};
+use crate::extraction::mammo_type::contains_exact_token;
 use crate::extraction::tags::{
-    get_string_value, get_u16_value, COLUMNS, LOSSY_IMAGE_COMPRESSION, PIXEL_DATA_TAG, ROWS,
-    SERIES_INSTANCE_UID, SOP_INSTANCE_UID, STUDY_INSTANCE_UID,
+    get_date_value, get_string_value, get_u16_value, BITS_STORED, COLUMNS, LOSSY_IMAGE_COMPRESSION,
+    PATIENT_ID, PATIENT_NAME, PIXEL_DATA_TAG, ROWS, SERIES_INSTANCE_UID, SOP_CLASS_UID,
+    SOP_INSTANCE_UID, STUDY_DATE, STUDY_INSTANCE_UID,
 };
-use crate::types::PreferenceOrder;
+use crate::extraction::CompressionKind;
+use crate::types::{
+    DbtObjectKind, FilterConfig, ImageType, MammogramType, PreferenceOrder, SopClassKind,
+};
+use chrono::NaiveDate;
 use dicom_object::{FileDicomObject, InMemDicomObject, OpenFileOptions};
 use std::cmp::Ordering;
-use std::path::PathBuf;
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 
 /// Transfer syntax UIDs that imply lossy image compression.
 ///
@@ -67,6 +76,55 @@ pub(crate) fn lossy_compression_source(
     }
 }
 
+/// Whether any ImageType value has `token` as a whole word, ignoring case.
+fn image_type_mentions(image_type: &ImageType, token: &str) -> bool {
+    let values = [
+        Some(&image_type.pixels),
+        Some(&image_type.exam),
+        image_type.flavor.as_ref(),
+    ];
+    values
+        .into_iter()
+        .flatten()
+        .chain(image_type.extras.iter().flatten())
+        .any(|value| contains_exact_token(value, token))
+}
+
+/// Reads a DICOM file once and returns both its metadata and record
+///
+/// Equivalent to calling [`MammogramExtractor::extract_file`] and
+/// [`MammogramRecord::from_file`] but opens the file a single time. Only
+/// metadata (headers) is read, not pixel data.
+///
+/// # Arguments
+///
+/// * `path` - Path to DICOM file
+///
+/// # Returns
+///
+/// Result containing the extracted metadata and the record built from it
+pub fn extract_and_record(path: PathBuf) -> Result<(MammogramMetadata, MammogramRecord)> {
+    let dcm = OpenFileOptions::new()
+        .read_until(PIXEL_DATA_TAG)
+        .open_file(&path)?;
+    let record = MammogramRecord::from_file_dicom(path, &dcm)?;
+    Ok((record.metadata.clone(), record))
+}
+
+/// Identity of a [`MammogramRecord`] for deduplication
+///
+/// Unlike the preference-based [`PartialEq`] on records, two keys are equal
+/// only when they refer to the same SOP instance, or, for records without a
+/// SOPInstanceUID, the same file path. Implements [`Hash`] so keys can be
+/// collected into a `HashSet` or used as `HashMap` keys.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RecordDedupKey {
+    /// Trimmed, non-empty SOPInstanceUID
+    SopInstanceUid(String),
+    /// File path fallback for records without a usable SOPInstanceUID
+    FilePath(PathBuf),
+}
+
 /// Mammogram record combining file path and extracted metadata
 ///
 /// Used for preferred view selection. Implements comparison logic
@@ -94,11 +152,26 @@ pub struct MammogramRecord {
     /// Number of columns in image
     pub columns: Option<u16>,
 
+    /// Bits Stored per pixel sample
+    pub bits_stored: Option<u16>,
+
     /// Transfer Syntax UID from file metadata, when available
     pub transfer_syntax_uid: Option<String>,
 
     /// Whether metadata indicates current or historical lossy compression
     pub is_lossy_compressed: bool,
+
+    /// Study Date, when present and a valid DICOM DA value
+    pub study_date: Option<NaiveDate>,
+
+    /// Patient ID
+    pub patient_id: Option<String>,
+
+    /// Patient's Name, used to recognize phantom and QC scans
+    pub patient_name: Option<String>,
+
+    /// SOP class family from SOPClassUID
+    pub sop_class: SopClassKind,
 }
 
 impl MammogramRecord {
@@ -112,13 +185,41 @@ impl MammogramRecord {
     ///
     /// # Returns
     ///
-    /// Result containing the MammogramRecord or an error
+    /// Result containing the MammogramRecord or an error. The SOP class is
+    /// recorded in `sop_class`; use [`MammogramRecord::from_image_file`] to
+    /// reject non-image objects up front.
+    ///
+    /// Paths ending in `.gz` are gzip-decompressed while parsing, stopping at
+    /// PixelData like uncompressed files; the record keeps the compressed path
+    /// as its `file_path`.
     pub fn from_file(path: PathBuf) -> Result<Self> {
-        // Read only metadata, stop before pixel data tag for performance
-        let dcm = OpenFileOptions::new()
-            .read_until(PIXEL_DATA_TAG)
-            .open_file(&path)?;
-        Self::from_file_dicom(path, &dcm)
+        Self::from_file_with_modality_policy(path, false)
+    }
+
+    /// Creates a record from a DICOM file, optionally ignoring the Modality tag
+    ///
+    /// With `ignore_modality` set, files whose Modality is not "MG" are
+    /// classified from their other tags instead of being rejected; see
+    /// [`MammogramExtractor::extract_with_options_and_modality_policy`].
+    pub fn from_file_with_modality_policy(path: PathBuf, ignore_modality: bool) -> Result<Self> {
+        let dcm = read_file_header(&path)?;
+        Self::from_file_dicom_with_modality_policy(path, &dcm, ignore_modality)
+    }
+
+    /// Creates a record from a DICOM file, skipping non-image SOP classes
+    ///
+    /// Like [`MammogramRecord::from_file_with_modality_policy`], but objects
+    /// whose SOP class is not an image (see [`SopClassKind::is_non_image`]),
+    /// such as CAD SR or presentation states, fail with
+    /// [`MammocatError::NonImageSopClass`] before metadata extraction. This
+    /// lets directory scans tell them apart from unreadable files.
+    pub fn from_image_file(path: PathBuf, ignore_modality: bool) -> Result<Self> {
+        let dcm = read_file_header(&path)?;
+        let sop_class = sop_class_kind(&dcm);
+        if sop_class.is_non_image() {
+            return Err(MammocatError::NonImageSopClass(sop_class));
+        }
+        Self::from_file_dicom_with_modality_policy(path, &dcm, ignore_modality)
     }
 
     /// Creates a MammogramRecord from in-memory DICOM bytes.
@@ -137,10 +238,27 @@ impl MammogramRecord {
     ///   the provided `id` (or empty path if None)
     /// * `Err` - If DICOM parsing fails or required metadata is missing
     pub fn from_bytes(bytes: &[u8], id: Option<&str>) -> Result<Self> {
-        let cursor = std::io::Cursor::new(bytes);
+        Self::from_reader(bytes, id)
+    }
+
+    /// Creates a MammogramRecord by reading a DICOM file from any byte source.
+    ///
+    /// Accepts network streams, object-storage bodies, or open files without
+    /// writing to a temporary file first. The 128-byte preamble is detected
+    /// automatically, and reading stops before pixel data like `from_file`.
+    ///
+    /// # Arguments
+    /// * `reader` - Source of DICOM file bytes
+    /// * `id` - Optional label stored as `file_path` for reporting; no file
+    ///   needs to exist at that path
+    ///
+    /// # Returns
+    /// * `Ok(MammogramRecord)` - Parsed record
+    /// * `Err` - If DICOM parsing fails or required metadata is missing
+    pub fn from_reader<R: Read>(reader: R, id: Option<&str>) -> Result<Self> {
         let dcm = OpenFileOptions::new()
             .read_until(PIXEL_DATA_TAG)
-            .from_reader(cursor)?;
+            .from_reader(reader)?;
 
         let path = id.map(PathBuf::from).unwrap_or_default();
         Self::from_file_dicom(path, &dcm)
@@ -177,7 +295,21 @@ impl MammogramRecord {
 
     /// Creates a record from an already-opened DICOM file object.
     pub fn from_file_dicom(path: PathBuf, dcm: &FileDicomObject<InMemDicomObject>) -> Result<Self> {
-        let metadata = MammogramExtractor::extract_file(dcm)?;
+        Self::from_file_dicom_with_modality_policy(path, dcm, false)
+    }
+
+    /// Creates a record from an already-opened DICOM file object, optionally
+    /// ignoring the Modality tag.
+    pub fn from_file_dicom_with_modality_policy(
+        path: PathBuf,
+        dcm: &FileDicomObject<InMemDicomObject>,
+        ignore_modality: bool,
+    ) -> Result<Self> {
+        let metadata = MammogramExtractor::extract_file_with_options_and_modality_policy(
+            dcm,
+            false,
+            ignore_modality,
+        )?;
         let transfer_syntax_uid = metadata
             .transfer_syntax_uid
             .clone()
@@ -209,11 +341,41 @@ impl MammogramRecord {
             sop_instance_uid: get_string_value(dcm, SOP_INSTANCE_UID),
             rows: get_u16_value(dcm, ROWS),
             columns: get_u16_value(dcm, COLUMNS),
+            bits_stored: get_u16_value(dcm, BITS_STORED),
             transfer_syntax_uid,
             is_lossy_compressed,
+            study_date: get_date_value(dcm, STUDY_DATE),
+            patient_id: get_string_value(dcm, PATIENT_ID),
+            patient_name: get_string_value(dcm, PATIENT_NAME),
+            sop_class: sop_class_kind(dcm),
         })
     }
 
+    /// Returns the key identifying this record for deduplication
+    ///
+    /// Uses the SOPInstanceUID when present and non-blank, otherwise falls
+    /// back to the file path, so the same UID-less file read twice still
+    /// deduplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::{MammogramRecord, RecordDedupKey};
+    ///
+    /// # fn check(record: &MammogramRecord) {
+    /// match record.dedup_key() {
+    ///     RecordDedupKey::SopInstanceUid(uid) => println!("instance {uid}"),
+    ///     RecordDedupKey::FilePath(path) => println!("file {}", path.display()),
+    /// }
+    /// # }
+    /// ```
+    pub fn dedup_key(&self) -> RecordDedupKey {
+        match normalized_optional_identifier(&self.sop_instance_uid) {
+            Some(uid) => RecordDedupKey::SopInstanceUid(uid.to_string()),
+            None => RecordDedupKey::FilePath(self.file_path.clone()),
+        }
+    }
+
     /// Computes image area (rows * columns)
     ///
     /// # Returns
@@ -226,6 +388,30 @@ impl MammogramRecord {
         }
     }
 
+    /// Computes the physical image size in millimeters
+    ///
+    /// # Returns
+    ///
+    /// `(rows * spacing.row, columns * spacing.col)`, or None if the
+    /// dimensions or the pixel spacing are not available
+    pub fn physical_size_mm(&self) -> Option<(f64, f64)> {
+        let spacing = self.metadata.pixel_spacing?;
+        Some((
+            f64::from(self.rows?) * spacing.row,
+            f64::from(self.columns?) * spacing.col,
+        ))
+    }
+
+    /// Pixel data compression family from the Transfer Syntax UID
+    ///
+    /// Returns [`CompressionKind::Other`] when the transfer syntax is unknown.
+    pub fn compression(&self) -> CompressionKind {
+        self.transfer_syntax_uid.as_deref().map_or(
+            CompressionKind::Other,
+            CompressionKind::from_transfer_syntax,
+        )
+    }
+
     /// Checks if this is a spot compression or magnification view
     ///
     /// These views are deprioritized during selection
@@ -252,29 +438,101 @@ impl MammogramRecord {
         self.metadata.is_magnified()
     }
 
+    /// Whether this record has a rolled view modifier.
+    pub fn is_rolled(&self) -> bool {
+        self.metadata.is_rolled()
+    }
+
     /// Whether this record has any modifier that should lose to an otherwise
     /// equivalent unmodified view.
+    ///
+    /// Includes magnification detected only from the spacing ratio, so
+    /// ranking agrees with [`MammogramRecord::is_spot_or_mag`].
     pub fn has_deprioritized_view_modifier(&self) -> bool {
-        self.metadata
-            .view_modifiers
+        self.is_magnified()
+            || self
+                .metadata
+                .view_modifiers
+                .iter()
+                .any(|modifier| modifier.affects_selection())
+    }
+
+    /// Lists discrepancies between extracted flags and the fields that should
+    /// corroborate them
+    ///
+    /// Intended for QA of the extractor itself rather than of the source
+    /// data: an ImageType SPOT or MAG value the extractor did not turn into a
+    /// spot compression or magnification flag, a view modifier without a
+    /// known base ViewPosition, an implant displaced view without
+    /// BreastImplantPresent, or a DBT object kind on a non-TOMO record may
+    /// indicate a detection bug.
+    ///
+    /// Spot and magnification flags do not need an ImageType value: view
+    /// modifiers come from ViewModifierCodeSequence, ViewPosition, and view
+    /// descriptions, and a spacing ratio above
+    /// [`crate::api::SPACING_MAGNIFICATION_THRESHOLD`] also marks magnification, so
+    /// each of those already corroborates the flag.
+    ///
+    /// # Returns
+    ///
+    /// Human-readable discrepancy descriptions, empty when all flags agree
+    pub fn self_check(&self) -> Vec<String> {
+        let metadata = &self.metadata;
+        let mut discrepancies = Vec::new();
+
+        if image_type_mentions(&metadata.image_type, "SPOT") && !self.is_spot_compression() {
+            discrepancies.push(format!(
+                "ImageType {} has a SPOT value but no spot compression modifier was detected",
+                metadata.image_type
+            ));
+        }
+        let image_type_magnified = ["MAG", "MAGNIFICATION"]
             .iter()
-            .any(|modifier| modifier.affects_selection())
+            .any(|token| image_type_mentions(&metadata.image_type, token));
+        if image_type_magnified && !self.is_magnified() {
+            discrepancies.push(format!(
+                "ImageType {} has a MAG value but no magnification was detected",
+                metadata.image_type
+            ));
+        }
+        if !metadata.view_modifiers.is_empty() && metadata.view_position.is_unknown() {
+            discrepancies.push("view modifiers present but ViewPosition is unknown".to_string());
+        }
+        if self.is_implant_displaced() && !metadata.has_implant {
+            discrepancies
+                .push("implant displaced flag set but BreastImplantPresent is not YES".to_string());
+        }
+        if metadata.dbt_object_kind != DbtObjectKind::None
+            && metadata.mammogram_type != MammogramType::Tomo
+        {
+            discrepancies.push(format!(
+                "DBT object kind {} set on {} record",
+                metadata.dbt_object_kind,
+                metadata.mammogram_type.simple_name()
+            ));
+        }
+
+        discrepancies
     }
 
     /// Checks if this record is preferred over another
     ///
     /// Implements Python logic from record.py:805-838
-    /// Uses the default preference order (FFDM > SYNTH > TOMO > SFM)
+    /// Uses the default preference order (FFDM > SYNTH > TOMO > SFM > CEM)
     ///
     /// Priority order:
     /// 1. Standard views beat non-standard views
-    /// 2. Views without deprioritized CID 4015 modifiers beat modified views
-    /// 3. Records are partitioned by StudyInstanceUID for stable cross-study ordering
-    /// 4. Implant displaced beats non-displaced within a study
-    /// 5. Lossless beats lossy compressed
-    /// 6. Type preference (FFDM > SYNTH > TOMO > SFM)
-    /// 7. Higher resolution beats lower resolution
-    /// 8. Stable source identifiers break remaining ties
+    /// 2. Single-breast images beat bilateral acquisitions
+    /// 3. Views without deprioritized CID 4015 modifiers beat modified views
+    /// 4. Newer StudyDate beats older or undated (only with `prefer_latest_study`,
+    ///    which this method leaves off)
+    /// 5. Records are partitioned by StudyInstanceUID for stable cross-study ordering
+    /// 6. Implant displaced beats non-displaced within a study
+    /// 7. Lossless beats lossy compressed
+    /// 8. Type preference (FFDM > SYNTH > TOMO > SFM > CEM)
+    /// 9. Higher resolution beats lower resolution
+    /// 10. Higher bit depth (BitsStored) beats lower bit depth
+    /// 11. Stable source identifiers break remaining ties
     ///
     /// # Arguments
     ///
@@ -293,13 +551,17 @@ impl MammogramRecord {
     ///
     /// Priority order:
     /// 1. Standard views beat non-standard views
-    /// 2. Views without deprioritized CID 4015 modifiers beat modified views
-    /// 3. Records are partitioned by StudyInstanceUID for stable cross-study ordering
-    /// 4. Implant displaced beats non-displaced within a study
-    /// 5. Lossless beats lossy compressed
-    /// 6. Type preference (according to the provided preference order)
-    /// 7. Higher resolution beats lower resolution
-    /// 8. Stable source identifiers break remaining ties
+    /// 2. Single-breast images beat bilateral acquisitions
+    /// 3. Views without deprioritized CID 4015 modifiers beat modified views
+    /// 4. Newer StudyDate beats older or undated (only with `prefer_latest_study`,
+    ///    which this method leaves off)
+    /// 5. Records are partitioned by StudyInstanceUID for stable cross-study ordering
+    /// 6. Implant displaced beats non-displaced within a study
+    /// 7. Lossless beats lossy compressed
+    /// 8. Type preference (according to the provided preference order)
+    /// 9. Higher resolution beats lower resolution
+    /// 10. Higher bit depth (BitsStored) beats lower bit depth
+    /// 11. Stable source identifiers break remaining ties
     ///
     /// # Arguments
     ///
@@ -327,7 +589,27 @@ impl MammogramRecord {
         preference_order: PreferenceOrder,
         deprioritize_lossy_compressed: bool,
     ) -> bool {
-        self.preference_cmp_with_options(other, preference_order, deprioritize_lossy_compressed)
+        let rules = PreferenceRules {
+            deprioritize_lossy_compressed,
+            ..PreferenceRules::default()
+        };
+        self.preference_cmp_with_options(other, preference_order, rules) == Ordering::Less
+    }
+
+    /// Checks if this record is preferred over another using the ranking
+    /// options of a filter configuration
+    ///
+    /// Applies `deprioritize_lossy_compressed` and `prefer_latest_study` from
+    /// `config`. With `prefer_latest_study`, records from a newer StudyDate
+    /// beat records from an older or undated study right after the
+    /// view-kind rules, before the StudyInstanceUID ordering.
+    pub fn is_preferred_to_with_config(
+        &self,
+        other: &MammogramRecord,
+        preference_order: PreferenceOrder,
+        config: &FilterConfig,
+    ) -> bool {
+        self.preference_cmp_with_options(other, preference_order, PreferenceRules::from(config))
             == Ordering::Less
     }
 
@@ -335,52 +617,197 @@ impl MammogramRecord {
         &self,
         other: &MammogramRecord,
         preference_order: PreferenceOrder,
-        deprioritize_lossy_compressed: bool,
+        rules: PreferenceRules,
     ) -> Ordering {
-        prefer_true(
-            self.metadata.is_standard_view(),
-            other.metadata.is_standard_view(),
-        )
-        .then_with(|| {
-            self.has_deprioritized_view_modifier()
-                .cmp(&other.has_deprioritized_view_modifier())
-        })
-        .then_with(|| {
-            compare_optional_identifier(&self.study_instance_uid, &other.study_instance_uid)
-        })
-        .then_with(|| {
-            let same_known_study = normalized_optional_identifier(&self.study_instance_uid)
-                .zip(normalized_optional_identifier(&other.study_instance_uid))
-                .is_some_and(|(left, right)| left == right);
-            if same_known_study {
-                prefer_true(self.is_implant_displaced(), other.is_implant_displaced())
-            } else {
-                Ordering::Equal
-            }
-        })
-        .then_with(|| {
-            if deprioritize_lossy_compressed {
-                self.is_lossy_compressed.cmp(&other.is_lossy_compressed)
-            } else {
-                Ordering::Equal
-            }
-        })
-        .then_with(|| {
-            preference_order
-                .preference_value(&self.metadata.mammogram_type)
-                .cmp(&preference_order.preference_value(&other.metadata.mammogram_type))
-        })
-        .then_with(|| {
-            other
-                .image_area()
-                .unwrap_or(0)
-                .cmp(&self.image_area().unwrap_or(0))
-        })
-        .then_with(|| compare_optional_identifier(&self.sop_instance_uid, &other.sop_instance_uid))
-        .then_with(|| {
-            compare_optional_identifier(&self.series_instance_uid, &other.series_instance_uid)
-        })
-        .then_with(|| self.file_path.cmp(&other.file_path))
+        self.preference_cmp_with_reason(other, preference_order, rules)
+            .0
+    }
+
+    /// Compares two records and reports which rule decided the outcome
+    ///
+    /// Rules are applied in the same order as
+    /// [`MammogramRecord::is_preferred_to_with_options`]; the returned reason
+    /// is the first rule that told the records apart.
+    pub(crate) fn preference_cmp_with_reason(
+        &self,
+        other: &MammogramRecord,
+        preference_order: PreferenceOrder,
+        preference_rules: PreferenceRules,
+    ) -> (Ordering, SelectionReason) {
+        let same_known_study = normalized_optional_identifier(&self.study_instance_uid)
+            .zip(normalized_optional_identifier(&other.study_instance_uid))
+            .is_some_and(|(left, right)| left == right);
+        let rules: [(SelectionReason, &dyn Fn() -> Ordering); 11] = [
+            (SelectionReason::StandardView, &|| {
+                prefer_true(
+                    self.metadata.is_standard_view(),
+                    other.metadata.is_standard_view(),
+                )
+            }),
+            (SelectionReason::Unilateral, &|| {
+                prefer_true(
+                    self.metadata.laterality.is_unilateral(),
+                    other.metadata.laterality.is_unilateral(),
+                )
+            }),
+            (SelectionReason::NotSpotMag, &|| {
+                self.has_deprioritized_view_modifier()
+                    .cmp(&other.has_deprioritized_view_modifier())
+            }),
+            (SelectionReason::LatestStudy, &|| {
+                if preference_rules.prefer_latest_study {
+                    match (self.study_date, other.study_date) {
+                        (Some(left), Some(right)) => right.cmp(&left),
+                        (Some(_), None) => Ordering::Less,
+                        (None, Some(_)) => Ordering::Greater,
+                        (None, None) => Ordering::Equal,
+                    }
+                } else {
+                    Ordering::Equal
+                }
+            }),
+            (SelectionReason::StudyUid, &|| {
+                compare_optional_identifier(&self.study_instance_uid, &other.study_instance_uid)
+            }),
+            (SelectionReason::ImplantDisplaced, &|| {
+                if same_known_study {
+                    prefer_true(self.is_implant_displaced(), other.is_implant_displaced())
+                } else {
+                    Ordering::Equal
+                }
+            }),
+            (SelectionReason::Lossless, &|| {
+                if preference_rules.deprioritize_lossy_compressed {
+                    self.is_lossy_compressed.cmp(&other.is_lossy_compressed)
+                } else {
+                    Ordering::Equal
+                }
+            }),
+            (SelectionReason::TypePreference, &|| {
+                preference_order
+                    .preference_value(&self.metadata.mammogram_type)
+                    .cmp(&preference_order.preference_value(&other.metadata.mammogram_type))
+            }),
+            (SelectionReason::HigherResolution, &|| {
+                other
+                    .image_area()
+                    .unwrap_or(0)
+                    .cmp(&self.image_area().unwrap_or(0))
+            }),
+            (SelectionReason::HigherBitDepth, &|| {
+                other
+                    .bits_stored
+                    .unwrap_or(0)
+                    .cmp(&self.bits_stored.unwrap_or(0))
+            }),
+            (SelectionReason::SopUidTiebreak, &|| {
+                compare_optional_identifier(&self.sop_instance_uid, &other.sop_instance_uid)
+            }),
+        ];
+
+        rules
+            .iter()
+            .find_map(|(reason, rule)| {
+                let ordering = rule();
+                ordering.is_ne().then_some((ordering, *reason))
+            })
+            .unwrap_or_else(|| {
+                let ordering = compare_optional_identifier(
+                    &self.series_instance_uid,
+                    &other.series_instance_uid,
+                )
+                .then_with(|| self.file_path.cmp(&other.file_path));
+                (ordering, SelectionReason::FallbackTiebreak)
+            })
+    }
+}
+
+/// Comparison rule that decided a preferred-view selection
+///
+/// Returned by [`crate::get_preferred_views_explained`] for each selected
+/// record: the first rule that separated the winner from the runner-up, or
+/// [`SelectionReason::OnlyCandidate`] when nothing competed with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json", serde(rename_all = "snake_case"))]
+pub enum SelectionReason {
+    /// No other record competed for the view
+    OnlyCandidate,
+    /// Standard CC/MLO view preferred over a non-standard one
+    StandardView,
+    /// Single-breast image preferred over a bilateral acquisition
+    Unilateral,
+    /// Unmodified view preferred over spot compression, magnification, etc.
+    NotSpotMag,
+    /// Newer StudyDate preferred (with `prefer_latest_study`)
+    LatestStudy,
+    /// Ordered by StudyInstanceUID (records from different studies)
+    StudyUid,
+    /// Implant displaced view preferred within the same study
+    ImplantDisplaced,
+    /// Lossless record preferred over a lossy compressed one
+    Lossless,
+    /// Mammogram type ranked better under the preference order
+    TypePreference,
+    /// Larger image area (Rows × Columns)
+    HigherResolution,
+    /// Larger BitsStored at equal image area
+    HigherBitDepth,
+    /// Lowest SOPInstanceUID
+    SopUidTiebreak,
+    /// SeriesInstanceUID, then file path
+    FallbackTiebreak,
+}
+
+impl SelectionReason {
+    /// Returns simple name for display and dictionary output
+    pub fn simple_name(&self) -> &'static str {
+        match self {
+            Self::OnlyCandidate => "only_candidate",
+            Self::StandardView => "standard_view",
+            Self::Unilateral => "unilateral",
+            Self::NotSpotMag => "not_spot_mag",
+            Self::LatestStudy => "latest_study",
+            Self::StudyUid => "study_uid",
+            Self::ImplantDisplaced => "implant_displaced",
+            Self::Lossless => "lossless",
+            Self::TypePreference => "type_preference",
+            Self::HigherResolution => "higher_resolution",
+            Self::HigherBitDepth => "higher_bit_depth",
+            Self::SopUidTiebreak => "sop_uid_tiebreak",
+            Self::FallbackTiebreak => "fallback_tiebreak",
+        }
+    }
+}
+
+impl fmt::Display for SelectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.simple_name())
+    }
+}
+
+/// Optional comparison rules taken from [`FilterConfig`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PreferenceRules {
+    pub(crate) deprioritize_lossy_compressed: bool,
+    pub(crate) prefer_latest_study: bool,
+}
+
+impl Default for PreferenceRules {
+    fn default() -> Self {
+        Self {
+            deprioritize_lossy_compressed: true,
+            prefer_latest_study: false,
+        }
+    }
+}
+
+impl From<&FilterConfig> for PreferenceRules {
+    fn from(config: &FilterConfig) -> Self {
+        Self {
+            deprioritize_lossy_compressed: config.deprioritize_lossy_compressed,
+            prefer_latest_study: config.prefer_latest_study,
+        }
     }
 }
 
@@ -420,6 +847,26 @@ fn normalized_transfer_syntax_uid(uid: &str) -> &str {
     uid.trim().trim_end_matches('\0').trim()
 }
 
+fn sop_class_kind(dcm: &InMemDicomObject) -> SopClassKind {
+    get_string_value(dcm, SOP_CLASS_UID)
+        .map(|uid| SopClassKind::from_uid(&uid))
+        .unwrap_or_default()
+}
+
+/// Reads a DICOM file up to PixelData, decompressing `.gz` paths on the fly
+fn read_file_header(path: &Path) -> Result<FileDicomObject<InMemDicomObject>> {
+    let options = OpenFileOptions::new().read_until(PIXEL_DATA_TAG);
+    let dcm = if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gz"))
+    {
+        options.from_reader(gzipped_dicom_reader(path)?)?
+    } else {
+        options.open_file(path)?
+    };
+    Ok(dcm)
+}
+
 fn is_lossy_compressed(dcm: &InMemDicomObject, transfer_syntax_uid: Option<&str>) -> bool {
     let lossy_image_compression = get_string_value(dcm, LOSSY_IMAGE_COMPRESSION);
     lossy_compression_source(lossy_image_compression.as_deref(), transfer_syntax_uid).is_some()
@@ -454,7 +901,11 @@ impl PartialOrd for MammogramRecord {
 
 impl Ord for MammogramRecord {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.preference_cmp_with_options(other, PreferenceOrder::Default, true)
+        self.preference_cmp_with_options(
+            other,
+            PreferenceOrder::Default,
+            PreferenceRules::default(),
+        )
     }
 }
 
@@ -463,7 +914,8 @@ mod tests {
     use super::*;
     use crate::extraction::tags::LOSSY_IMAGE_COMPRESSION;
     use crate::types::{
-        DbtObjectKind, ImageType, Laterality, MammogramType, MammographyViewModifier, ViewPosition,
+        DbtObjectKind, ImageType, Laterality, MammogramType, MammographyViewModifier,
+        PhotometricInterpretation, PixelSpacing, PresentationIntent, ViewPosition,
     };
     use dicom_core::{DataElement, PrimitiveValue, VR};
 
@@ -502,23 +954,38 @@ mod tests {
                     None,
                 ),
                 is_for_processing: false,
+                presentation_intent: PresentationIntent::Unknown,
                 has_implant: false,
                 manufacturer: None,
                 model: None,
                 number_of_frames: 1,
                 pixel_spacing: None,
+                imager_pixel_spacing: None,
+                photometric: PhotometricInterpretation::Monochrome2,
+                rescale_slope: None,
+                rescale_intercept: None,
+                body_part_thickness_mm: None,
+                compression_force_n: None,
                 concatenation_uid: None,
                 sop_instance_uid_of_concatenation_source: None,
                 is_secondary_capture: false,
+                is_localizer: false,
+                is_specimen: false,
                 modality: Some("MG".to_string()),
+                character_set: None,
                 transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
                 transfer_syntax_name: Some("Explicit VR Little Endian".to_string()),
                 compression_type: Some("uncompressed".to_string()),
             },
             rows,
             columns,
+            bits_stored: None,
             transfer_syntax_uid: None,
             is_lossy_compressed: false,
+            study_date: None,
+            patient_id: None,
+            patient_name: None,
+            sop_class: SopClassKind::MammoImage,
             study_instance_uid: study_uid,
             series_instance_uid: None,
             sop_instance_uid: sop_uid,
@@ -570,6 +1037,48 @@ mod tests {
         dcm
     }
 
+    #[test]
+    fn test_from_dicom_populates_study_date_and_patient_id() {
+        let mut dcm = InMemDicomObject::new_empty();
+        for (tag, vr, value) in [
+            (crate::extraction::tags::MODALITY, VR::CS, "MG"),
+            (crate::extraction::tags::IMAGE_LATERALITY, VR::CS, "R"),
+            (crate::extraction::tags::VIEW_POSITION, VR::CS, "CC"),
+            (STUDY_DATE, VR::DA, "20231102"),
+            (PATIENT_ID, VR::LO, "PAT-001"),
+            (PATIENT_NAME, VR::PN, "ACR PHANTOM"),
+        ] {
+            dcm.put(DataElement::new(tag, vr, PrimitiveValue::from(value)));
+        }
+
+        let record = MammogramRecord::from_dicom(PathBuf::from("dated.dcm"), &dcm).unwrap();
+
+        assert_eq!(record.study_date, NaiveDate::from_ymd_opt(2023, 11, 2));
+        assert_eq!(record.patient_id.as_deref(), Some("PAT-001"));
+        assert_eq!(record.patient_name.as_deref(), Some("ACR PHANTOM"));
+    }
+
+    #[test]
+    fn test_from_dicom_populates_bits_stored() {
+        let mut dcm = InMemDicomObject::new_empty();
+        for (tag, vr, value) in [
+            (crate::extraction::tags::MODALITY, VR::CS, "MG"),
+            (crate::extraction::tags::IMAGE_LATERALITY, VR::CS, "L"),
+            (crate::extraction::tags::VIEW_POSITION, VR::CS, "MLO"),
+        ] {
+            dcm.put(DataElement::new(tag, vr, PrimitiveValue::from(value)));
+        }
+        dcm.put(DataElement::new(
+            BITS_STORED,
+            VR::US,
+            PrimitiveValue::from(12_u16),
+        ));
+
+        let record = MammogramRecord::from_dicom(PathBuf::from("12bit.dcm"), &dcm).unwrap();
+
+        assert_eq!(record.bits_stored, Some(12));
+    }
+
     #[test]
     fn test_lossy_image_compression_tag_true() {
         let dcm = dicom_with_lossy_image_compression("01");
@@ -683,6 +1192,30 @@ mod tests {
         assert_eq!(record.image_area(), None);
     }
 
+    #[test]
+    fn test_physical_size_mm() {
+        let mut record = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(2560),
+            Some(3328),
+            true,
+            false,
+            false,
+            false,
+            None,
+            None,
+        );
+        assert_eq!(record.physical_size_mm(), None);
+
+        record.metadata.pixel_spacing = Some(PixelSpacing::new(0.125, 0.0625));
+        assert_eq!(record.physical_size_mm(), Some((320.0, 208.0)));
+
+        record.columns = None;
+        assert_eq!(record.physical_size_mm(), None);
+    }
+
     #[test]
     fn test_is_preferred_to_standard_view() {
         let standard = make_test_record(
@@ -1008,6 +1541,98 @@ mod tests {
         assert!(!low_res.is_preferred_to(&high_res));
     }
 
+    #[test]
+    fn test_is_preferred_to_bit_depth_at_equal_resolution() {
+        let mut twelve_bit = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(3000),
+            Some(4000),
+            true,
+            false,
+            false,
+            false,
+            None,
+            Some("2".to_string()),
+        );
+        twelve_bit.bits_stored = Some(12);
+        let mut eight_bit = twelve_bit.clone();
+        eight_bit.sop_instance_uid = Some("1".to_string());
+        eight_bit.bits_stored = Some(8);
+
+        // Bit depth decides before the SOP UID tie-break
+        assert!(twelve_bit.is_preferred_to(&eight_bit));
+        assert!(!eight_bit.is_preferred_to(&twelve_bit));
+
+        // Missing BitsStored ranks below any known depth
+        eight_bit.bits_stored = None;
+        assert!(twelve_bit.is_preferred_to(&eight_bit));
+    }
+
+    #[test]
+    fn test_bit_depth_does_not_override_resolution() {
+        let mut high_res = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(3000),
+            Some(4000),
+            true,
+            false,
+            false,
+            false,
+            None,
+            None,
+        );
+        high_res.bits_stored = Some(8);
+        let mut low_res = high_res.clone();
+        low_res.rows = Some(2000);
+        low_res.columns = Some(2500);
+        low_res.bits_stored = Some(16);
+
+        assert!(high_res.is_preferred_to(&low_res));
+    }
+
+    #[test]
+    fn test_prefer_latest_study_ranks_newer_study_first() {
+        let mut prior = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(3000),
+            Some(4000),
+            true,
+            false,
+            false,
+            false,
+            Some("1.2.826.0.10".to_string()),
+            None,
+        );
+        prior.study_date = NaiveDate::from_ymd_opt(2023, 5, 10);
+        let mut current = prior.clone();
+        current.study_instance_uid = Some("1.2.826.0.20".to_string());
+        current.study_date = NaiveDate::from_ymd_opt(2024, 5, 10);
+        let latest = FilterConfig::default().prefer_latest_study(true);
+
+        // Without the flag the lower StudyInstanceUID wins
+        assert!(prior.is_preferred_to(&current));
+        assert!(current.is_preferred_to_with_config(&prior, PreferenceOrder::Default, &latest));
+        assert!(!prior.is_preferred_to_with_config(&current, PreferenceOrder::Default, &latest));
+        assert_eq!(
+            current.preference_cmp_with_reason(
+                &prior,
+                PreferenceOrder::Default,
+                PreferenceRules::from(&latest)
+            ),
+            (Ordering::Less, SelectionReason::LatestStudy)
+        );
+
+        // An undated study ranks after a dated one
+        current.study_date = None;
+        assert!(prior.is_preferred_to_with_config(&current, PreferenceOrder::Default, &latest));
+    }
+
     #[test]
     fn test_ord_implementation() {
         let better = make_test_record(
@@ -1285,6 +1910,241 @@ mod tests {
         assert!(regular.is_preferred_to(&implant_displaced));
     }
 
+    #[test]
+    fn test_is_preferred_to_with_order_tomo_first_flips_type_preference() {
+        let make = |mammo_type: MammogramType| {
+            make_test_record(
+                mammo_type,
+                ViewPosition::Cc,
+                Laterality::Left,
+                Some(2560),
+                Some(3328),
+                true,
+                false,
+                false,
+                false,
+                None,
+                None,
+            )
+        };
+        let ffdm = make(MammogramType::Ffdm);
+        let tomo = make(MammogramType::Tomo);
+
+        assert!(ffdm.is_preferred_to_with_order(&tomo, PreferenceOrder::Default));
+        assert!(!tomo.is_preferred_to_with_order(&ffdm, PreferenceOrder::Default));
+        assert!(tomo.is_preferred_to_with_order(&ffdm, PreferenceOrder::TomoFirst));
+        assert!(!ffdm.is_preferred_to_with_order(&tomo, PreferenceOrder::TomoFirst));
+        assert_eq!(
+            ffdm.is_preferred_to(&tomo),
+            ffdm.is_preferred_to_with_order(&tomo, PreferenceOrder::default())
+        );
+    }
+
+    #[test]
+    fn test_is_spot_or_mag_flags() {
+        let make = |spot: bool, mag: bool| {
+            make_test_record(
+                MammogramType::Ffdm,
+                ViewPosition::Cc,
+                Laterality::Left,
+                Some(2560),
+                Some(3328),
+                true,
+                false,
+                spot,
+                mag,
+                None,
+                None,
+            )
+        };
+
+        assert!(!make(false, false).is_spot_or_mag());
+        assert!(make(true, false).is_spot_or_mag());
+        assert!(make(false, true).is_spot_or_mag());
+        assert!(make(false, false).is_preferred_to(&make(true, false)));
+    }
+
+    #[test]
+    fn test_self_check_reports_image_type_spot_without_flag() {
+        let mut record = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(2560),
+            Some(3328),
+            true,
+            false,
+            false, // no spot compression modifier despite the SPOT ImageType value
+            false,
+            None,
+            None,
+        );
+        record.metadata.image_type = ImageType::new(
+            "ORIGINAL".to_string(),
+            "PRIMARY".to_string(),
+            None,
+            Some(vec!["SPOT".to_string()]),
+        );
+
+        let discrepancies = record.self_check();
+
+        assert_eq!(discrepancies.len(), 1);
+        assert!(discrepancies[0].contains("spot compression"));
+    }
+
+    #[test]
+    fn test_self_check_accepts_coded_spot_and_spacing_magnification() {
+        // Spot compression from ViewModifierCodeSequence, ImageType silent
+        let coded_spot = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(2560),
+            Some(3328),
+            true,
+            false,
+            true,
+            false,
+            None,
+            None,
+        );
+        assert!(coded_spot.self_check().is_empty());
+
+        let mut spacing_mag = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(2560),
+            Some(3328),
+            true,
+            false,
+            false,
+            false,
+            None,
+            None,
+        );
+        spacing_mag.metadata.imager_pixel_spacing = Some(PixelSpacing::new(0.1, 0.1));
+        spacing_mag.metadata.pixel_spacing = Some(PixelSpacing::new(0.05, 0.05));
+        spacing_mag.metadata.image_type = ImageType::new(
+            "ORIGINAL".to_string(),
+            "PRIMARY".to_string(),
+            Some("MAG".to_string()),
+            None,
+        );
+        assert!(spacing_mag.is_magnified());
+        assert!(spacing_mag.self_check().is_empty());
+    }
+
+    #[test]
+    fn test_self_check_matches_image_type_whole_tokens() {
+        let mut record = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(2560),
+            Some(3328),
+            true,
+            false,
+            false,
+            false,
+            None,
+            None,
+        );
+        record.metadata.image_type = ImageType::new(
+            "DERIVED".to_string(),
+            "PRIMARY".to_string(),
+            Some("POST_PROCESSED IMAGE".to_string()),
+            Some(vec!["SPOTLIGHT".to_string()]),
+        );
+
+        assert!(record.self_check().is_empty());
+    }
+
+    #[test]
+    fn test_self_check_accepts_corroborated_flags() {
+        let mut record = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(2560),
+            Some(3328),
+            true,
+            false,
+            true,
+            false,
+            None,
+            None,
+        );
+        record.metadata.image_type = ImageType::new(
+            "ORIGINAL".to_string(),
+            "PRIMARY".to_string(),
+            None,
+            Some(vec!["SPOT".to_string()]),
+        );
+
+        assert!(record.self_check().is_empty());
+    }
+
+    #[test]
+    fn test_self_check_reports_implant_and_dbt_discrepancies() {
+        let mut record = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(2560),
+            Some(3328),
+            true,
+            true, // implant displaced without BreastImplantPresent
+            false,
+            false,
+            None,
+            None,
+        );
+        record.metadata.dbt_object_kind = DbtObjectKind::Volume;
+
+        let discrepancies = record.self_check();
+
+        assert_eq!(discrepancies.len(), 2);
+        assert!(discrepancies[0].contains("BreastImplantPresent"));
+        assert!(discrepancies[1].contains("DBT object kind"));
+    }
+
+    #[test]
+    fn test_is_preferred_to_ranks_spacing_magnification_below_normal_view() {
+        let normal = make_test_record(
+            MammogramType::Ffdm,
+            ViewPosition::Cc,
+            Laterality::Left,
+            Some(2560),
+            Some(3328),
+            true,
+            false,
+            false,
+            false,
+            None,
+            Some("BBB".to_string()),
+        );
+        let mut magnified = normal.clone();
+        magnified.sop_instance_uid = Some("AAA".to_string());
+        magnified.metadata.imager_pixel_spacing = Some(PixelSpacing::new(0.1, 0.1));
+        magnified.metadata.pixel_spacing = Some(PixelSpacing::new(0.05, 0.05));
+        assert!(magnified.metadata.view_modifiers.is_empty());
+        assert!(magnified.is_spot_or_mag());
+
+        // Without the spacing evidence the lower SOP UID would win
+        assert!(normal.is_preferred_to(&magnified));
+        assert_eq!(
+            normal
+                .preference_cmp_with_reason(
+                    &magnified,
+                    PreferenceOrder::Default,
+                    PreferenceRules::default()
+                )
+                .1,
+            SelectionReason::NotSpotMag
+        );
+    }
+
     #[test]
     fn test_is_preferred_to_spot_vs_mag_same_priority() {
         let spot = make_test_record(
@@ -1319,6 +2179,192 @@ mod tests {
         assert!(spot.is_preferred_to(&mag)); // AAA < BBB
     }
 
+    #[test]
+    fn test_extract_and_record_returns_metadata_and_record() {
+        use dicom_dictionary_std::{tags, uids};
+        use dicom_object::FileMetaTableBuilder;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("rcc.dcm");
+        InMemDicomObject::from_element_iter([
+            DataElement::new(
+                tags::SOP_CLASS_UID,
+                VR::UI,
+                uids::DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION,
+            ),
+            DataElement::new(tags::SOP_INSTANCE_UID, VR::UI, "1.2.3.4"),
+            DataElement::new(tags::STUDY_INSTANCE_UID, VR::UI, "1.2.3"),
+            DataElement::new(tags::MODALITY, VR::CS, "MG"),
+            DataElement::new(tags::IMAGE_TYPE, VR::CS, "ORIGINAL\\PRIMARY"),
+            DataElement::new(tags::IMAGE_LATERALITY, VR::CS, "R"),
+            DataElement::new(tags::VIEW_POSITION, VR::CS, "CC"),
+            DataElement::new(tags::ROWS, VR::US, PrimitiveValue::from(32_u16)),
+            DataElement::new(tags::COLUMNS, VR::US, PrimitiveValue::from(24_u16)),
+        ])
+        .with_meta(
+            FileMetaTableBuilder::new()
+                .transfer_syntax(uids::EXPLICIT_VR_LITTLE_ENDIAN)
+                .media_storage_sop_class_uid(
+                    uids::DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION,
+                )
+                .media_storage_sop_instance_uid("1.2.3.4"),
+        )
+        .unwrap()
+        .write_to_file(&path)
+        .unwrap();
+
+        let (metadata, record) = extract_and_record(path.clone()).unwrap();
+
+        assert_eq!(metadata.laterality, Laterality::Right);
+        assert_eq!(metadata.view_position, ViewPosition::Cc);
+        assert_eq!(record.metadata, metadata);
+        assert_eq!(record.file_path, path);
+        assert_eq!(record.sop_instance_uid.as_deref(), Some("1.2.3.4"));
+        assert_eq!((record.rows, record.columns), (Some(32), Some(24)));
+    }
+
+    /// Writes a small L-MLO mammogram with pixel data to `path`.
+    fn write_lmlo_fixture(path: &std::path::Path) {
+        use crate::test_support::MammogramFixture;
+        use dicom_dictionary_std::tags;
+
+        let pixels = vec![0x0123_u16; 64 * 48];
+        MammogramFixture::new("1.2.3.5")
+            .with(tags::STUDY_INSTANCE_UID, VR::UI, "1.2.3")
+            .with(tags::PATIENT_ID, VR::LO, "PAT-002")
+            .with(tags::STUDY_DATE, VR::DA, "20240210")
+            .view("L", "MLO")
+            .with(tags::ROWS, VR::US, 64_u16)
+            .with(tags::COLUMNS, VR::US, 48_u16)
+            .with(tags::BITS_ALLOCATED, VR::US, 16_u16)
+            .with(tags::PIXEL_DATA, VR::OW, PrimitiveValue::U16(pixels.into()))
+            .write(path);
+    }
+
+    #[test]
+    fn test_from_file_skips_pixel_data_without_changing_record() {
+        use dicom_dictionary_std::tags;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("lmlo_with_pixels.dcm");
+        write_lmlo_fixture(&path);
+
+        let full_dicom = dicom_object::open_file(&path).unwrap();
+        assert!(full_dicom.element(tags::PIXEL_DATA).is_ok());
+        let header_only_dicom = OpenFileOptions::new()
+            .read_until(PIXEL_DATA_TAG)
+            .open_file(&path)
+            .unwrap();
+        assert!(header_only_dicom.element(tags::PIXEL_DATA).is_err());
+
+        let from_full = MammogramRecord::from_file_dicom(path.clone(), &full_dicom).unwrap();
+        let header_only = MammogramRecord::from_file(path).unwrap();
+
+        assert_eq!(format!("{header_only:?}"), format!("{from_full:?}"));
+        assert_eq!(header_only.patient_id.as_deref(), Some("PAT-002"));
+    }
+
+    #[test]
+    fn test_from_bytes_and_reader_match_from_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("lmlo_with_pixels.dcm");
+        write_lmlo_fixture(&path);
+        let label = path.to_str().unwrap();
+
+        let from_file = MammogramRecord::from_file(path.clone()).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let from_bytes = MammogramRecord::from_bytes(&bytes, Some(label)).unwrap();
+        let from_reader =
+            MammogramRecord::from_reader(std::fs::File::open(&path).unwrap(), Some(label)).unwrap();
+
+        assert_eq!(format!("{from_bytes:?}"), format!("{from_file:?}"));
+        assert_eq!(format!("{from_reader:?}"), format!("{from_file:?}"));
+        assert_eq!(from_file.compression(), CompressionKind::Uncompressed);
+
+        let unlabeled = MammogramRecord::from_bytes(&bytes, None).unwrap();
+        assert_eq!(unlabeled.file_path, PathBuf::new());
+        assert_eq!(unlabeled.sop_instance_uid.as_deref(), Some("1.2.3.5"));
+    }
+
+    #[test]
+    fn test_from_file_decompresses_gzipped_dicom() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("lmlo.dcm");
+        write_lmlo_fixture(&path);
+        let gz_path = temp_dir.path().join("lmlo.dcm.gz");
+        let mut encoder = GzEncoder::new(
+            std::fs::File::create(&gz_path).unwrap(),
+            Compression::default(),
+        );
+        encoder.write_all(&std::fs::read(&path).unwrap()).unwrap();
+        encoder.finish().unwrap();
+
+        let plain = MammogramRecord::from_file(path.clone()).unwrap();
+        let mut gzipped = MammogramRecord::from_file(gz_path.clone()).unwrap();
+
+        assert_eq!(gzipped.file_path, gz_path);
+        gzipped.file_path = path;
+        assert_eq!(format!("{gzipped:?}"), format!("{plain:?}"));
+    }
+
+    #[test]
+    fn test_from_file_records_sop_class() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("lmlo.dcm");
+        write_lmlo_fixture(&path);
+
+        let record = MammogramRecord::from_file(path).unwrap();
+        assert_eq!(record.sop_class, SopClassKind::MammoImage);
+    }
+
+    #[test]
+    fn test_cad_sr_is_classified_and_only_rejected_by_from_image_file() {
+        use dicom_dictionary_std::{tags, uids};
+        use dicom_object::FileMetaTableBuilder;
+
+        let dcm = InMemDicomObject::from_element_iter([
+            DataElement::new(tags::SOP_CLASS_UID, VR::UI, uids::MAMMOGRAPHY_CADSR_STORAGE),
+            DataElement::new(tags::SOP_INSTANCE_UID, VR::UI, "1.2.3.9"),
+            DataElement::new(tags::STUDY_INSTANCE_UID, VR::UI, "1.2.3"),
+            DataElement::new(tags::MODALITY, VR::CS, "MG"),
+            DataElement::new(tags::IMAGE_LATERALITY, VR::CS, "L"),
+            DataElement::new(tags::VIEW_POSITION, VR::CS, "MLO"),
+        ])
+        .with_meta(
+            FileMetaTableBuilder::new()
+                .transfer_syntax(uids::EXPLICIT_VR_LITTLE_ENDIAN)
+                .media_storage_sop_class_uid(uids::MAMMOGRAPHY_CADSR_STORAGE)
+                .media_storage_sop_instance_uid("1.2.3.9"),
+        )
+        .unwrap();
+        let mut bytes = Vec::new();
+        dcm.write_all(&mut bytes).unwrap();
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("cad.dcm");
+        dcm.write_to_file(&path).unwrap();
+
+        let record = MammogramRecord::from_bytes(&bytes, Some("cad.dcm")).unwrap();
+        assert_eq!(record.sop_class, SopClassKind::CadSr);
+        let record = MammogramRecord::from_file(path.clone()).unwrap();
+        assert_eq!(record.sop_class, SopClassKind::CadSr);
+
+        let error = MammogramRecord::from_image_file(path, false).unwrap_err();
+        assert!(matches!(
+            error,
+            MammocatError::NonImageSopClass(SopClassKind::CadSr)
+        ));
+        assert_eq!(error.to_string(), "Non-image SOP class: cad_sr");
+    }
+
+    #[test]
+    fn test_extract_and_record_missing_file() {
+        assert!(extract_and_record(PathBuf::from("/nonexistent/file.dcm")).is_err());
+    }
+
     #[test]
     fn test_from_bytes_invalid_data() {
         // Invalid bytes should return an error
@@ -1326,6 +2372,42 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_dedup_key_falls_back_to_file_path() {
+        let make = |sop_uid: Option<&str>| {
+            make_test_record(
+                MammogramType::Ffdm,
+                ViewPosition::Cc,
+                Laterality::Left,
+                None,
+                None,
+                true,
+                false,
+                false,
+                false,
+                None,
+                sop_uid.map(str::to_string),
+            )
+        };
+
+        let keys: std::collections::HashSet<_> = [make(None), make(None), make(Some(" "))]
+            .iter()
+            .map(MammogramRecord::dedup_key)
+            .collect();
+        assert_eq!(
+            keys.into_iter().collect::<Vec<_>>(),
+            vec![RecordDedupKey::FilePath(PathBuf::from("test.dcm"))]
+        );
+
+        let mut other_path = make(None);
+        other_path.file_path = PathBuf::from("other.dcm");
+        assert_ne!(other_path.dedup_key(), make(None).dedup_key());
+        assert_eq!(
+            make(Some(" 1.2.3 ")).dedup_key(),
+            RecordDedupKey::SopInstanceUid("1.2.3".to_string())
+        );
+    }
+
     #[test]
     fn test_from_bytes_empty_bytes() {
         // Empty bytes should return an error
@@ -0,0 +1,259 @@
+//! Serializable snapshots of preferred-view selection for QA regression tests
+
+use crate::error::Result;
+use crate::selection::record::{MammogramRecord, PreferenceRules};
+use crate::selection::views::{
+    get_preferred_views_filtered_with_study_mode_and_warnings, rank_candidates_for_view,
+    selection_study_records, StudySelectionMode,
+};
+use crate::types::{FilterConfig, PreferenceOrder, STANDARD_MAMMO_VIEWS};
+use std::collections::BTreeMap;
+
+/// Per-view winner and ranked candidates captured in a [`SelectionSnapshot`]
+///
+/// Records are identified by SOPInstanceUID, falling back to the file path
+/// when the UID is missing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewSnapshot {
+    /// Identifier of the selected record, if any
+    pub selected: Option<String>,
+    /// Identifiers of all considered candidates, best first
+    pub candidates: Vec<String>,
+}
+
+/// Reproducible record of a preferred-view selection
+///
+/// Captures, for each standard view, the winner plus the ordered list of
+/// candidates that were considered after filtering and study selection.
+/// Saving snapshots and comparing them across versions catches selection
+/// regressions that a winner-only comparison would miss.
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::{FilterConfig, PreferenceOrder, SelectionSnapshot};
+///
+/// # let records = vec![]; // Would normally load from files
+/// let snapshot =
+///     SelectionSnapshot::capture(&records, &FilterConfig::default(), PreferenceOrder::Default)
+///         .unwrap();
+/// assert_eq!(snapshot.views.len(), 4);
+/// assert!(snapshot.views["lcc"].selected.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectionSnapshot {
+    /// Snapshot per standard view, keyed by short view code (e.g. "lcc")
+    pub views: BTreeMap<String, ViewSnapshot>,
+}
+
+impl SelectionSnapshot {
+    /// Captures a snapshot using the most-complete-study selection mode
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if selection fails.
+    pub fn capture(
+        records: &[MammogramRecord],
+        filter_config: &FilterConfig,
+        preference_order: PreferenceOrder,
+    ) -> Result<Self> {
+        Self::capture_with_study_mode(
+            records,
+            filter_config,
+            preference_order,
+            StudySelectionMode::MostComplete,
+        )
+    }
+
+    /// Captures a snapshot with explicit study handling
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if selection fails, e.g. multiple studies in
+    /// [`StudySelectionMode::StrictSingleStudy`].
+    pub fn capture_with_study_mode(
+        records: &[MammogramRecord],
+        filter_config: &FilterConfig,
+        preference_order: PreferenceOrder,
+        study_selection_mode: StudySelectionMode,
+    ) -> Result<Self> {
+        let (selection, _) = get_preferred_views_filtered_with_study_mode_and_warnings(
+            records,
+            filter_config,
+            preference_order,
+            study_selection_mode,
+        )?;
+        let study_records = selection_study_records(records, filter_config, study_selection_mode)?;
+
+        let views = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                let candidates = rank_candidates_for_view(
+                    &study_records,
+                    view,
+                    preference_order,
+                    PreferenceRules::from(filter_config),
+                )
+                .into_iter()
+                .map(record_id)
+                .collect();
+                let selected = selection.get(view).cloned().flatten();
+                let snapshot = ViewSnapshot {
+                    selected: selected.as_ref().map(record_id),
+                    candidates,
+                };
+                (view.to_string(), snapshot)
+            })
+            .collect();
+
+        Ok(Self { views })
+    }
+}
+
+fn record_id(record: &MammogramRecord) -> String {
+    record
+        .sop_instance_uid
+        .clone()
+        .unwrap_or_else(|| record.file_path.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::MammogramMetadata;
+    use crate::test_support::{sample_metadata, sample_record};
+    use crate::types::{Laterality, MammogramType, ViewPosition};
+
+    fn make_record(
+        laterality: Laterality,
+        view_position: ViewPosition,
+        mammogram_type: MammogramType,
+        sop_uid: &str,
+    ) -> MammogramRecord {
+        MammogramRecord {
+            sop_instance_uid: Some(sop_uid.to_string()),
+            ..sample_record(
+                format!("{sop_uid}.dcm"),
+                MammogramMetadata {
+                    mammogram_type,
+                    laterality,
+                    view_position,
+                    ..sample_metadata()
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn snapshot_records_winner_and_ranked_candidates() {
+        let records = vec![
+            make_record(
+                Laterality::Left,
+                ViewPosition::Cc,
+                MammogramType::Sfm,
+                "1.1",
+            ),
+            make_record(
+                Laterality::Left,
+                ViewPosition::Cc,
+                MammogramType::Ffdm,
+                "1.2",
+            ),
+            make_record(
+                Laterality::Left,
+                ViewPosition::Cc,
+                MammogramType::Synth,
+                "1.3",
+            ),
+            make_record(
+                Laterality::Right,
+                ViewPosition::Mlo,
+                MammogramType::Ffdm,
+                "2.1",
+            ),
+        ];
+
+        let snapshot = SelectionSnapshot::capture(
+            &records,
+            &FilterConfig::default(),
+            PreferenceOrder::Default,
+        )
+        .unwrap();
+
+        let keys: Vec<&str> = snapshot.views.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["lcc", "lmlo", "rcc", "rmlo"]);
+        assert_eq!(
+            snapshot.views["lcc"],
+            ViewSnapshot {
+                selected: Some("1.2".to_string()),
+                candidates: vec!["1.2".to_string(), "1.3".to_string(), "1.1".to_string()],
+            }
+        );
+        assert_eq!(snapshot.views["rmlo"].selected.as_deref(), Some("2.1"));
+        assert_eq!(
+            snapshot.views["lmlo"],
+            ViewSnapshot {
+                selected: None,
+                candidates: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn snapshot_omits_filtered_candidates() {
+        let mut for_processing = make_record(
+            Laterality::Left,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            "1.9",
+        );
+        for_processing.metadata.is_for_processing = true;
+        let records = vec![
+            for_processing,
+            make_record(
+                Laterality::Left,
+                ViewPosition::Cc,
+                MammogramType::Ffdm,
+                "1.2",
+            ),
+        ];
+
+        let snapshot = SelectionSnapshot::capture(
+            &records,
+            &FilterConfig::default(),
+            PreferenceOrder::Default,
+        )
+        .unwrap();
+
+        assert_eq!(snapshot.views["lcc"].candidates, vec!["1.2".to_string()]);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn snapshot_round_trips_through_json() {
+        let records = vec![make_record(
+            Laterality::Right,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            "3.1",
+        )];
+        let snapshot = SelectionSnapshot::capture(
+            &records,
+            &FilterConfig::default(),
+            PreferenceOrder::Default,
+        )
+        .unwrap();
+
+        let json = serde_json::to_value(&snapshot).unwrap();
+        assert_eq!(json["views"]["rcc"]["selected"], "3.1");
+        assert_eq!(
+            json["views"]["rcc"]["candidates"],
+            serde_json::json!(["3.1"])
+        );
+
+        let restored: SelectionSnapshot = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, snapshot);
+    }
+}
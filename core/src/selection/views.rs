@@ -1,11 +1,13 @@
 use crate::error::{MammocatError, Result};
-use crate::selection::record::MammogramRecord;
+use crate::selection::record::{MammogramRecord, PreferenceRules, SelectionReason};
 use crate::types::{
-    DbtObjectKind, FilterConfig, MammogramType, MammogramView, PreferenceOrder,
-    STANDARD_MAMMO_VIEWS,
+    DbtObjectKind, FilterConfig, Laterality, MammogramType, MammogramView, PreferenceOrder,
+    ViewPosition, STANDARD_MAMMO_VIEWS,
 };
+use chrono::NaiveDate;
+use std::borrow::{Borrow, Cow};
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
 
 const MIXED_STUDY_WARNING_PREFIX: &str = "mixed study input detected";
@@ -47,6 +49,13 @@ impl SelectionWarning {
 /// Preferred-view selection result map.
 pub type PreferredViewSelection = HashMap<MammogramView, Option<MammogramRecord>>;
 
+/// Preferred-view selection result annotated with the deciding comparison rule.
+pub type PreferredViewSelectionExplained =
+    HashMap<MammogramView, Option<(MammogramRecord, SelectionReason)>>;
+
+/// Preferred-view selection in canonical [`STANDARD_MAMMO_VIEWS`] order.
+pub type OrderedViewSelection = Vec<(MammogramView, Option<MammogramRecord>)>;
+
 /// Preferred-view selection result with non-fatal warnings.
 pub type PreferredViewSelectionWithWarnings = (PreferredViewSelection, Vec<SelectionWarning>);
 
@@ -66,6 +75,38 @@ impl DbtRefinementReason {
     }
 }
 
+/// Why preferred-view selection left a standard view empty.
+///
+/// Returned by [`explain_missing`]. Counts are the number of records that
+/// matched the view's laterality and MLO-like/CC-like position at the stage
+/// that removed them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingReason {
+    /// The view has a selected record.
+    NotMissing,
+    /// No record matches the view's laterality and view position.
+    NoCandidates,
+    /// Matching records exist but every one was removed by the filter configuration.
+    AllFiltered(usize),
+    /// Matching records passed the filters but belong to a study that was not selected.
+    OtherStudySelected(usize),
+    /// Matching records were dropped to keep the selection to one modality group.
+    CommonModalityConflict(usize),
+}
+
+impl MissingReason {
+    /// Stable diagnostic code for reports and JSON output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NotMissing => "not_missing",
+            Self::NoCandidates => "no_candidates",
+            Self::AllFiltered(_) => "all_filtered",
+            Self::OtherStudySelected(_) => "other_study_selected",
+            Self::CommonModalityConflict(_) => "common_modality_conflict",
+        }
+    }
+}
+
 /// Diagnostic emitted when collection context changes a DBT classification.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DbtRefinementDiagnostic {
@@ -83,9 +124,12 @@ pub struct DbtRefinementDiagnostic {
 #[derive(Debug, Clone)]
 struct StudyGroup {
     study_instance_uid: Option<String>,
-    records: Vec<MammogramRecord>,
+    /// Position of each record in the slice the groups were built from
+    source_indices: Vec<usize>,
     standard_slot_count: usize,
     candidate_slot_count: usize,
+    /// Most recent StudyDate among the group's records
+    latest_study_date: Option<NaiveDate>,
     unknown_sort_key: Option<(String, String)>,
 }
 
@@ -95,6 +139,14 @@ struct SelectedStudyRecords {
     warnings: Vec<SelectionWarning>,
 }
 
+/// Study chosen by [`select_study_indices`], without copying its records
+#[derive(Debug, Clone)]
+struct SelectedStudyIndices {
+    /// Position of each selected record in the slice passed to `select_study_indices`
+    source_indices: Vec<usize>,
+    warnings: Vec<SelectionWarning>,
+}
+
 /// Selects preferred inference views from a collection of mammogram records
 ///
 /// For each of the 4 standard views (L-MLO, R-MLO, L-CC, R-CC), selects the
@@ -113,6 +165,39 @@ pub fn get_preferred_views(records: &[MammogramRecord]) -> PreferredViewSelectio
     get_preferred_views_with_order(records, PreferenceOrder::default())
 }
 
+/// Selects preferred inference views in canonical view order
+///
+/// Same selection as [`get_preferred_views`], returned as a list ordered
+/// L-MLO, R-MLO, L-CC, R-CC so iteration is deterministic.
+///
+/// # Arguments
+///
+/// * `records` - Slice of MammogramRecord to select from
+///
+/// # Returns
+///
+/// One entry per standard view, in [`STANDARD_MAMMO_VIEWS`] order
+pub fn get_preferred_views_ordered(records: &[MammogramRecord]) -> OrderedViewSelection {
+    let mut selection = get_preferred_views(records);
+    STANDARD_MAMMO_VIEWS
+        .iter()
+        .map(|view| (*view, selection.remove(view).flatten()))
+        .collect()
+}
+
+/// Lists a selection map's standard views in canonical order
+///
+/// Views missing from `selection` map to `None`; keys outside
+/// [`STANDARD_MAMMO_VIEWS`] are not included.
+pub fn ordered_selection(
+    selection: &PreferredViewSelection,
+) -> Vec<(MammogramView, Option<&MammogramRecord>)> {
+    STANDARD_MAMMO_VIEWS
+        .iter()
+        .map(|view| (*view, selection.get(view).and_then(Option::as_ref)))
+        .collect()
+}
+
 /// Selects preferred inference views using a specific preference order
 ///
 /// For each of the 4 standard views (L-MLO, R-MLO, L-CC, R-CC), selects the
@@ -136,25 +221,408 @@ pub fn get_preferred_views_with_order(
     selection
 }
 
+/// Selects preferred views without cloning, borrowing winners from `records`
+///
+/// Uses the same DBT refinement, study selection, and comparison logic as
+/// [`get_preferred_views_with_order`], so the selected records are the same.
+/// Candidates are compared in place; only records whose DBT classification
+/// is refined are copied. The returned references point at the caller's
+/// records, which keep their original (unrefined) DBT classification.
+///
+/// # Arguments
+///
+/// * `records` - Slice of MammogramRecord to select from
+/// * `preference_order` - The preference ordering strategy to use
+///
+/// # Returns
+///
+/// HashMap mapping each standard view to a reference to the selected record
+/// (or None if not found)
+pub fn get_preferred_views_ref(
+    records: &[MammogramRecord],
+    preference_order: PreferenceOrder,
+) -> HashMap<MammogramView, Option<&MammogramRecord>> {
+    let (refined_records, _) = refine_dbt_classification_borrowed(records);
+    let selected_study = select_study_indices(
+        &refined_records,
+        StudySelectionMode::MostComplete,
+        false,
+        false,
+    )
+    .expect("most-complete study selection should not fail");
+    log_selection_warnings(&selected_study.warnings);
+
+    // DBT refinement keeps record positions, so study indices map onto `records`
+    let study_records: Vec<&MammogramRecord> = selected_study
+        .source_indices
+        .iter()
+        .map(|&index| refined_records[index].as_ref())
+        .collect();
+    select_preferred_indices(&study_records, preference_order, PreferenceRules::default())
+        .into_iter()
+        .map(|(view, index)| {
+            (
+                view,
+                index.map(|index| &records[selected_study.source_indices[index]]),
+            )
+        })
+        .collect()
+}
+
+/// Ranks all candidates for each standard view, best first
+///
+/// Uses the same study selection and comparison logic as
+/// [`get_preferred_views_with_order`], so the first record of each list is
+/// the record that function selects. Views without candidates map to an
+/// empty list.
+///
+/// # Arguments
+///
+/// * `records` - Slice of MammogramRecord to rank
+/// * `preference_order` - The preference ordering strategy to use
+///
+/// # Returns
+///
+/// HashMap mapping each standard view to its candidates sorted best to worst
+pub fn get_ranked_views(
+    records: &[MammogramRecord],
+    preference_order: PreferenceOrder,
+) -> HashMap<MammogramView, Vec<MammogramRecord>> {
+    let refined_records = refine_dbt_object_classification(records);
+    let selected_study = select_study_records(
+        &refined_records,
+        StudySelectionMode::MostComplete,
+        false,
+        false,
+    )
+    .expect("most-complete study selection should not fail");
+    log_selection_warnings(&selected_study.warnings);
+
+    STANDARD_MAMMO_VIEWS
+        .iter()
+        .map(|view| {
+            let ranked = rank_candidates_for_view(
+                &selected_study.records,
+                view,
+                preference_order,
+                PreferenceRules::default(),
+            )
+            .into_iter()
+            .cloned()
+            .collect();
+            (*view, ranked)
+        })
+        .collect()
+}
+
+/// Selects preferred views and reports why each winner was chosen
+///
+/// Uses the same study selection and comparison logic as
+/// [`get_preferred_views_with_order`]. Each selected record is paired with
+/// the first comparison rule that separated it from the runner-up, or
+/// [`SelectionReason::OnlyCandidate`] when it was the sole candidate.
+///
+/// # Arguments
+///
+/// * `records` - Slice of MammogramRecord to select from
+/// * `preference_order` - The preference ordering strategy to use
+///
+/// # Returns
+///
+/// HashMap mapping each standard view to the selected record and its reason
+pub fn get_preferred_views_explained(
+    records: &[MammogramRecord],
+    preference_order: PreferenceOrder,
+) -> PreferredViewSelectionExplained {
+    let refined_records = refine_dbt_object_classification(records);
+    let selected_study = select_study_records(
+        &refined_records,
+        StudySelectionMode::MostComplete,
+        false,
+        false,
+    )
+    .expect("most-complete study selection should not fail");
+    log_selection_warnings(&selected_study.warnings);
+
+    STANDARD_MAMMO_VIEWS
+        .iter()
+        .map(|view| {
+            let ranked = rank_candidates_for_view(
+                &selected_study.records,
+                view,
+                preference_order,
+                PreferenceRules::default(),
+            );
+            let explained = ranked.first().map(|winner| {
+                let reason = ranked
+                    .get(1)
+                    .map_or(SelectionReason::OnlyCandidate, |runner_up| {
+                        winner
+                            .preference_cmp_with_reason(
+                                runner_up,
+                                preference_order,
+                                PreferenceRules::default(),
+                            )
+                            .1
+                    });
+                ((*winner).clone(), reason)
+            });
+            (*view, explained)
+        })
+        .collect()
+}
+
+/// Selects the preferred record for every view present in the records
+///
+/// Unlike [`get_preferred_views_with_order`], records are grouped by their
+/// exact `(laterality, view_position)` rather than matched against the four
+/// standard views, so non-standard acquisitions such as XCCL or ML are
+/// surfaced under their own keys. AT and CV are view modifiers, so those
+/// images are grouped under their base view position. Records with unknown
+/// laterality or view position are skipped, and no study selection is
+/// applied.
+///
+/// The four standard views are always present as keys, mapping to `None`
+/// when no record has exactly that view.
+///
+/// # Arguments
+///
+/// * `records` - Slice of MammogramRecord to select from
+/// * `preference_order` - The preference ordering strategy to use
+///
+/// # Returns
+///
+/// HashMap mapping each view to its most preferred record
+pub fn get_all_views(
+    records: &[MammogramRecord],
+    preference_order: PreferenceOrder,
+) -> PreferredViewSelection {
+    select_best_per_view(records, preference_order, |view| view)
+}
+
+/// Selects the preferred record per normalized (laterality, view) bucket
+///
+/// Each record's view is mapped through
+/// [`MammogramView::normalized_standard`], so CC-like views such as XCCL
+/// share the CC bucket and MLO-like views such as ML share the MLO bucket.
+/// Because standard views rank ahead of non-standard ones, an XCCL only
+/// fills the CC bucket when no plain CC exists. Other view positions keep
+/// their own buckets. As with [`get_all_views`], records with unknown
+/// laterality or view position are skipped and no study selection is
+/// applied.
+///
+/// # Arguments
+///
+/// * `records` - Slice of MammogramRecord to select from
+/// * `preference_order` - The preference ordering strategy to use
+///
+/// # Returns
+///
+/// HashMap mapping each normalized view to its most preferred record
+pub fn get_preferred_views_normalized(
+    records: &[MammogramRecord],
+    preference_order: PreferenceOrder,
+) -> PreferredViewSelection {
+    select_best_per_view(records, preference_order, |view| view.normalized_standard())
+}
+
+/// Picks the best record per view key, seeding the four standard views
+fn select_best_per_view(
+    records: &[MammogramRecord],
+    preference_order: PreferenceOrder,
+    view_key: impl Fn(MammogramView) -> MammogramView,
+) -> PreferredViewSelection {
+    let refined_records = refine_dbt_object_classification(records);
+    let mut result: PreferredViewSelection = STANDARD_MAMMO_VIEWS
+        .iter()
+        .map(|view| (*view, None))
+        .collect();
+
+    for record in &refined_records {
+        let view = view_key(record.metadata.mammogram_view());
+        if view.laterality.is_unknown_or_none() || view.view.is_unknown() {
+            continue;
+        }
+        let best = result.entry(view).or_insert(None);
+        let is_better = best.as_ref().is_none_or(|current| {
+            compare_record_preference(
+                record,
+                current,
+                preference_order,
+                PreferenceRules::default(),
+            ) == Ordering::Less
+        });
+        if is_better {
+            *best = Some(record.clone());
+        }
+    }
+
+    result
+}
+
+/// Re-runs preferred view selection after excluding a record by SOP Instance UID
+///
+/// Intended for interactive correction, where a reviewer rejects an
+/// auto-selected image and wants the next-best candidate for that view.
+/// Every record whose SOPInstanceUID equals `excluded_sop_uid` is dropped
+/// before selection is recomputed.
+///
+/// # Arguments
+///
+/// * `records` - Slice of MammogramRecord to select from
+/// * `excluded_sop_uid` - SOPInstanceUID of the rejected record
+/// * `preference_order` - The preference ordering strategy to use
+///
+/// # Returns
+///
+/// HashMap mapping each standard view to the selected record (or None if not found)
+pub fn reselect_excluding(
+    records: &[MammogramRecord],
+    excluded_sop_uid: &str,
+    preference_order: PreferenceOrder,
+) -> PreferredViewSelection {
+    let remaining: Vec<MammogramRecord> = records
+        .iter()
+        .filter(|record| record.sop_instance_uid.as_deref() != Some(excluded_sop_uid))
+        .cloned()
+        .collect();
+    get_preferred_views_with_order(&remaining, preference_order)
+}
+
+/// Groups a preferred view selection into left and right breast sets
+///
+/// Each laterality maps to its CC and MLO slots, in that order, so a
+/// per-breast workflow can process `[CC, MLO]` pairs without looking up
+/// individual views.
+///
+/// # Arguments
+///
+/// * `selection` - Selection returned by one of the `get_preferred_views*` functions
+///
+/// # Returns
+///
+/// HashMap with `Left` and `Right` entries, each holding two slots (or None if not found)
+pub fn by_laterality(
+    selection: &PreferredViewSelection,
+) -> HashMap<Laterality, Vec<Option<MammogramRecord>>> {
+    [Laterality::Left, Laterality::Right]
+        .into_iter()
+        .map(|laterality| {
+            let slots = [ViewPosition::Cc, ViewPosition::Mlo]
+                .into_iter()
+                .map(|view_position| {
+                    selection
+                        .get(&MammogramView::new(laterality, view_position))
+                        .cloned()
+                        .flatten()
+                })
+                .collect();
+            (laterality, slots)
+        })
+        .collect()
+}
+
+/// Checks whether one breast has both CC and MLO candidates
+///
+/// Uses the same CC-like/MLO-like matching as view selection, so XCCL counts
+/// as a CC candidate and ML as an MLO candidate. Useful for per-breast
+/// completeness checks such as unilateral diagnostic studies.
+///
+/// # Arguments
+///
+/// * `records` - Candidate mammogram records
+/// * `laterality` - Breast side to check
+///
+/// # Returns
+///
+/// `true` if at least one CC and one MLO candidate exist for the side
+pub fn has_both_standard_views(records: &[MammogramRecord], laterality: Laterality) -> bool {
+    [ViewPosition::Cc, ViewPosition::Mlo]
+        .into_iter()
+        .map(|view_position| MammogramView::new(laterality, view_position))
+        .all(|target| {
+            records
+                .iter()
+                .any(|record| is_candidate_for_view(record, &target))
+        })
+}
+
+/// Returns the standard views without a selected record, in standard view order
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::{missing_views, PreferredViewSelection, STANDARD_MAMMO_VIEWS};
+///
+/// let selections = PreferredViewSelection::new();
+/// assert_eq!(missing_views(&selections), STANDARD_MAMMO_VIEWS.to_vec());
+/// ```
+pub fn missing_views(selections: &PreferredViewSelection) -> Vec<MammogramView> {
+    STANDARD_MAMMO_VIEWS
+        .iter()
+        .filter(|view| !matches!(selections.get(view), Some(Some(_))))
+        .copied()
+        .collect()
+}
+
+/// Returns the fraction of the four standard views with a selected record
+///
+/// A complete bilateral study scores 1.0, a study missing one view 0.75,
+/// and an empty selection 0.0. Non-standard views in `selections` are ignored.
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::{study_completeness, PreferredViewSelection};
+///
+/// assert_eq!(study_completeness(&PreferredViewSelection::new()), 0.0);
+/// ```
+pub fn study_completeness(selections: &PreferredViewSelection) -> f32 {
+    let present = STANDARD_MAMMO_VIEWS.len() - missing_views(selections).len();
+    present as f32 / STANDARD_MAMMO_VIEWS.len() as f32
+}
+
 /// Selects preferred inference views and returns non-fatal selection warnings.
 pub fn get_preferred_views_with_order_and_warnings(
     records: &[MammogramRecord],
     preference_order: PreferenceOrder,
 ) -> PreferredViewSelectionWithWarnings {
     let refined_records = refine_dbt_object_classification(records);
-    let selected_study =
-        select_study_records(&refined_records, StudySelectionMode::MostComplete, false)
-            .expect("most-complete study selection should not fail");
-    let selection =
-        select_preferred_views_for_records(&selected_study.records, preference_order, true);
+    let selected_study = select_study_records(
+        &refined_records,
+        StudySelectionMode::MostComplete,
+        false,
+        false,
+    )
+    .expect("most-complete study selection should not fail");
+    let selection = select_preferred_views_for_records(
+        &selected_study.records,
+        preference_order,
+        PreferenceRules::default(),
+    );
     (selection, selected_study.warnings)
 }
 
 fn select_preferred_views_for_records(
     records: &[MammogramRecord],
     preference_order: PreferenceOrder,
-    deprioritize_lossy_compressed: bool,
+    preference_rules: PreferenceRules,
 ) -> PreferredViewSelection {
+    select_preferred_indices(records, preference_order, preference_rules)
+        .into_iter()
+        .map(|(view, index)| (view, index.map(|index| records[index].clone())))
+        .collect()
+}
+
+/// Returns the index of the most preferred candidate for each standard view.
+///
+/// Each candidate is compared once against the running best, so only the
+/// caller decides whether to clone the winners.
+fn select_preferred_indices<R: Borrow<MammogramRecord>>(
+    records: &[R],
+    preference_order: PreferenceOrder,
+    preference_rules: PreferenceRules,
+) -> HashMap<MammogramView, Option<usize>> {
     let mut result = HashMap::with_capacity(STANDARD_MAMMO_VIEWS.len());
 
     // Try each standard view
@@ -162,24 +630,41 @@ fn select_preferred_views_for_records(
         // Select most preferred from candidates using the specified preference order
         let selection = records
             .iter()
-            .filter(|record| is_candidate_for_view(record, standard_view))
-            .min_by(|a, b| {
-                compare_record_preference(a, b, preference_order, deprioritize_lossy_compressed)
+            .enumerate()
+            .map(|(index, record)| (index, record.borrow()))
+            .filter(|(_, record)| is_candidate_for_view(record, standard_view))
+            .min_by(|(_, a), (_, b)| {
+                compare_record_preference(a, b, preference_order, preference_rules)
             })
-            .cloned();
+            .map(|(index, _)| index);
         result.insert(*standard_view, selection);
     }
 
     result
 }
 
+/// Returns the records competing for `view`, ordered best to worst.
+pub(crate) fn rank_candidates_for_view<'a>(
+    records: &'a [MammogramRecord],
+    view: &MammogramView,
+    preference_order: PreferenceOrder,
+    preference_rules: PreferenceRules,
+) -> Vec<&'a MammogramRecord> {
+    let mut candidates: Vec<&MammogramRecord> = records
+        .iter()
+        .filter(|record| is_candidate_for_view(record, view))
+        .collect();
+    candidates.sort_by(|a, b| compare_record_preference(a, b, preference_order, preference_rules));
+    candidates
+}
+
 fn compare_record_preference(
     a: &MammogramRecord,
     b: &MammogramRecord,
     preference_order: PreferenceOrder,
-    deprioritize_lossy_compressed: bool,
+    preference_rules: PreferenceRules,
 ) -> Ordering {
-    a.preference_cmp_with_options(b, preference_order, deprioritize_lossy_compressed)
+    a.preference_cmp_with_options(b, preference_order, preference_rules)
 }
 
 /// Selects preferred inference views from a filtered collection of mammogram records
@@ -281,13 +766,14 @@ pub(crate) fn get_preferred_views_filtered_refined_with_study_mode_and_warnings(
         &filtered_records,
         study_selection_mode,
         filter_config.require_common_modality,
+        filter_config.prefer_latest_study,
     )?;
 
     // Run initial selection
     let selection = select_preferred_views_for_records(
         &selected_study.records,
         preference_order,
-        filter_config.deprioritize_lossy_compressed,
+        PreferenceRules::from(filter_config),
     );
 
     // Optionally enforce common modality
@@ -296,7 +782,7 @@ pub(crate) fn get_preferred_views_filtered_refined_with_study_mode_and_warnings(
             &selected_study.records,
             selection,
             preference_order,
-            filter_config.deprioritize_lossy_compressed,
+            PreferenceRules::from(filter_config),
         )
     } else {
         selection
@@ -305,6 +791,100 @@ pub(crate) fn get_preferred_views_filtered_refined_with_study_mode_and_warnings(
     Ok((selection, selected_study.warnings))
 }
 
+/// Returns the refined, filtered records of the study that selection would consider.
+pub(crate) fn selection_study_records(
+    records: &[MammogramRecord],
+    filter_config: &FilterConfig,
+    study_selection_mode: StudySelectionMode,
+) -> Result<Vec<MammogramRecord>> {
+    let refined_records = refine_dbt_object_classification(records);
+    let filtered_records = apply_filters(&refined_records, filter_config);
+    let selected_study = select_study_records(
+        &filtered_records,
+        study_selection_mode,
+        filter_config.require_common_modality,
+        filter_config.prefer_latest_study,
+    )?;
+    Ok(selected_study.records)
+}
+
+/// Explains why [`get_preferred_views_filtered`] leaves `view` empty
+///
+/// Uses the default preference order; see [`explain_missing_with_order`].
+///
+/// # Example
+///
+/// ```
+/// use mammocat_core::{explain_missing, FilterConfig, MissingReason, STANDARD_MAMMO_VIEWS};
+///
+/// # let records = vec![]; // Would normally load from files
+/// let reason = explain_missing(&records, &FilterConfig::default(), &STANDARD_MAMMO_VIEWS[0]);
+/// assert_eq!(reason, MissingReason::NoCandidates);
+/// ```
+pub fn explain_missing(
+    records: &[MammogramRecord],
+    filter_config: &FilterConfig,
+    view: &MammogramView,
+) -> MissingReason {
+    explain_missing_with_order(records, filter_config, PreferenceOrder::Default, view)
+}
+
+/// Explains why [`get_preferred_views_filtered`] leaves `view` empty for a preference order
+///
+/// Replays the selection stages with the most-complete-study mode and
+/// reports the first stage that removed every candidate for `view`:
+/// filtering, study selection, then common-modality enforcement.
+pub fn explain_missing_with_order(
+    records: &[MammogramRecord],
+    filter_config: &FilterConfig,
+    preference_order: PreferenceOrder,
+    view: &MammogramView,
+) -> MissingReason {
+    let refined_records = refine_dbt_object_classification(records);
+    let count_candidates = |records: &[MammogramRecord]| {
+        records
+            .iter()
+            .filter(|record| is_candidate_for_view(record, view))
+            .count()
+    };
+
+    let candidate_count = count_candidates(&refined_records);
+    if candidate_count == 0 {
+        return MissingReason::NoCandidates;
+    }
+
+    let filtered_records = apply_filters(&refined_records, filter_config);
+    let filtered_count = count_candidates(&filtered_records);
+    if filtered_count == 0 {
+        return MissingReason::AllFiltered(candidate_count);
+    }
+
+    let study_records = select_study_records(
+        &filtered_records,
+        StudySelectionMode::MostComplete,
+        filter_config.require_common_modality,
+        filter_config.prefer_latest_study,
+    )
+    .expect("most-complete study selection should not fail")
+    .records;
+    let study_count = count_candidates(&study_records);
+    if study_count == 0 {
+        return MissingReason::OtherStudySelected(filtered_count);
+    }
+
+    let (selection, _) = get_preferred_views_filtered_refined_with_study_mode_and_warnings(
+        &refined_records,
+        filter_config,
+        preference_order,
+        StudySelectionMode::MostComplete,
+    )
+    .expect("most-complete study selection should not fail");
+    match selection.get(view) {
+        Some(Some(_)) => MissingReason::NotMissing,
+        _ => MissingReason::CommonModalityConflict(study_count),
+    }
+}
+
 /// Refines ambiguous single-file DBT classifications using collection context.
 ///
 /// Single-file extraction intentionally reports Fuji-like split-slice/SYN2D
@@ -322,7 +902,19 @@ pub fn refine_dbt_object_classification(records: &[MammogramRecord]) -> Vec<Mamm
 pub fn refine_dbt_object_classification_with_diagnostics(
     records: &[MammogramRecord],
 ) -> (Vec<MammogramRecord>, Vec<DbtRefinementDiagnostic>) {
-    let mut refined_records = records.to_vec();
+    let (refined_records, diagnostics) = refine_dbt_classification_borrowed(records);
+    (
+        refined_records.into_iter().map(Cow::into_owned).collect(),
+        diagnostics,
+    )
+}
+
+/// Refines DBT classifications, copying only the records that change
+fn refine_dbt_classification_borrowed(
+    records: &[MammogramRecord],
+) -> (Vec<Cow<'_, MammogramRecord>>, Vec<DbtRefinementDiagnostic>) {
+    let mut refined_records: Vec<Cow<'_, MammogramRecord>> =
+        records.iter().map(Cow::Borrowed).collect();
     let mut diagnostics = Vec::new();
     let series_infos = build_series_infos(records);
     let split_slice_series = split_slice_series_keys_from_cardinality(&series_infos);
@@ -337,7 +929,7 @@ pub fn refine_dbt_object_classification_with_diagnostics(
 
         if split_slice_series.contains(&series_key) {
             refine_record_with_diagnostic(
-                &mut refined_records[index],
+                refined_records[index].to_mut(),
                 MammogramType::Tomo,
                 DbtObjectKind::Slice,
                 DbtRefinementReason::SplitSliceSeriesCardinality,
@@ -444,7 +1036,10 @@ fn non_empty(value: Option<&str>) -> Option<&str> {
 /// # Returns
 ///
 /// Vector of records that pass all filters
-fn apply_filters(records: &[MammogramRecord], config: &FilterConfig) -> Vec<MammogramRecord> {
+pub(crate) fn apply_filters(
+    records: &[MammogramRecord],
+    config: &FilterConfig,
+) -> Vec<MammogramRecord> {
     records
         .iter()
         .filter(|record| {
@@ -472,16 +1067,66 @@ fn apply_filters(records: &[MammogramRecord], config: &FilterConfig) -> Vec<Mamm
                 return false;
             }
 
+            // Filter: Exclude spot compression and magnification views
+            if config.exclude_spot_mag && record.is_spot_or_mag() {
+                return false;
+            }
+
             // Filter: Exclude FOR PROCESSING
             if config.exclude_for_processing && record.metadata.is_for_processing {
                 return false;
             }
 
+            // Filter: Require explicit FOR PRESENTATION
+            if config.require_for_presentation
+                && !record.metadata.presentation_intent.is_for_presentation()
+            {
+                return false;
+            }
+
+            // Filter: Minimum number of frames
+            if config
+                .min_frames
+                .is_some_and(|min_frames| record.metadata.number_of_frames < min_frames)
+            {
+                return false;
+            }
+
+            // Filter: Manufacturer whitelist and model blacklist
+            if !config.manufacturer_allowed(record.metadata.manufacturer.as_deref())
+                || config.model_excluded(record.metadata.model.as_deref())
+            {
+                return false;
+            }
+
+            // Filter: Phantom / QC PatientName patterns
+            if config.patient_name_excluded(record.patient_name.as_deref()) {
+                return false;
+            }
+
+            // Filter: Minimum image area (unknown dimensions fail)
+            if config
+                .min_image_area
+                .is_some_and(|min_area| record.image_area().is_none_or(|area| area < min_area))
+            {
+                return false;
+            }
+
             // Filter: Exclude secondary capture
             if config.exclude_secondary_capture && record.metadata.is_secondary_capture {
                 return false;
             }
 
+            // Filter: Exclude scout/localizer frames
+            if config.exclude_localizers && record.metadata.is_localizer {
+                return false;
+            }
+
+            // Filter: Exclude specimen images
+            if config.exclude_specimens && record.metadata.is_specimen {
+                return false;
+            }
+
             // Filter: Exclude non-MG modality
             if config.exclude_non_mg_modality {
                 if let Some(modality) = &record.metadata.modality {
@@ -509,46 +1154,82 @@ fn select_study_records(
     records: &[MammogramRecord],
     study_selection_mode: StudySelectionMode,
     require_common_modality: bool,
+    prefer_latest_study: bool,
 ) -> Result<SelectedStudyRecords> {
-    let candidate_records: Vec<MammogramRecord> = records
+    let selected = select_study_indices(
+        records,
+        study_selection_mode,
+        require_common_modality,
+        prefer_latest_study,
+    )?;
+    Ok(SelectedStudyRecords {
+        records: selected
+            .source_indices
+            .iter()
+            .map(|&index| records[index].clone())
+            .collect(),
+        warnings: selected.warnings,
+    })
+}
+
+fn select_study_indices<R: Borrow<MammogramRecord>>(
+    records: &[R],
+    study_selection_mode: StudySelectionMode,
+    require_common_modality: bool,
+    prefer_latest_study: bool,
+) -> Result<SelectedStudyIndices> {
+    let candidate_indices: Vec<usize> = records
         .iter()
-        .filter(|record| is_candidate_for_any_standard_view(record))
-        .cloned()
+        .enumerate()
+        .filter(|(_, record)| is_candidate_for_any_standard_view((*record).borrow()))
+        .map(|(index, _)| index)
         .collect();
 
-    if candidate_records.is_empty() {
-        return Ok(SelectedStudyRecords {
-            records: Vec::new(),
+    if candidate_indices.is_empty() {
+        return Ok(SelectedStudyIndices {
+            source_indices: Vec::new(),
             warnings: Vec::new(),
         });
     }
 
     match study_selection_mode {
         StudySelectionMode::MostComplete => {
-            let mut groups = build_study_groups(&candidate_records, require_common_modality);
-            groups.sort_by(compare_study_groups);
+            let mut groups =
+                build_study_groups(records, &candidate_indices, require_common_modality);
+            groups.sort_by(|left, right| compare_study_groups(left, right, prefer_latest_study));
             let selected_group = groups
                 .first()
                 .expect("candidate records always form at least one study group");
-            Ok(SelectedStudyRecords {
-                records: selected_group.records.clone(),
+            Ok(SelectedStudyIndices {
+                source_indices: selected_group.source_indices.clone(),
                 warnings: mixed_study_warnings(&groups, selected_group),
             })
         }
         StudySelectionMode::StrictSingleStudy => {
-            let records = select_strict_study_records(candidate_records)?;
-            Ok(SelectedStudyRecords {
-                records,
+            // Strict selection succeeds only when every candidate shares one
+            // study, so the selected records are the candidates in order.
+            check_strict_single_study(records, &candidate_indices)?;
+            Ok(SelectedStudyIndices {
+                source_indices: candidate_indices,
                 warnings: Vec::new(),
             })
         }
     }
 }
 
-fn select_strict_study_records(
-    candidate_records: Vec<MammogramRecord>,
-) -> Result<Vec<MammogramRecord>> {
-    let missing_uid_paths = missing_study_uid_paths(&candidate_records);
+fn check_strict_single_study<R: Borrow<MammogramRecord>>(
+    records: &[R],
+    candidate_indices: &[usize],
+) -> Result<()> {
+    let candidates = || {
+        candidate_indices
+            .iter()
+            .map(|&index| records[index].borrow())
+    };
+    let missing_uid_paths: Vec<String> = candidates()
+        .filter(|record| is_missing_study_uid(record))
+        .map(|record| record.file_path.display().to_string())
+        .collect();
     if !missing_uid_paths.is_empty() {
         return Err(MammocatError::SelectionError(format!(
             "strict study selection requires StudyInstanceUID on every usable candidate; missing for: {}",
@@ -556,41 +1237,17 @@ fn select_strict_study_records(
         )));
     }
 
-    let records_by_uid = group_records_by_study_uid(candidate_records);
-    if records_by_uid.len() > 1 {
-        let study_uids: Vec<String> = records_by_uid.keys().cloned().collect();
+    let study_uids: BTreeSet<&str> = candidates()
+        .filter_map(|record| record.study_instance_uid.as_deref())
+        .collect();
+    if study_uids.len() > 1 {
         return Err(MammocatError::SelectionError(format!(
             "strict study selection requires exactly one StudyInstanceUID; found: {}",
-            study_uids.join(", ")
+            study_uids.into_iter().collect::<Vec<_>>().join(", ")
         )));
     }
 
-    Ok(records_by_uid.into_values().next().unwrap_or_default())
-}
-
-fn missing_study_uid_paths(records: &[MammogramRecord]) -> Vec<String> {
-    records
-        .iter()
-        .filter(|record| is_missing_study_uid(record))
-        .map(|record| record.file_path.display().to_string())
-        .collect()
-}
-
-fn group_records_by_study_uid(
-    records: Vec<MammogramRecord>,
-) -> BTreeMap<String, Vec<MammogramRecord>> {
-    let mut records_by_uid: BTreeMap<String, Vec<MammogramRecord>> = BTreeMap::new();
-
-    for record in records {
-        if let Some(study_uid) = &record.study_instance_uid {
-            records_by_uid
-                .entry(study_uid.clone())
-                .or_default()
-                .push(record);
-        }
-    }
-
-    records_by_uid
+    Ok(())
 }
 
 fn mixed_study_warnings(
@@ -629,49 +1286,56 @@ fn study_group_label(group: &StudyGroup) -> String {
         })
 }
 
-fn build_study_groups(
-    records: &[MammogramRecord],
+fn build_study_groups<R: Borrow<MammogramRecord>>(
+    records: &[R],
+    candidate_indices: &[usize],
     require_common_modality: bool,
 ) -> Vec<StudyGroup> {
-    let mut records_by_uid: BTreeMap<String, Vec<MammogramRecord>> = BTreeMap::new();
+    let mut indices_by_uid: BTreeMap<String, Vec<usize>> = BTreeMap::new();
     let mut unknown_groups = Vec::new();
 
-    for record in records {
+    for &index in candidate_indices {
+        let record = records[index].borrow();
         if is_missing_study_uid(record) {
-            unknown_groups.push(vec![record.clone()]);
+            unknown_groups.push(vec![index]);
         } else if let Some(study_uid) = &record.study_instance_uid {
-            records_by_uid
+            indices_by_uid
                 .entry(study_uid.clone())
                 .or_default()
-                .push(record.clone());
+                .push(index);
         }
     }
 
-    let mut groups: Vec<StudyGroup> = records_by_uid
+    let mut groups: Vec<StudyGroup> = indices_by_uid
         .into_iter()
-        .map(|(study_uid, records)| {
-            make_study_group(Some(study_uid), records, require_common_modality)
+        .map(|(study_uid, indices)| {
+            make_study_group(Some(study_uid), records, indices, require_common_modality)
         })
         .collect();
 
     groups.extend(
         unknown_groups
             .into_iter()
-            .map(|records| make_study_group(None, records, require_common_modality)),
+            .map(|indices| make_study_group(None, records, indices, require_common_modality)),
     );
 
     groups
 }
 
-fn make_study_group(
+fn make_study_group<R: Borrow<MammogramRecord>>(
     study_instance_uid: Option<String>,
-    records: Vec<MammogramRecord>,
+    records: &[R],
+    source_indices: Vec<usize>,
     require_common_modality: bool,
 ) -> StudyGroup {
+    let group_records: Vec<&MammogramRecord> = source_indices
+        .iter()
+        .map(|&index| records[index].borrow())
+        .collect();
     let (standard_slot_count, candidate_slot_count) =
-        count_study_slots(&records, require_common_modality);
+        count_study_slots(&group_records, require_common_modality);
     let unknown_sort_key = study_instance_uid.is_none().then(|| {
-        records
+        group_records
             .iter()
             .map(|record| {
                 (
@@ -683,19 +1347,42 @@ fn make_study_group(
             .unwrap_or_default()
     });
 
+    let latest_study_date = group_records
+        .iter()
+        .filter_map(|record| record.study_date)
+        .max();
+
     StudyGroup {
         study_instance_uid,
-        records,
+        source_indices,
         standard_slot_count,
         candidate_slot_count,
+        latest_study_date,
         unknown_sort_key,
     }
 }
 
-fn compare_study_groups(left: &StudyGroup, right: &StudyGroup) -> Ordering {
-    right
-        .standard_slot_count
-        .cmp(&left.standard_slot_count)
+fn compare_study_groups(
+    left: &StudyGroup,
+    right: &StudyGroup,
+    prefer_latest_study: bool,
+) -> Ordering {
+    let by_date = || {
+        if !prefer_latest_study {
+            return Ordering::Equal;
+        }
+        // Dated studies sort before undated ones, newest first
+        match (left.latest_study_date, right.latest_study_date) {
+            (Some(left_date), Some(right_date)) => right_date.cmp(&left_date),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    };
+    // With `prefer_latest_study`, the newest study wins even when an older
+    // one is more complete; completeness then ranks studies of the same date
+    by_date()
+        .then_with(|| right.standard_slot_count.cmp(&left.standard_slot_count))
         .then_with(|| right.candidate_slot_count.cmp(&left.candidate_slot_count))
         .then_with(
             || match (&left.study_instance_uid, &right.study_instance_uid) {
@@ -714,7 +1401,10 @@ fn is_missing_study_uid(record: &MammogramRecord) -> bool {
     }
 }
 
-fn count_study_slots(records: &[MammogramRecord], require_common_modality: bool) -> (usize, usize) {
+fn count_study_slots(
+    records: &[&MammogramRecord],
+    require_common_modality: bool,
+) -> (usize, usize) {
     if require_common_modality {
         return count_common_modality_study_slots(records);
     }
@@ -725,16 +1415,16 @@ fn count_study_slots(records: &[MammogramRecord], require_common_modality: bool)
     )
 }
 
-fn count_common_modality_study_slots(records: &[MammogramRecord]) -> (usize, usize) {
-    let records_2d: Vec<MammogramRecord> = records
+fn count_common_modality_study_slots(records: &[&MammogramRecord]) -> (usize, usize) {
+    let records_2d: Vec<&MammogramRecord> = records
         .iter()
+        .copied()
         .filter(|record| record.metadata.mammogram_type.is_2d_group())
-        .cloned()
         .collect();
-    let records_dbt: Vec<MammogramRecord> = records
+    let records_dbt: Vec<&MammogramRecord> = records
         .iter()
+        .copied()
         .filter(|record| record.metadata.mammogram_type.is_dbt_group())
-        .cloned()
         .collect();
 
     [
@@ -752,7 +1442,7 @@ fn count_common_modality_study_slots(records: &[MammogramRecord]) -> (usize, usi
     .unwrap_or_default()
 }
 
-fn count_standard_slots(records: &[MammogramRecord]) -> usize {
+fn count_standard_slots(records: &[&MammogramRecord]) -> usize {
     STANDARD_MAMMO_VIEWS
         .iter()
         .filter(|standard_view| {
@@ -766,7 +1456,7 @@ fn count_standard_slots(records: &[MammogramRecord]) -> usize {
         .count()
 }
 
-fn count_candidate_slots(records: &[MammogramRecord]) -> usize {
+fn count_candidate_slots(records: &[&MammogramRecord]) -> usize {
     STANDARD_MAMMO_VIEWS
         .iter()
         .filter(|standard_view| {
@@ -799,7 +1489,7 @@ fn enforce_common_modality(
         filtered_records,
         initial_selection,
         preference_order,
-        true,
+        PreferenceRules::default(),
     )
 }
 
@@ -807,7 +1497,7 @@ fn enforce_common_modality_with_options(
     filtered_records: &[MammogramRecord],
     initial_selection: HashMap<MammogramView, Option<MammogramRecord>>,
     preference_order: PreferenceOrder,
-    deprioritize_lossy_compressed: bool,
+    preference_rules: PreferenceRules,
 ) -> HashMap<MammogramView, Option<MammogramRecord>> {
     // If already single-modality, return as-is
     if is_single_modality(&initial_selection) {
@@ -827,16 +1517,10 @@ fn enforce_common_modality_with_options(
         .cloned()
         .collect();
 
-    let selection_2d = select_preferred_views_for_records(
-        &records_2d,
-        preference_order,
-        deprioritize_lossy_compressed,
-    );
-    let selection_dbt = select_preferred_views_for_records(
-        &records_dbt,
-        preference_order,
-        deprioritize_lossy_compressed,
-    );
+    let selection_2d =
+        select_preferred_views_for_records(&records_2d, preference_order, preference_rules);
+    let selection_dbt =
+        select_preferred_views_for_records(&records_dbt, preference_order, preference_rules);
 
     let coverage_2d = count_coverage(&selection_2d);
     let coverage_dbt = count_coverage(&selection_dbt);
@@ -846,7 +1530,7 @@ fn enforce_common_modality_with_options(
     } else if coverage_dbt > coverage_2d {
         selection_dbt
     } else {
-        if deprioritize_lossy_compressed {
+        if preference_rules.deprioritize_lossy_compressed {
             let lossy_2d = count_lossy(&selection_2d);
             let lossy_dbt = count_lossy(&selection_dbt);
 
@@ -935,8 +1619,10 @@ fn total_preference_score(
 fn is_candidate_for_view(record: &MammogramRecord, target: &MammogramView) -> bool {
     let candidate_view = record.metadata.mammogram_view();
 
-    // Laterality must match
-    if candidate_view.laterality != target.laterality {
+    // Laterality must match; a bilateral acquisition covers either breast
+    let bilateral_covers_target =
+        candidate_view.laterality == Laterality::Bilateral && target.laterality.is_unilateral();
+    if candidate_view.laterality != target.laterality && !bilateral_covers_target {
         return false;
     }
 
@@ -962,7 +1648,7 @@ mod tests {
     use crate::error::MammocatError;
     use crate::types::{
         DbtObjectKind, ImageType, Laterality, MammogramType, MammographyViewModifier,
-        PreferenceOrder, ViewPosition,
+        PhotometricInterpretation, PreferenceOrder, PresentationIntent, SopClassKind, ViewPosition,
     };
     use std::path::PathBuf;
 
@@ -1006,23 +1692,38 @@ mod tests {
                     None,
                 ),
                 is_for_processing: false,
+                presentation_intent: PresentationIntent::Unknown,
                 has_implant: false,
                 manufacturer: None,
                 model: None,
                 number_of_frames: 1,
                 pixel_spacing: None,
+                imager_pixel_spacing: None,
+                photometric: PhotometricInterpretation::Monochrome2,
+                rescale_slope: None,
+                rescale_intercept: None,
+                body_part_thickness_mm: None,
+                compression_force_n: None,
                 concatenation_uid: None,
                 sop_instance_uid_of_concatenation_source: None,
                 is_secondary_capture: false,
+                is_localizer: false,
+                is_specimen: false,
                 modality: Some("MG".to_string()),
+                character_set: None,
                 transfer_syntax_uid: Some("1.2.840.10008.1.2.1".to_string()),
                 transfer_syntax_name: Some("Explicit VR Little Endian".to_string()),
                 compression_type: Some("uncompressed".to_string()),
             },
             rows: Some(2560),
             columns: Some(3328),
+            bits_stored: None,
             transfer_syntax_uid: None,
             is_lossy_compressed: false,
+            study_date: None,
+            patient_id: None,
+            patient_name: None,
+            sop_class: SopClassKind::MammoImage,
             study_instance_uid: study_uid.map(str::to_string),
             series_instance_uid: study_uid.map(|uid| format!("{uid}.series")),
             sop_instance_uid: Some(format!(
@@ -1223,45 +1924,426 @@ mod tests {
     }
 
     #[test]
-    fn test_get_preferred_views_missing() {
-        // Only create 3 views (missing R-CC)
+    fn test_get_preferred_views_missing() {
+        // Only create 3 views (missing R-CC)
+        let records = vec![
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm),
+            make_test_record(Laterality::Right, ViewPosition::Mlo, MammogramType::Ffdm),
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm),
+        ];
+
+        let selections = get_preferred_views(&records);
+
+        // Should have all 4 standard views in result
+        assert_eq!(selections.len(), 4);
+
+        // First 3 should have selections
+        assert!(selections[&MammogramView::new(Laterality::Left, ViewPosition::Mlo)].is_some());
+        assert!(selections[&MammogramView::new(Laterality::Right, ViewPosition::Mlo)].is_some());
+        assert!(selections[&MammogramView::new(Laterality::Left, ViewPosition::Cc)].is_some());
+
+        // R-CC should be None
+        assert!(selections[&MammogramView::new(Laterality::Right, ViewPosition::Cc)].is_none());
+    }
+
+    #[test]
+    fn test_get_preferred_views_type_preference() {
+        // Create multiple of same view with different types
+        let records = vec![
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm),
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Tomo),
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Synth),
+        ];
+
+        let selections = get_preferred_views(&records);
+
+        // Should select FFDM (most preferred with default ordering)
+        let selected = selections[&MammogramView::new(Laterality::Left, ViewPosition::Mlo)]
+            .as_ref()
+            .unwrap();
+
+        assert_eq!(selected.metadata.mammogram_type, MammogramType::Ffdm);
+    }
+
+    #[test]
+    fn test_by_laterality_splits_left_and_right_slots() {
+        let records = vec![
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm),
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm),
+            make_test_record(Laterality::Right, ViewPosition::Mlo, MammogramType::Ffdm),
+        ];
+        let selections = get_preferred_views(&records);
+
+        let grouped = by_laterality(&selections);
+
+        assert_eq!(grouped.len(), 2);
+        let left = &grouped[&Laterality::Left];
+        let right = &grouped[&Laterality::Right];
+        assert_eq!(left.len(), 2);
+        assert_eq!(right.len(), 2);
+        assert_eq!(
+            left[0].as_ref().unwrap().metadata.view_position,
+            ViewPosition::Cc
+        );
+        assert_eq!(
+            left[1].as_ref().unwrap().metadata.view_position,
+            ViewPosition::Mlo
+        );
+        assert!(right[0].is_none());
+        assert_eq!(
+            right[1].as_ref().unwrap().metadata.laterality,
+            Laterality::Right
+        );
+    }
+
+    #[test]
+    fn test_has_both_standard_views_per_laterality() {
+        let records = vec![
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm),
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm),
+            make_test_record(Laterality::Right, ViewPosition::Mlo, MammogramType::Ffdm),
+        ];
+
+        assert!(has_both_standard_views(&records, Laterality::Left));
+        assert!(!has_both_standard_views(&records, Laterality::Right));
+        assert!(!has_both_standard_views(&[], Laterality::Left));
+    }
+
+    #[test]
+    fn test_get_all_views_surfaces_non_standard_views() {
+        let xccl = make_test_record(Laterality::Left, ViewPosition::Xccl, MammogramType::Ffdm);
+        let mut axillary_tail =
+            make_test_record(Laterality::Left, ViewPosition::Ml, MammogramType::Ffdm);
+        axillary_tail
+            .metadata
+            .view_modifiers
+            .insert(MammographyViewModifier::AxillaryTail);
+        let records = vec![xccl.clone(), axillary_tail.clone()];
+
+        let all_views = get_all_views(&records, PreferenceOrder::Default);
+        let standard = get_preferred_views(&records);
+
+        let lxccl = MammogramView::new(Laterality::Left, ViewPosition::Xccl);
+        let lml = MammogramView::new(Laterality::Left, ViewPosition::Ml);
+        assert_eq!(
+            all_views[&lxccl].as_ref().unwrap().sop_instance_uid,
+            xccl.sop_instance_uid
+        );
+        assert_eq!(
+            all_views[&lml].as_ref().unwrap().sop_instance_uid,
+            axillary_tail.sop_instance_uid
+        );
+        assert_eq!(all_views.len(), STANDARD_MAMMO_VIEWS.len() + 2);
+        assert!(STANDARD_MAMMO_VIEWS
+            .iter()
+            .all(|view| all_views[view].is_none()));
+        assert!(!standard.contains_key(&lxccl));
+    }
+
+    #[test]
+    fn test_get_all_views_picks_best_per_group_and_skips_unknown() {
+        let records = vec![
+            make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Sfm),
+            make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm),
+            make_test_record(
+                Laterality::Right,
+                ViewPosition::Unknown,
+                MammogramType::Ffdm,
+            ),
+            make_test_record(Laterality::Unknown, ViewPosition::Mlo, MammogramType::Ffdm),
+        ];
+
+        let all_views = get_all_views(&records, PreferenceOrder::Default);
+
+        assert_eq!(all_views.len(), STANDARD_MAMMO_VIEWS.len());
+        let rcc = all_views[&MammogramView::new(Laterality::Right, ViewPosition::Cc)]
+            .as_ref()
+            .unwrap();
+        assert_eq!(rcc.metadata.mammogram_type, MammogramType::Ffdm);
+    }
+
+    #[test]
+    fn test_get_preferred_views_normalized_uses_xccl_as_cc_fallback() {
+        let xccl = make_test_record(Laterality::Left, ViewPosition::Xccl, MammogramType::Ffdm);
+        let sio = make_test_record(Laterality::Left, ViewPosition::Sio, MammogramType::Ffdm);
+        let records = vec![
+            xccl.clone(),
+            sio.clone(),
+            make_test_record(Laterality::Right, ViewPosition::Xccl, MammogramType::Ffdm),
+            make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Sfm),
+        ];
+
+        let normalized = get_preferred_views_normalized(&records, PreferenceOrder::Default);
+
+        let lcc = normalized[&MammogramView::new(Laterality::Left, ViewPosition::Cc)]
+            .as_ref()
+            .unwrap();
+        assert_eq!(lcc.sop_instance_uid, xccl.sop_instance_uid);
+        assert_eq!(lcc.metadata.view_position, ViewPosition::Xccl);
+
+        let rcc = normalized[&MammogramView::new(Laterality::Right, ViewPosition::Cc)]
+            .as_ref()
+            .unwrap();
+        assert_eq!(rcc.metadata.view_position, ViewPosition::Cc);
+
+        let lsio = MammogramView::new(Laterality::Left, ViewPosition::Sio);
+        assert_eq!(
+            normalized[&lsio].as_ref().unwrap().sop_instance_uid,
+            sio.sop_instance_uid
+        );
+        assert!(!normalized.contains_key(&MammogramView::new(Laterality::Left, ViewPosition::Xccl)));
+        assert_eq!(normalized.len(), STANDARD_MAMMO_VIEWS.len() + 1);
+    }
+
+    #[test]
+    fn test_study_completeness_scores_standard_view_coverage() {
+        let complete: PreferredViewSelection = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                (
+                    *view,
+                    Some(make_test_record(
+                        view.laterality,
+                        view.view,
+                        MammogramType::Ffdm,
+                    )),
+                )
+            })
+            .collect();
+        assert_eq!(study_completeness(&complete), 1.0);
+        assert!(missing_views(&complete).is_empty());
+
+        let mut three_view = complete.clone();
+        three_view.insert(STANDARD_MAMMO_VIEWS[2], None);
+        assert_eq!(study_completeness(&three_view), 0.75);
+        assert_eq!(missing_views(&three_view), vec![STANDARD_MAMMO_VIEWS[2]]);
+
+        let empty = PreferredViewSelection::new();
+        assert_eq!(study_completeness(&empty), 0.0);
+        assert_eq!(missing_views(&empty), STANDARD_MAMMO_VIEWS.to_vec());
+    }
+
+    #[test]
+    fn test_get_preferred_views_explained_reports_bit_depth_tiebreak() {
+        let mut twelve_bit =
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        twelve_bit.file_path = PathBuf::from("twelve-bit.dcm");
+        twelve_bit.sop_instance_uid = Some("2".to_string());
+        twelve_bit.bits_stored = Some(12);
+        let mut eight_bit = twelve_bit.clone();
+        eight_bit.file_path = PathBuf::from("eight-bit.dcm");
+        eight_bit.sop_instance_uid = Some("1".to_string());
+        eight_bit.bits_stored = Some(8);
+
+        let explained =
+            get_preferred_views_explained(&[eight_bit, twelve_bit], PreferenceOrder::Default);
+
+        let (lcc, reason) = explained[&MammogramView::new(Laterality::Left, ViewPosition::Cc)]
+            .as_ref()
+            .unwrap();
+        assert_eq!(lcc.file_path, PathBuf::from("twelve-bit.dcm"));
+        assert_eq!(*reason, SelectionReason::HigherBitDepth);
+    }
+
+    #[test]
+    fn test_get_preferred_views_explained_reports_resolution_tiebreak() {
+        let mut high_resolution =
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        high_resolution.file_path = PathBuf::from("high-resolution.dcm");
+        high_resolution.sop_instance_uid = Some("2".to_string());
+        let mut low_resolution = high_resolution.clone();
+        low_resolution.file_path = PathBuf::from("low-resolution.dcm");
+        low_resolution.sop_instance_uid = Some("1".to_string());
+        low_resolution.rows = Some(2000);
+        low_resolution.columns = Some(2000);
+        let records = vec![
+            low_resolution,
+            high_resolution,
+            make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm),
+        ];
+
+        let explained = get_preferred_views_explained(&records, PreferenceOrder::Default);
+
+        let (lcc, reason) = explained[&MammogramView::new(Laterality::Left, ViewPosition::Cc)]
+            .as_ref()
+            .unwrap();
+        assert_eq!(lcc.file_path, PathBuf::from("high-resolution.dcm"));
+        assert_eq!(*reason, SelectionReason::HigherResolution);
+
+        let (_, reason) = explained[&MammogramView::new(Laterality::Right, ViewPosition::Cc)]
+            .as_ref()
+            .unwrap();
+        assert_eq!(*reason, SelectionReason::OnlyCandidate);
+        assert!(explained[&MammogramView::new(Laterality::Left, ViewPosition::Mlo)].is_none());
+    }
+
+    #[test]
+    fn test_get_preferred_views_explained_matches_preferred_selection() {
+        let records = vec![
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Sfm),
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm),
+            make_test_record(Laterality::Right, ViewPosition::Mlo, MammogramType::Ffdm),
+        ];
+
+        let explained = get_preferred_views_explained(&records, PreferenceOrder::Default);
+        let preferred = get_preferred_views_with_order(&records, PreferenceOrder::Default);
+
+        for view in STANDARD_MAMMO_VIEWS.iter() {
+            assert_eq!(
+                explained[view]
+                    .as_ref()
+                    .map(|(record, _)| &record.sop_instance_uid),
+                preferred[view]
+                    .as_ref()
+                    .map(|record| &record.sop_instance_uid)
+            );
+        }
+        let (_, reason) = explained[&MammogramView::new(Laterality::Left, ViewPosition::Cc)]
+            .as_ref()
+            .unwrap();
+        assert_eq!(*reason, SelectionReason::TypePreference);
+    }
+
+    #[test]
+    fn test_bilateral_cc_fills_both_cc_views() {
+        let bilateral =
+            make_test_record(Laterality::Bilateral, ViewPosition::Cc, MammogramType::Ffdm);
+        let records = vec![bilateral.clone()];
+
+        let selections = get_preferred_views(&records);
+
+        for laterality in [Laterality::Left, Laterality::Right] {
+            let selected = selections[&MammogramView::new(laterality, ViewPosition::Cc)]
+                .as_ref()
+                .unwrap();
+            assert_eq!(selected.sop_instance_uid, bilateral.sop_instance_uid);
+            assert!(selections[&MammogramView::new(laterality, ViewPosition::Mlo)].is_none());
+        }
+    }
+
+    #[test]
+    fn test_unilateral_record_preferred_over_bilateral() {
+        let mut bilateral =
+            make_test_record(Laterality::Bilateral, ViewPosition::Cc, MammogramType::Ffdm);
+        bilateral.rows = Some(4096);
+        bilateral.columns = Some(4096);
+        let left = make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        let records = vec![bilateral.clone(), left.clone()];
+
+        let explained = get_preferred_views_explained(&records, PreferenceOrder::Default);
+
+        let (lcc, reason) = explained[&MammogramView::new(Laterality::Left, ViewPosition::Cc)]
+            .as_ref()
+            .unwrap();
+        assert_eq!(lcc.sop_instance_uid, left.sop_instance_uid);
+        assert_eq!(*reason, SelectionReason::Unilateral);
+        let (rcc, _) = explained[&MammogramView::new(Laterality::Right, ViewPosition::Cc)]
+            .as_ref()
+            .unwrap();
+        assert_eq!(rcc.sop_instance_uid, bilateral.sop_instance_uid);
+    }
+
+    #[test]
+    fn test_get_preferred_views_ordered_follows_standard_view_order() {
         let records = vec![
+            make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm),
             make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm),
-            make_test_record(Laterality::Right, ViewPosition::Mlo, MammogramType::Ffdm),
             make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm),
         ];
 
-        let selections = get_preferred_views(&records);
-
-        // Should have all 4 standard views in result
-        assert_eq!(selections.len(), 4);
+        let ordered = get_preferred_views_ordered(&records);
 
-        // First 3 should have selections
-        assert!(selections[&MammogramView::new(Laterality::Left, ViewPosition::Mlo)].is_some());
-        assert!(selections[&MammogramView::new(Laterality::Right, ViewPosition::Mlo)].is_some());
-        assert!(selections[&MammogramView::new(Laterality::Left, ViewPosition::Cc)].is_some());
+        let views: Vec<MammogramView> = ordered.iter().map(|(view, _)| *view).collect();
+        assert_eq!(views, STANDARD_MAMMO_VIEWS.to_vec());
+        let selected: Vec<bool> = ordered.iter().map(|(_, record)| record.is_some()).collect();
+        assert_eq!(selected, vec![true, false, true, true]);
 
-        // R-CC should be None
-        assert!(selections[&MammogramView::new(Laterality::Right, ViewPosition::Cc)].is_none());
+        let selection = get_preferred_views(&records);
+        let borrowed: Vec<MammogramView> = ordered_selection(&selection)
+            .iter()
+            .map(|(view, _)| *view)
+            .collect();
+        assert_eq!(borrowed, views);
     }
 
     #[test]
-    fn test_get_preferred_views_type_preference() {
-        // Create multiple of same view with different types
+    fn test_get_ranked_views_first_matches_preferred_selection() {
         let records = vec![
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Sfm),
             make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm),
-            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Tomo),
             make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Synth),
+            make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm),
         ];
 
-        let selections = get_preferred_views(&records);
+        for order in [PreferenceOrder::Default, PreferenceOrder::TomoFirst] {
+            let ranked = get_ranked_views(&records, order);
+            let selections = get_preferred_views_with_order(&records, order);
+
+            assert_eq!(ranked.len(), STANDARD_MAMMO_VIEWS.len());
+            for view in STANDARD_MAMMO_VIEWS.iter() {
+                assert_eq!(
+                    ranked[view].first().map(|record| &record.sop_instance_uid),
+                    selections[view]
+                        .as_ref()
+                        .map(|record| &record.sop_instance_uid)
+                );
+            }
+        }
 
-        // Should select FFDM (most preferred with default ordering)
-        let selected = selections[&MammogramView::new(Laterality::Left, ViewPosition::Mlo)]
-            .as_ref()
-            .unwrap();
+        let ranked = get_ranked_views(&records, PreferenceOrder::Default);
+        let lmlo = &ranked[&MammogramView::new(Laterality::Left, ViewPosition::Mlo)];
+        let types: Vec<_> = lmlo
+            .iter()
+            .map(|record| record.metadata.mammogram_type)
+            .collect();
+        assert_eq!(lmlo.len(), 3);
+        assert_eq!(
+            types,
+            vec![
+                MammogramType::Ffdm,
+                MammogramType::Synth,
+                MammogramType::Sfm
+            ]
+        );
+        assert!(ranked[&MammogramView::new(Laterality::Right, ViewPosition::Mlo)].is_empty());
+    }
 
-        assert_eq!(selected.metadata.mammogram_type, MammogramType::Ffdm);
+    #[test]
+    fn test_reselect_excluding_promotes_runner_up() {
+        let ffdm = make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        let tomo = make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Tomo);
+        let records = vec![ffdm.clone(), tomo.clone()];
+        let view = MammogramView::new(Laterality::Left, ViewPosition::Cc);
+
+        let initial = get_preferred_views(&records);
+        assert_eq!(
+            initial[&view].as_ref().unwrap().sop_instance_uid,
+            ffdm.sop_instance_uid
+        );
+
+        let reselected = reselect_excluding(
+            &records,
+            ffdm.sop_instance_uid.as_deref().unwrap(),
+            PreferenceOrder::Default,
+        );
+        let selected = reselected[&view].as_ref().unwrap();
+
+        assert_eq!(selected.metadata.mammogram_type, MammogramType::Tomo);
+        assert_eq!(selected.sop_instance_uid, tomo.sop_instance_uid);
+    }
+
+    #[test]
+    fn test_reselect_excluding_leaves_view_empty_without_runner_up() {
+        let ffdm = make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        let records = vec![ffdm.clone()];
+
+        let reselected = reselect_excluding(
+            &records,
+            ffdm.sop_instance_uid.as_deref().unwrap(),
+            PreferenceOrder::Default,
+        );
+
+        assert!(reselected[&MammogramView::new(Laterality::Left, ViewPosition::Cc)].is_none());
     }
 
     #[test]
@@ -1332,6 +2414,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn preferred_view_selection_breaks_uid_less_ties_by_file_path() {
+        let mut first = make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        first.sop_instance_uid = None;
+        first.series_instance_uid = None;
+        first.file_path = PathBuf::from("anon/a.dcm");
+        let mut second = first.clone();
+        second.file_path = PathBuf::from("anon/b.dcm");
+
+        for records in [
+            vec![first.clone(), second.clone()],
+            vec![second.clone(), first.clone()],
+        ] {
+            let selections = get_preferred_views(&records);
+            let selected = selections[&MammogramView::new(Laterality::Left, ViewPosition::Cc)]
+                .as_ref()
+                .unwrap();
+            assert_eq!(selected.file_path, PathBuf::from("anon/a.dcm"));
+        }
+    }
+
     #[test]
     fn test_get_preferred_views_tomo_first_order() {
         // Create multiple of same view with different types
@@ -1351,6 +2454,87 @@ mod tests {
         assert_eq!(selected.metadata.mammogram_type, MammogramType::Tomo);
     }
 
+    #[test]
+    fn test_get_preferred_views_custom_order() {
+        let records = vec![
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm),
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Synth),
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Sfm),
+        ];
+        let lmlo = MammogramView::new(Laterality::Left, ViewPosition::Mlo);
+        let custom = PreferenceOrder::custom(&[
+            MammogramType::Sfm,
+            MammogramType::Synth,
+            MammogramType::Ffdm,
+            MammogramType::Tomo,
+            MammogramType::Cem,
+        ])
+        .unwrap();
+
+        for built_in in [
+            PreferenceOrder::Default,
+            PreferenceOrder::TomoFirst,
+            PreferenceOrder::Synthetic2dFirst,
+        ] {
+            let selections = get_preferred_views_with_order(&records, built_in);
+            let selected = selections[&lmlo].as_ref().unwrap();
+            assert_ne!(selected.metadata.mammogram_type, MammogramType::Sfm);
+        }
+        let selections = get_preferred_views_with_order(&records, custom);
+        let selected = selections[&lmlo].as_ref().unwrap();
+        assert_eq!(selected.metadata.mammogram_type, MammogramType::Sfm);
+    }
+
+    #[test]
+    fn test_get_preferred_views_ref_matches_cloning_selection() {
+        let mut records = vec![
+            make_test_record_with_study(
+                Laterality::Left,
+                ViewPosition::Cc,
+                MammogramType::Ffdm,
+                Some("1.2.3.other"),
+            ),
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Synth),
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm),
+            make_test_record_with_study(
+                Laterality::Right,
+                ViewPosition::Cc,
+                MammogramType::Ffdm,
+                None,
+            ),
+            make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Sfm),
+            make_test_record(Laterality::Left, ViewPosition::Sio, MammogramType::Ffdm),
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm),
+        ];
+        records.extend(make_ambiguous_series(
+            DEFAULT_STUDY_UID,
+            "1.2.3.dbt",
+            None,
+            Laterality::Right,
+            ViewPosition::Mlo,
+            3,
+        ));
+
+        for order in [PreferenceOrder::Default, PreferenceOrder::TomoFirst] {
+            let cloned = get_preferred_views_with_order(&records, order);
+            let borrowed = get_preferred_views_ref(&records, order);
+
+            assert_eq!(borrowed.len(), cloned.len());
+            for view in STANDARD_MAMMO_VIEWS.iter() {
+                let borrowed = borrowed[view];
+                let cloned = cloned[view].as_ref();
+                assert_eq!(
+                    borrowed.map(|record| (&record.file_path, &record.sop_instance_uid)),
+                    cloned.map(|record| (&record.file_path, &record.sop_instance_uid)),
+                    "{view}"
+                );
+                if let Some(record) = borrowed {
+                    assert!(records.iter().any(|input| std::ptr::eq(input, record)));
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_get_preferred_views_empty() {
         let records: Vec<MammogramRecord> = vec![];
@@ -1428,6 +2612,109 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_prefer_latest_study_selects_newer_equally_complete_study() {
+        let prior_study = "1.2.826.0.10";
+        let current_study = "1.2.826.0.20";
+        let records: Vec<_> = [
+            (prior_study, NaiveDate::from_ymd_opt(2023, 3, 1)),
+            (current_study, NaiveDate::from_ymd_opt(2024, 3, 1)),
+        ]
+        .into_iter()
+        .flat_map(|(study_uid, study_date)| {
+            STANDARD_MAMMO_VIEWS.iter().map(move |view| {
+                let mut record = make_test_record_with_study(
+                    view.laterality,
+                    view.view,
+                    MammogramType::Ffdm,
+                    Some(study_uid),
+                );
+                record.study_date = study_date;
+                record
+            })
+        })
+        .collect();
+
+        let default_selection = get_preferred_views_filtered(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+        );
+        let latest_selection = get_preferred_views_filtered(
+            &records,
+            &FilterConfig::permissive().prefer_latest_study(true),
+            PreferenceOrder::Default,
+        );
+
+        for view in &STANDARD_MAMMO_VIEWS {
+            let study_of = |selection: &PreferredViewSelection| {
+                selection[view]
+                    .as_ref()
+                    .unwrap()
+                    .study_instance_uid
+                    .clone()
+                    .unwrap()
+            };
+            assert_eq!(study_of(&default_selection), prior_study);
+            assert_eq!(study_of(&latest_selection), current_study);
+        }
+    }
+
+    #[test]
+    fn test_prefer_latest_study_outranks_more_complete_prior_study() {
+        let prior_study = "1.2.826.0.10";
+        let current_study = "1.2.826.0.20";
+        let mut records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| {
+                let mut record = make_test_record_with_study(
+                    view.laterality,
+                    view.view,
+                    MammogramType::Ffdm,
+                    Some(prior_study),
+                );
+                record.study_date = NaiveDate::from_ymd_opt(2023, 3, 1);
+                record
+            })
+            .collect();
+        let mut current_lcc = make_test_record_with_study(
+            Laterality::Left,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            Some(current_study),
+        );
+        current_lcc.study_date = NaiveDate::from_ymd_opt(2024, 3, 1);
+        records.push(current_lcc);
+
+        let latest_selection = get_preferred_views_filtered(
+            &records,
+            &FilterConfig::permissive().prefer_latest_study(true),
+            PreferenceOrder::Default,
+        );
+
+        let lcc = MammogramView::new(Laterality::Left, ViewPosition::Cc);
+        let rmlo = MammogramView::new(Laterality::Right, ViewPosition::Mlo);
+        assert_eq!(
+            latest_selection[&lcc]
+                .as_ref()
+                .and_then(|record| record.study_instance_uid.as_deref()),
+            Some(current_study)
+        );
+        assert!(latest_selection[&rmlo].is_none());
+
+        let default_selection = get_preferred_views_filtered(
+            &records,
+            &FilterConfig::permissive(),
+            PreferenceOrder::Default,
+        );
+        assert_eq!(
+            default_selection[&lcc]
+                .as_ref()
+                .and_then(|record| record.study_instance_uid.as_deref()),
+            Some(prior_study)
+        );
+    }
+
     #[test]
     fn test_get_preferred_views_ties_by_lowest_study_uid() {
         let higher_study = "1.2.826.0.20";
@@ -2149,6 +3436,202 @@ mod tests {
         assert!(!filtered[0].metadata.is_for_processing);
     }
 
+    #[test]
+    fn test_apply_filters_require_for_presentation() {
+        let config = FilterConfig::default().require_for_presentation(true);
+
+        let mut presentation_record =
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        presentation_record.metadata.presentation_intent = PresentationIntent::ForPresentation;
+
+        let mut processing_record =
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm);
+        processing_record.metadata.presentation_intent = PresentationIntent::ForProcessing;
+
+        let unknown_intent_record =
+            make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm);
+
+        let records = vec![
+            presentation_record,
+            processing_record,
+            unknown_intent_record,
+        ];
+        let filtered = apply_filters(&records, &config);
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(
+            filtered[0].metadata.presentation_intent,
+            PresentationIntent::ForPresentation
+        );
+    }
+
+    #[test]
+    fn test_apply_filters_exclude_spot_mag() {
+        let config = FilterConfig::default().exclude_spot_mag(true);
+
+        let mut spot = make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        spot.metadata
+            .view_modifiers
+            .insert(MammographyViewModifier::SpotCompression);
+        let mut magnified =
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm);
+        magnified
+            .metadata
+            .view_modifiers
+            .insert(MammographyViewModifier::Magnification);
+        let plain = make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm);
+
+        let records = vec![spot, magnified, plain];
+        let filtered = apply_filters(&records, &config);
+
+        assert_eq!(filtered.len(), 1);
+        assert!(filtered[0].metadata.view_modifiers.is_empty());
+    }
+
+    #[test]
+    fn test_apply_filters_min_frames() {
+        let config = FilterConfig::default()
+            .with_allowed_types([MammogramType::Tomo].into())
+            .with_min_frames(2);
+
+        let single_frame =
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Tomo);
+        let mut volume = make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Tomo);
+        volume.metadata.number_of_frames = 60;
+        let mut ffdm = make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm);
+        ffdm.metadata.number_of_frames = 2;
+
+        let records = vec![single_frame, volume, ffdm];
+        let filtered = apply_filters(&records, &config);
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].metadata.number_of_frames, 60);
+        assert_eq!(filtered[0].metadata.mammogram_type, MammogramType::Tomo);
+    }
+
+    #[test]
+    fn test_apply_filters_manufacturer_and_model() {
+        let mut hologic = make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        hologic.metadata.manufacturer = Some("HOLOGIC, Inc.".to_string());
+        hologic.metadata.model = Some("Selenia Dimensions".to_string());
+        let mut ge = make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm);
+        ge.metadata.manufacturer = Some("GE MEDICAL SYSTEMS".to_string());
+        ge.metadata.model = Some("Senographe Pristina".to_string());
+        let unlabeled = make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm);
+        let records = vec![hologic, ge, unlabeled];
+
+        let allow = FilterConfig::default()
+            .with_allowed_manufacturers(["hologic, inc.".to_string()].into());
+        let filtered = apply_filters(&records, &allow);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(
+            filtered[0].metadata.manufacturer.as_deref(),
+            Some("HOLOGIC, Inc.")
+        );
+
+        let deny = FilterConfig::default()
+            .with_excluded_models(["SENOGRAPHE PRISTINA".to_string()].into());
+        let filtered = apply_filters(&records, &deny);
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered
+            .iter()
+            .all(|record| record.metadata.model.as_deref() != Some("Senographe Pristina")));
+    }
+
+    #[test]
+    fn test_apply_filters_excludes_phantom_patient_names() {
+        let mut phantom = make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        phantom.patient_name = Some("ACR PHANTOM".to_string());
+        let mut patient =
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm);
+        patient.patient_name = Some("DOE^JANE".to_string());
+        let unnamed = make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm);
+        let records = vec![phantom, patient, unnamed];
+
+        assert_eq!(apply_filters(&records, &FilterConfig::default()).len(), 3);
+
+        let filtered = apply_filters(&records, &FilterConfig::default().exclude_phantoms());
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered
+            .iter()
+            .all(|record| record.patient_name.as_deref() != Some("ACR PHANTOM")));
+    }
+
+    #[test]
+    fn test_apply_filters_min_image_area() {
+        let config = FilterConfig::default().with_min_image_area(1_000_000);
+
+        let mut thumbnail =
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        thumbnail.rows = Some(256);
+        thumbnail.columns = Some(256);
+        let mut full = make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm);
+        full.rows = Some(2560);
+        full.columns = Some(3328);
+        let mut unknown =
+            make_test_record(Laterality::Right, ViewPosition::Cc, MammogramType::Ffdm);
+        unknown.rows = None;
+
+        let records = vec![thumbnail, full, unknown];
+        let filtered = apply_filters(&records, &config);
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].image_area(), Some(2560 * 3328));
+    }
+
+    #[test]
+    fn test_explain_missing_reports_all_filtered() {
+        let config = FilterConfig::default();
+        let mut lcc = make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        lcc.metadata.is_for_processing = true;
+        let mut lcc_tomo =
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Tomo);
+        lcc_tomo.metadata.is_for_processing = true;
+        let records = vec![
+            lcc,
+            lcc_tomo,
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm),
+        ];
+        let lcc_view = MammogramView::new(Laterality::Left, ViewPosition::Cc);
+        let rcc_view = MammogramView::new(Laterality::Right, ViewPosition::Cc);
+        let lmlo_view = MammogramView::new(Laterality::Left, ViewPosition::Mlo);
+
+        assert_eq!(
+            explain_missing(&records, &config, &lcc_view),
+            MissingReason::AllFiltered(2)
+        );
+        assert_eq!(
+            explain_missing(&records, &config, &rcc_view),
+            MissingReason::NoCandidates
+        );
+        assert_eq!(
+            explain_missing(&records, &config, &lmlo_view),
+            MissingReason::NotMissing
+        );
+        assert_eq!(MissingReason::AllFiltered(2).as_str(), "all_filtered");
+    }
+
+    #[test]
+    fn test_explain_missing_reports_other_study_selected() {
+        let mut records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| make_test_record(view.laterality, view.view, MammogramType::Ffdm))
+            .filter(|record| record.metadata.laterality == Laterality::Left)
+            .collect();
+        records.push(make_test_record_with_study(
+            Laterality::Right,
+            ViewPosition::Cc,
+            MammogramType::Ffdm,
+            Some("1.2.826.0.2"),
+        ));
+        let rcc_view = MammogramView::new(Laterality::Right, ViewPosition::Cc);
+
+        assert_eq!(
+            explain_missing(&records, &FilterConfig::permissive(), &rcc_view),
+            MissingReason::OtherStudySelected(1)
+        );
+    }
+
     #[test]
     fn test_apply_filters_exclude_secondary_capture() {
         let config = FilterConfig::default().exclude_secondary_capture(true);
@@ -2167,6 +3650,28 @@ mod tests {
         assert!(!filtered[0].metadata.is_secondary_capture);
     }
 
+    #[test]
+    fn test_apply_filters_exclude_localizers_and_specimens() {
+        let mut localizer =
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm);
+        localizer.metadata.is_localizer = true;
+        let mut specimen =
+            make_test_record(Laterality::Left, ViewPosition::Mlo, MammogramType::Ffdm);
+        specimen.metadata.is_specimen = true;
+        let regular_record =
+            make_test_record(Laterality::Left, ViewPosition::Cc, MammogramType::Ffdm);
+        let records = vec![localizer, specimen, regular_record];
+
+        let filtered = apply_filters(&records, &FilterConfig::default());
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].metadata.view_position, ViewPosition::Cc);
+
+        let config = FilterConfig::default()
+            .exclude_localizers(false)
+            .exclude_specimens(false);
+        assert_eq!(apply_filters(&records, &config).len(), 3);
+    }
+
     #[test]
     fn test_apply_filters_exclude_non_mg_modality() {
         let config = FilterConfig::default().exclude_non_mg_modality(true);
@@ -2564,6 +4069,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_preferred_views_filtered_with_common_modality_keeps_pure_tomo() {
+        let config = FilterConfig::permissive().require_common_modality(true);
+
+        let records: Vec<_> = STANDARD_MAMMO_VIEWS
+            .iter()
+            .map(|view| make_test_record(view.laterality, view.view, MammogramType::Tomo))
+            .collect();
+
+        let selections = get_preferred_views_filtered(&records, &config, PreferenceOrder::Default);
+
+        assert_eq!(count_coverage(&selections), 4);
+        for record in selections.values().flatten() {
+            assert_eq!(record.metadata.mammogram_type, MammogramType::Tomo);
+        }
+    }
+
     #[test]
     fn test_get_preferred_views_filtered_without_common_modality() {
         // Without flag, mixed results are kept
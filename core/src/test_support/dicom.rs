@@ -0,0 +1,84 @@
+//! DICOM file fixtures for unit tests.
+//!
+//! Compiled into the library's tests and, through a `#[path]` module, into
+//! the `mammoselect` binary's tests, so it only depends on external crates.
+
+use dicom_core::value::PrimitiveValue;
+use dicom_core::{DataElement, Tag, VR};
+use dicom_dictionary_std::{tags, uids};
+use dicom_object::{FileDicomObject, FileMetaTableBuilder, InMemDicomObject};
+use std::path::Path;
+
+/// Study Instance UID given to fixtures that do not set their own
+pub(crate) const FIXTURE_STUDY_UID: &str = "1.2.826.0.1";
+
+/// Builder for a small FFDM for-presentation mammogram
+///
+/// Starts with the SOP class, SOP Instance UID, Study Instance UID,
+/// Modality `MG`, Image Type `ORIGINAL\PRIMARY`, and a 32x32 matrix; tests
+/// add or replace elements for the case they cover. The file meta table
+/// follows whatever SOP Class UID the object ends up with.
+pub(crate) struct MammogramFixture {
+    object: InMemDicomObject,
+    sop_uid: String,
+}
+
+impl MammogramFixture {
+    pub(crate) fn new(sop_uid: &str) -> Self {
+        let object = InMemDicomObject::from_element_iter([
+            DataElement::new(
+                tags::SOP_CLASS_UID,
+                VR::UI,
+                uids::DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION,
+            ),
+            DataElement::new(tags::SOP_INSTANCE_UID, VR::UI, sop_uid),
+            DataElement::new(tags::STUDY_INSTANCE_UID, VR::UI, FIXTURE_STUDY_UID),
+            DataElement::new(tags::MODALITY, VR::CS, "MG"),
+            DataElement::new(tags::IMAGE_TYPE, VR::CS, "ORIGINAL\\PRIMARY"),
+            DataElement::new(tags::ROWS, VR::US, PrimitiveValue::from(32_u16)),
+            DataElement::new(tags::COLUMNS, VR::US, PrimitiveValue::from(32_u16)),
+        ]);
+        Self {
+            object,
+            sop_uid: sop_uid.to_string(),
+        }
+    }
+
+    /// Sets Image Laterality and View Position
+    pub(crate) fn view(self, laterality: &str, view_position: &str) -> Self {
+        self.with(tags::IMAGE_LATERALITY, VR::CS, laterality).with(
+            tags::VIEW_POSITION,
+            VR::CS,
+            view_position,
+        )
+    }
+
+    /// Adds or replaces an element
+    pub(crate) fn with(mut self, tag: Tag, vr: VR, value: impl Into<PrimitiveValue>) -> Self {
+        self.object.put(DataElement::new(tag, vr, value.into()));
+        self
+    }
+
+    pub(crate) fn into_file_object(self) -> FileDicomObject<InMemDicomObject> {
+        let sop_class_uid = self
+            .object
+            .element(tags::SOP_CLASS_UID)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .trim_end_matches('\0')
+            .to_string();
+        self.object
+            .with_meta(
+                FileMetaTableBuilder::new()
+                    .transfer_syntax(uids::EXPLICIT_VR_LITTLE_ENDIAN)
+                    .media_storage_sop_class_uid(sop_class_uid)
+                    .media_storage_sop_instance_uid(self.sop_uid),
+            )
+            .unwrap()
+    }
+
+    pub(crate) fn write(self, path: &Path) {
+        self.into_file_object().write_to_file(path).unwrap();
+    }
+}
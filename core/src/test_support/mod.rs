@@ -0,0 +1,7 @@
+//! Shared fixtures for unit tests.
+
+mod dicom;
+mod records;
+
+pub(crate) use dicom::MammogramFixture;
+pub(crate) use records::{sample_metadata, sample_record};
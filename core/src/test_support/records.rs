@@ -0,0 +1,70 @@
+//! In-memory record fixtures for unit tests.
+//!
+//! Tests start from these values and override the fields they care about
+//! with struct update syntax, so new fields only need a default here.
+
+use crate::api::MammogramMetadata;
+use crate::selection::MammogramRecord;
+use crate::types::{
+    DbtObjectKind, ImageType, Laterality, MammogramType, PhotometricInterpretation,
+    PresentationIntent, SopClassKind, ViewPosition,
+};
+use std::path::PathBuf;
+
+/// FFDM L-CC metadata with Image Type `ORIGINAL\PRIMARY` and Modality `MG`
+pub(crate) fn sample_metadata() -> MammogramMetadata {
+    MammogramMetadata {
+        mammogram_type: MammogramType::Ffdm,
+        dbt_object_kind: DbtObjectKind::None,
+        laterality: Laterality::Left,
+        view_position: ViewPosition::Cc,
+        view_modifiers: Default::default(),
+        image_type: ImageType::new("ORIGINAL".to_string(), "PRIMARY".to_string(), None, None),
+        is_for_processing: false,
+        presentation_intent: PresentationIntent::Unknown,
+        has_implant: false,
+        manufacturer: None,
+        model: None,
+        number_of_frames: 1,
+        pixel_spacing: None,
+        imager_pixel_spacing: None,
+        photometric: PhotometricInterpretation::Monochrome2,
+        rescale_slope: None,
+        rescale_intercept: None,
+        body_part_thickness_mm: None,
+        compression_force_n: None,
+        concatenation_uid: None,
+        sop_instance_uid_of_concatenation_source: None,
+        is_secondary_capture: false,
+        is_localizer: false,
+        is_specimen: false,
+        modality: Some("MG".to_string()),
+        character_set: None,
+        transfer_syntax_uid: None,
+        transfer_syntax_name: None,
+        compression_type: None,
+    }
+}
+
+/// A 2560x3328 mammogram image in study `1.2.3` with no other identifiers
+pub(crate) fn sample_record(
+    file_path: impl Into<PathBuf>,
+    metadata: MammogramMetadata,
+) -> MammogramRecord {
+    MammogramRecord {
+        file_path: file_path.into(),
+        metadata,
+        study_instance_uid: Some("1.2.3".to_string()),
+        series_instance_uid: None,
+        sop_instance_uid: None,
+        rows: Some(2560),
+        columns: Some(3328),
+        bits_stored: None,
+        transfer_syntax_uid: None,
+        is_lossy_compressed: false,
+        study_date: None,
+        patient_id: None,
+        patient_name: None,
+        sop_class: SopClassKind::MammoImage,
+    }
+}
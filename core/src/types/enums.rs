@@ -1,5 +1,7 @@
+use crate::error::MammocatError;
 use std::cmp::Ordering;
 use std::fmt;
+use std::str::FromStr;
 
 /// Preference ordering strategy for selecting preferred mammogram types
 ///
@@ -7,21 +9,30 @@ use std::fmt;
 /// Lower preference values indicate MORE preferred types (will be selected by .min()).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "json", serde(rename_all = "kebab-case"))]
+#[cfg_attr(
+    feature = "json",
+    serde(rename_all = "kebab-case", try_from = "PreferenceOrderRepr")
+)]
 pub enum PreferenceOrder {
-    /// Default ordering: FFDM > SYNTH > TOMO > SFM
+    /// Default ordering: FFDM > SYNTH > TOMO > SFM > CEM
     /// Prefers 2D images over tomosynthesis for general inference
     #[default]
     Default,
 
-    /// Tomosynthesis first: TOMO > FFDM > SYNTH > SFM
+    /// Tomosynthesis first: TOMO > FFDM > SYNTH > SFM > CEM
     /// Maximizes use of 3D imaging when available
     TomoFirst,
 
-    /// Synthetic 2D first: SYNTH > FFDM > TOMO > SFM
+    /// Synthetic 2D first: SYNTH > FFDM > TOMO > SFM > CEM
     /// Preserves the default ordering except synthetic 2D views are preferred over FFDM.
     #[cfg_attr(feature = "json", serde(rename = "synthetic-2d-first"))]
     Synthetic2dFirst,
+
+    /// Explicit ranking of the known types, most preferred first
+    ///
+    /// Build with [`PreferenceOrder::custom`] to validate the ranking;
+    /// deserialization goes through the same check. UNKNOWN always ranks last.
+    Custom([MammogramType; 5]),
 }
 
 impl PreferenceOrder {
@@ -31,30 +42,178 @@ impl PreferenceOrder {
     pub fn preference_value(&self, mammo_type: &MammogramType) -> i32 {
         match self {
             PreferenceOrder::Default => match mammo_type {
-                MammogramType::Unknown => 5,
+                MammogramType::Unknown => 6,
                 MammogramType::Ffdm => 1,
                 MammogramType::Synth => 2,
                 MammogramType::Tomo => 3,
                 MammogramType::Sfm => 4,
+                MammogramType::Cem => 5,
             },
             PreferenceOrder::TomoFirst => match mammo_type {
-                MammogramType::Unknown => 5,
+                MammogramType::Unknown => 6,
                 MammogramType::Tomo => 1,
                 MammogramType::Ffdm => 2,
                 MammogramType::Synth => 3,
                 MammogramType::Sfm => 4,
+                MammogramType::Cem => 5,
             },
             PreferenceOrder::Synthetic2dFirst => match mammo_type {
-                MammogramType::Unknown => 5,
+                MammogramType::Unknown => 6,
                 MammogramType::Synth => 1,
                 MammogramType::Ffdm => 2,
                 MammogramType::Tomo => 3,
                 MammogramType::Sfm => 4,
+                MammogramType::Cem => 5,
             },
+            PreferenceOrder::Custom(ranking) => ranking
+                .iter()
+                .position(|ranked| ranked == mammo_type)
+                .map_or(6, |index| index as i32 + 1),
+        }
+    }
+
+    /// Builds a [`PreferenceOrder::Custom`] ranking, most preferred first
+    ///
+    /// The ranking must list every known type exactly once. UNKNOWN cannot
+    /// be ranked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::{MammogramType, PreferenceOrder};
+    ///
+    /// let order = PreferenceOrder::custom(&[
+    ///     MammogramType::Ffdm,
+    ///     MammogramType::Synth,
+    ///     MammogramType::Tomo,
+    ///     MammogramType::Cem,
+    ///     MammogramType::Sfm,
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(order.preference_value(&MammogramType::Cem), 4);
+    /// ```
+    pub fn custom(ranking: &[MammogramType]) -> Result<Self, MammocatError> {
+        let invalid = || {
+            let names: Vec<&str> = ranking.iter().map(|t| t.serialized_name()).collect();
+            MammocatError::InvalidValue(format!(
+                "custom preference order must rank tomo, ffdm, synth, sfm, and cem exactly once, got [{}]",
+                names.join(", ")
+            ))
+        };
+        let ranking: [MammogramType; 5] = ranking.try_into().map_err(|_| invalid())?;
+        let known = MammogramType::all()
+            .iter()
+            .filter(|mammo_type| !mammo_type.is_unknown());
+        for mammo_type in known {
+            if ranking
+                .iter()
+                .filter(|ranked| *ranked == mammo_type)
+                .count()
+                != 1
+            {
+                return Err(invalid());
+            }
+        }
+        Ok(PreferenceOrder::Custom(ranking))
+    }
+
+    /// Returns the kebab-case name used by the CLIs and serialized configs
+    pub fn simple_name(&self) -> &'static str {
+        match self {
+            PreferenceOrder::Default => "default",
+            PreferenceOrder::TomoFirst => "tomo-first",
+            PreferenceOrder::Synthetic2dFirst => "synthetic-2d-first",
+            PreferenceOrder::Custom(_) => "custom",
+        }
+    }
+
+    /// Parses a preference order from its [`simple_name`](Self::simple_name)
+    ///
+    /// Matching is case-insensitive and accepts underscores in place of
+    /// hyphens, so `"tomo_first"` and `"TOMO-FIRST"` both parse. A custom
+    /// ranking is written as `"custom:ffdm,synth,tomo,sfm,cem"`, matching its
+    /// [`Display`](fmt::Display) output. Returns `None` for unrecognized
+    /// strings or invalid custom rankings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::PreferenceOrder;
+    ///
+    /// let order = PreferenceOrder::from_str("tomo-first").unwrap();
+    /// assert_eq!(order, PreferenceOrder::TomoFirst);
+    /// assert_eq!(PreferenceOrder::from_str(&order.to_string()), Some(order));
+    /// assert_eq!(PreferenceOrder::from_str("newest"), None);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        let normalized = s.trim().to_ascii_lowercase();
+        if let Some(ranking) = normalized.strip_prefix("custom:") {
+            let ranking: Vec<MammogramType> = ranking
+                .split(',')
+                .map(|name| name.trim().parse::<MammogramType>())
+                .collect::<Result<_, _>>()
+                .ok()?;
+            return PreferenceOrder::custom(&ranking).ok();
+        }
+        match normalized.replace('_', "-").as_str() {
+            "default" => Some(PreferenceOrder::Default),
+            "tomo-first" => Some(PreferenceOrder::TomoFirst),
+            "synthetic-2d-first" => Some(PreferenceOrder::Synthetic2dFirst),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PreferenceOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreferenceOrder::Custom(ranking) => {
+                let names: Vec<&str> = ranking.iter().map(|t| t.serialized_name()).collect();
+                write!(f, "{}:{}", self.simple_name(), names.join(","))
+            }
+            _ => write!(f, "{}", self.simple_name()),
+        }
+    }
+}
+
+/// Deserialized form of [`PreferenceOrder`], before the custom ranking is validated
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum PreferenceOrderRepr {
+    Default,
+    TomoFirst,
+    #[serde(rename = "synthetic-2d-first")]
+    Synthetic2dFirst,
+    Custom(Vec<MammogramType>),
+}
+
+#[cfg(feature = "json")]
+impl TryFrom<PreferenceOrderRepr> for PreferenceOrder {
+    type Error = MammocatError;
+
+    fn try_from(repr: PreferenceOrderRepr) -> Result<Self, Self::Error> {
+        match repr {
+            PreferenceOrderRepr::Default => Ok(PreferenceOrder::Default),
+            PreferenceOrderRepr::TomoFirst => Ok(PreferenceOrder::TomoFirst),
+            PreferenceOrderRepr::Synthetic2dFirst => Ok(PreferenceOrder::Synthetic2dFirst),
+            PreferenceOrderRepr::Custom(ranking) => PreferenceOrder::custom(&ranking),
         }
     }
 }
 
+/// Parses with the lenient [`PreferenceOrder::from_str`] rules
+impl FromStr for PreferenceOrder {
+    type Err = MammocatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PreferenceOrder::from_str(s).ok_or_else(|| {
+            MammocatError::InvalidValue(format!("unrecognized preference order: {s:?}"))
+        })
+    }
+}
+
 /// DBT object representation for tomosynthesis metadata.
 ///
 /// This is orthogonal to [`MammogramType`]: confidently identified or refined
@@ -93,11 +252,100 @@ impl fmt::Display for DbtObjectKind {
     }
 }
 
+/// SOP class family derived from the SOPClassUID tag.
+///
+/// Used to tell mammography images apart from the non-image objects (CAD
+/// reports, presentation states, key object selections) that PACS exports
+/// routinely mix into study folders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json", serde(rename_all = "snake_case"))]
+pub enum SopClassKind {
+    /// Digital mammography or breast projection X-ray image.
+    MammoImage,
+    /// Breast tomosynthesis image.
+    TomoImage,
+    /// CAD structured report (mammography, chest, or colon CAD SR).
+    CadSr,
+    /// Softcopy presentation state of any flavour.
+    PresentationState,
+    /// Key object selection document.
+    KeyObjectSelection,
+    /// Secondary capture image (single- or multi-frame).
+    SecondaryCapture,
+    /// Any other or missing SOP class.
+    #[default]
+    Other,
+}
+
+impl SopClassKind {
+    /// Classifies a SOPClassUID.
+    ///
+    /// Surrounding whitespace and trailing NUL padding are ignored.
+    /// Unrecognized UIDs map to [`SopClassKind::Other`].
+    pub fn from_uid(uid: &str) -> Self {
+        let uid = uid.trim().trim_end_matches('\0').trim();
+        match uid {
+            // Digital Mammography X-Ray (presentation/processing), Breast Projection X-Ray
+            "1.2.840.10008.5.1.4.1.1.1.2"
+            | "1.2.840.10008.5.1.4.1.1.1.2.1"
+            | "1.2.840.10008.5.1.4.1.1.13.1.4"
+            | "1.2.840.10008.5.1.4.1.1.13.1.5" => Self::MammoImage,
+            "1.2.840.10008.5.1.4.1.1.13.1.3" => Self::TomoImage,
+            // Mammography, Chest, and Colon CAD SR
+            "1.2.840.10008.5.1.4.1.1.88.50"
+            | "1.2.840.10008.5.1.4.1.1.88.65"
+            | "1.2.840.10008.5.1.4.1.1.88.69" => Self::CadSr,
+            "1.2.840.10008.5.1.4.1.1.88.59" => Self::KeyObjectSelection,
+            _ if uid.starts_with("1.2.840.10008.5.1.4.1.1.11.") => Self::PresentationState,
+            _ if uid == "1.2.840.10008.5.1.4.1.1.7"
+                || uid.starts_with("1.2.840.10008.5.1.4.1.1.7.") =>
+            {
+                Self::SecondaryCapture
+            }
+            _ => Self::Other,
+        }
+    }
+
+    /// Returns simple name for display and dictionary output.
+    pub fn simple_name(&self) -> &'static str {
+        match self {
+            Self::MammoImage => "mammo_image",
+            Self::TomoImage => "tomo_image",
+            Self::CadSr => "cad_sr",
+            Self::PresentationState => "presentation_state",
+            Self::KeyObjectSelection => "key_object_selection",
+            Self::SecondaryCapture => "secondary_capture",
+            Self::Other => "other",
+        }
+    }
+
+    /// Whether the SOP class never carries pixel data worth selecting.
+    ///
+    /// Secondary captures and unrecognized classes are not considered
+    /// non-image; the existing extraction rules decide what to do with them.
+    pub fn is_non_image(&self) -> bool {
+        matches!(
+            self,
+            Self::CadSr | Self::PresentationState | Self::KeyObjectSelection
+        )
+    }
+}
+
+impl fmt::Display for SopClassKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.simple_name())
+    }
+}
+
 /// Mammogram type classification with intrinsic ordering.
 ///
-/// Intrinsic order: TOMO < FFDM < SYNTH < SFM < UNKNOWN. This powers
+/// Intrinsic order: TOMO < FFDM < SYNTH < SFM < CEM < UNKNOWN. This powers
 /// [`Ord`] and [`MammogramType::is_preferred_to`]; preferred-view selection can
 /// override type ranking with [`PreferenceOrder`].
+///
+/// Serde uses [`MammogramType::simple_name`] as the wire form (`"s-view"` for
+/// SYNTH) and still accepts the legacy `"synth"` when deserializing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json", serde(rename_all = "lowercase"))]
@@ -105,21 +353,36 @@ pub enum MammogramType {
     Unknown,
     Tomo,
     Ffdm,
+    #[cfg_attr(feature = "json", serde(rename = "s-view", alias = "synth"))]
     Synth,
     Sfm,
+    /// Contrast-enhanced mammography (low-energy or recombined image)
+    Cem,
 }
 
 impl MammogramType {
+    /// Returns every variant in declaration order
+    pub fn all() -> &'static [Self] {
+        &[
+            MammogramType::Unknown,
+            MammogramType::Tomo,
+            MammogramType::Ffdm,
+            MammogramType::Synth,
+            MammogramType::Sfm,
+            MammogramType::Cem,
+        ]
+    }
+
     /// Returns whether this type is unknown
     pub fn is_unknown(&self) -> bool {
         matches!(self, MammogramType::Unknown)
     }
 
-    /// Returns whether this type belongs to the 2D modality group (FFDM, SYNTH, SFM)
+    /// Returns whether this type belongs to the 2D modality group (FFDM, SYNTH, SFM, CEM)
     pub fn is_2d_group(&self) -> bool {
         matches!(
             self,
-            MammogramType::Ffdm | MammogramType::Synth | MammogramType::Sfm
+            MammogramType::Ffdm | MammogramType::Synth | MammogramType::Sfm | MammogramType::Cem
         )
     }
 
@@ -136,6 +399,7 @@ impl MammogramType {
             MammogramType::Ffdm => "ffdm",
             MammogramType::Synth => "s-view",
             MammogramType::Sfm => "sfm",
+            MammogramType::Cem => "cem",
         }
     }
 
@@ -147,6 +411,7 @@ impl MammogramType {
             MammogramType::Ffdm => "ffdm",
             MammogramType::Synth => "synth",
             MammogramType::Sfm => "sfm",
+            MammogramType::Cem => "cem",
         }
     }
 
@@ -157,7 +422,8 @@ impl MammogramType {
             MammogramType::Ffdm => 2,
             MammogramType::Synth => 3,
             MammogramType::Sfm => 4,
-            MammogramType::Unknown => 5,
+            MammogramType::Cem => 5,
+            MammogramType::Unknown => 6,
         }
     }
 
@@ -180,10 +446,19 @@ impl MammogramType {
     }
 
     /// Parses mammogram type from string
+    ///
+    /// Contrast-enhanced names (`cem`, `cesm`, `contrast`) must appear as
+    /// whole words, so words such as "displacement" or "enhancement" do not
+    /// parse as CEM.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Self {
         let s_lower = s.to_lowercase();
-        if s_lower.contains("tomo") {
+        let is_cem = s_lower
+            .split(|character: char| !character.is_ascii_alphanumeric())
+            .any(|token| matches!(token, "cem" | "cesm" | "contrast"));
+        if is_cem {
+            MammogramType::Cem
+        } else if s_lower.contains("tomo") {
             MammogramType::Tomo
         } else if s_lower.contains("view") || s_lower.contains("synth") {
             MammogramType::Synth
@@ -216,9 +491,26 @@ impl fmt::Display for MammogramType {
     }
 }
 
+/// Parses with the lenient [`MammogramType::from_str`] rules
+///
+/// Returns an error instead of `Unknown` when the input is not recognized,
+/// unless it is empty or literally "unknown".
+impl FromStr for MammogramType {
+    type Err = MammocatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_known(
+            s,
+            MammogramType::from_str(s),
+            MammogramType::Unknown,
+            "mammogram type",
+        )
+    }
+}
+
 /// Laterality specification (left/right/bilateral)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "json", derive(serde::Serialize))]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json", serde(rename_all = "lowercase"))]
 pub enum Laterality {
     Unknown,
@@ -229,6 +521,17 @@ pub enum Laterality {
 }
 
 impl Laterality {
+    /// Returns every variant in declaration order
+    pub fn all() -> &'static [Self] {
+        &[
+            Laterality::Unknown,
+            Laterality::None,
+            Laterality::Left,
+            Laterality::Right,
+            Laterality::Bilateral,
+        ]
+    }
+
     /// Returns whether this laterality is unknown
     pub fn is_unknown(&self) -> bool {
         matches!(self, Laterality::Unknown)
@@ -333,11 +636,32 @@ impl fmt::Display for Laterality {
     }
 }
 
+/// Parses with the lenient [`Laterality::from_str`] rules
+///
+/// Returns an error instead of `Unknown` when the input is not recognized,
+/// unless it is empty or literally "unknown".
+impl FromStr for Laterality {
+    type Err = MammocatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_known(
+            s,
+            Laterality::from_str(s),
+            Laterality::Unknown,
+            "laterality",
+        )
+    }
+}
+
 /// View position enumeration
+///
+/// Serde uses [`ViewPosition::short_str`] as the wire form (an empty string
+/// for `Unknown`) and still accepts `"unknown"` when deserializing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-#[cfg_attr(feature = "json", derive(serde::Serialize))]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json", serde(rename_all = "lowercase"))]
 pub enum ViewPosition {
+    #[cfg_attr(feature = "json", serde(rename = "", alias = "unknown"))]
     Unknown,
     Xccl,
     Xccm,
@@ -353,6 +677,24 @@ pub enum ViewPosition {
 }
 
 impl ViewPosition {
+    /// Returns every variant in declaration order
+    pub fn all() -> &'static [Self] {
+        &[
+            ViewPosition::Unknown,
+            ViewPosition::Xccl,
+            ViewPosition::Xccm,
+            ViewPosition::Cc,
+            ViewPosition::Mlo,
+            ViewPosition::Ml,
+            ViewPosition::Lmo,
+            ViewPosition::Lm,
+            ViewPosition::Fb,
+            ViewPosition::Sio,
+            ViewPosition::Iso,
+            ViewPosition::Specimen,
+        ]
+    }
+
     /// Returns whether this view position is unknown
     pub fn is_unknown(&self) -> bool {
         matches!(self, ViewPosition::Unknown)
@@ -379,6 +721,20 @@ impl ViewPosition {
         )
     }
 
+    /// Maps CC-like views to CC and MLO-like views to MLO
+    ///
+    /// Other view positions, such as SIO or specimen views, are returned
+    /// unchanged.
+    pub fn normalized_standard(&self) -> ViewPosition {
+        if self.is_cc_like() {
+            ViewPosition::Cc
+        } else if self.is_mlo_like() {
+            ViewPosition::Mlo
+        } else {
+            *self
+        }
+    }
+
     /// Returns short string representation
     pub fn short_str(&self) -> &'static str {
         match self {
@@ -405,7 +761,7 @@ impl ViewPosition {
 
 /// Standard CID 4015 mammography view modifier.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-#[cfg_attr(feature = "json", derive(serde::Serialize))]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "json", serde(rename_all = "snake_case"))]
 pub enum MammographyViewModifier {
     Cleavage,
@@ -447,6 +803,15 @@ impl MammographyViewModifier {
     pub fn affects_selection(self) -> bool {
         self != Self::ImplantDisplaced
     }
+
+    /// Whether this is one of the rolled view modifiers (lateral, medial,
+    /// inferior, or superior).
+    pub fn is_rolled(self) -> bool {
+        matches!(
+            self,
+            Self::RolledLateral | Self::RolledMedial | Self::RolledInferior | Self::RolledSuperior
+        )
+    }
 }
 
 impl fmt::Display for MammographyViewModifier {
@@ -461,6 +826,19 @@ impl fmt::Display for ViewPosition {
     }
 }
 
+/// Parses an exact view code or CID 4014 code meaning (e.g. "mlo", "xccl")
+///
+/// Returns an error instead of `Unknown` when the input is not recognized,
+/// unless it is empty or literally "unknown".
+impl FromStr for ViewPosition {
+    type Err = MammocatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = crate::extraction::view_position::from_str(s, true);
+        parse_known(s, parsed, ViewPosition::Unknown, "view position")
+    }
+}
+
 /// Photometric interpretation enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PhotometricInterpretation {
@@ -547,10 +925,315 @@ impl fmt::Display for PhotometricInterpretation {
     }
 }
 
+/// Parses with the [`PhotometricInterpretation::from_str`] rules
+///
+/// Returns an error instead of `Unknown` when the input is not recognized,
+/// unless it is empty or literally "unknown".
+impl FromStr for PhotometricInterpretation {
+    type Err = MammocatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = PhotometricInterpretation::from_str(s.trim());
+        parse_known(
+            s,
+            parsed,
+            PhotometricInterpretation::Unknown,
+            "photometric interpretation",
+        )
+    }
+}
+
+/// Presentation intent enumeration (PresentationIntentType)
+///
+/// Distinguishes an explicit FOR PRESENTATION or FOR PROCESSING value from a
+/// missing or unrecognized tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PresentationIntent {
+    #[default]
+    Unknown,
+    ForPresentation,
+    ForProcessing,
+}
+
+impl PresentationIntent {
+    /// Returns whether the intent is explicitly FOR PRESENTATION
+    pub fn is_for_presentation(&self) -> bool {
+        matches!(self, PresentationIntent::ForPresentation)
+    }
+
+    /// Returns whether the intent is explicitly FOR PROCESSING
+    pub fn is_for_processing(&self) -> bool {
+        matches!(self, PresentationIntent::ForProcessing)
+    }
+
+    /// Parses presentation intent from string
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Self {
+        match s.trim().to_uppercase().as_str() {
+            "FOR PRESENTATION" => PresentationIntent::ForPresentation,
+            "FOR PROCESSING" => PresentationIntent::ForProcessing,
+            _ => PresentationIntent::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for PresentationIntent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PresentationIntent::Unknown => "UNKNOWN",
+            PresentationIntent::ForPresentation => "FOR PRESENTATION",
+            PresentationIntent::ForProcessing => "FOR PROCESSING",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Parses with the [`PresentationIntent::from_str`] rules
+///
+/// Returns an error instead of `Unknown` when the input is not recognized,
+/// unless it is empty or literally "unknown".
+impl FromStr for PresentationIntent {
+    type Err = MammocatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_known(
+            s,
+            PresentationIntent::from_str(s),
+            PresentationIntent::Unknown,
+            "presentation intent",
+        )
+    }
+}
+
+/// Rejects inputs that a lenient parser mapped to `unknown` by fallback
+fn parse_known<T: PartialEq>(
+    s: &str,
+    parsed: T,
+    unknown: T,
+    kind: &str,
+) -> Result<T, MammocatError> {
+    let trimmed = s.trim();
+    if parsed != unknown || trimmed.is_empty() || trimmed.eq_ignore_ascii_case("unknown") {
+        Ok(parsed)
+    } else {
+        Err(MammocatError::InvalidValue(format!(
+            "unrecognized {kind}: {s:?}"
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_str_trait_round_trips_display() {
+        for &mammogram_type in MammogramType::all() {
+            assert_eq!(
+                mammogram_type.to_string().parse::<MammogramType>().unwrap(),
+                mammogram_type
+            );
+        }
+        for &laterality in Laterality::all() {
+            assert_eq!(
+                laterality.to_string().parse::<Laterality>().unwrap(),
+                laterality
+            );
+        }
+        for &view_position in ViewPosition::all() {
+            assert_eq!(
+                view_position.to_string().parse::<ViewPosition>().unwrap(),
+                view_position
+            );
+        }
+        for photometric in [
+            PhotometricInterpretation::Unknown,
+            PhotometricInterpretation::Monochrome1,
+            PhotometricInterpretation::Monochrome2,
+            PhotometricInterpretation::PaletteColor,
+            PhotometricInterpretation::Rgb,
+            PhotometricInterpretation::Hsv,
+            PhotometricInterpretation::Argb,
+            PhotometricInterpretation::Cmyk,
+            PhotometricInterpretation::YbrFull,
+            PhotometricInterpretation::YbrFull422,
+            PhotometricInterpretation::YbrPartial422,
+            PhotometricInterpretation::YbrPartial420,
+            PhotometricInterpretation::YbrIct,
+            PhotometricInterpretation::YbrRct,
+        ] {
+            assert_eq!(
+                photometric
+                    .to_string()
+                    .parse::<PhotometricInterpretation>()
+                    .unwrap(),
+                photometric
+            );
+        }
+        for intent in [
+            PresentationIntent::Unknown,
+            PresentationIntent::ForPresentation,
+            PresentationIntent::ForProcessing,
+        ] {
+            assert_eq!(
+                intent.to_string().parse::<PresentationIntent>().unwrap(),
+                intent
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_matches_cem_as_whole_words() {
+        for cem in ["CEM", "cesm", "Contrast Enhanced", "cem_recombined"] {
+            assert_eq!(MammogramType::from_str(cem), MammogramType::Cem, "{cem}");
+        }
+        for other in ["displacement", "placement", "enhancement"] {
+            assert_eq!(
+                MammogramType::from_str(other),
+                MammogramType::Unknown,
+                "{other}"
+            );
+        }
+        assert_eq!(
+            MammogramType::from_str("implant displacement tomo"),
+            MammogramType::Tomo
+        );
+    }
+
+    #[test]
+    fn test_from_str_trait_rejects_unrecognized_values() {
+        assert!("ffdm".parse::<MammogramType>().is_ok());
+        assert!("mammogram".parse::<MammogramType>().is_err());
+        assert!("???".parse::<Laterality>().is_err());
+        assert!("CC".parse::<ViewPosition>().is_ok());
+        assert!("lateral-ish".parse::<ViewPosition>().is_err());
+        assert!("GRAYSCALE".parse::<PhotometricInterpretation>().is_err());
+        assert!("maybe".parse::<PresentationIntent>().is_err());
+    }
+
+    #[test]
+    fn test_preference_order_round_trips_string() {
+        for order in [
+            PreferenceOrder::Default,
+            PreferenceOrder::TomoFirst,
+            PreferenceOrder::Synthetic2dFirst,
+        ] {
+            assert_eq!(PreferenceOrder::from_str(&order.to_string()), Some(order));
+            assert_eq!(order.to_string().parse::<PreferenceOrder>().unwrap(), order);
+        }
+        assert_eq!(PreferenceOrder::TomoFirst.to_string(), "tomo-first");
+        assert_eq!(
+            PreferenceOrder::Synthetic2dFirst.to_string(),
+            "synthetic-2d-first"
+        );
+    }
+
+    #[test]
+    fn test_custom_preference_order_ranks_by_position() {
+        let order = PreferenceOrder::custom(&[
+            MammogramType::Synth,
+            MammogramType::Ffdm,
+            MammogramType::Sfm,
+            MammogramType::Tomo,
+            MammogramType::Cem,
+        ])
+        .unwrap();
+
+        assert_eq!(order.preference_value(&MammogramType::Synth), 1);
+        assert_eq!(order.preference_value(&MammogramType::Tomo), 4);
+        assert_eq!(order.preference_value(&MammogramType::Unknown), 6);
+        assert_eq!(order.to_string(), "custom:synth,ffdm,sfm,tomo,cem");
+        assert_eq!(PreferenceOrder::from_str(&order.to_string()), Some(order));
+        assert_eq!(order.to_string().parse::<PreferenceOrder>().unwrap(), order);
+    }
+
+    #[test]
+    fn test_custom_preference_order_rejects_incomplete_rankings() {
+        assert!(PreferenceOrder::custom(&[MammogramType::Ffdm]).is_err());
+        assert!(PreferenceOrder::custom(&[
+            MammogramType::Ffdm,
+            MammogramType::Ffdm,
+            MammogramType::Synth,
+            MammogramType::Tomo,
+            MammogramType::Sfm,
+        ])
+        .is_err());
+        assert!(PreferenceOrder::custom(&[
+            MammogramType::Unknown,
+            MammogramType::Ffdm,
+            MammogramType::Synth,
+            MammogramType::Tomo,
+            MammogramType::Sfm,
+        ])
+        .is_err());
+        assert_eq!(PreferenceOrder::from_str("custom:ffdm,synth"), None);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_preference_order_deserialization_validates_custom_rankings() {
+        let custom = PreferenceOrder::custom(&[
+            MammogramType::Tomo,
+            MammogramType::Ffdm,
+            MammogramType::Synth,
+            MammogramType::Sfm,
+            MammogramType::Cem,
+        ])
+        .unwrap();
+        for order in [PreferenceOrder::Synthetic2dFirst, custom] {
+            let json = serde_json::to_string(&order).unwrap();
+            assert_eq!(
+                serde_json::from_str::<PreferenceOrder>(&json).unwrap(),
+                order
+            );
+        }
+
+        let error = serde_json::from_str::<PreferenceOrder>(
+            r#"{"custom": ["ffdm", "ffdm", "tomo", "sfm", "cem"]}"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("exactly once"), "{error}");
+        assert!(serde_json::from_str::<PreferenceOrder>(r#"{"custom": ["ffdm"]}"#).is_err());
+    }
+
+    #[test]
+    fn test_preference_order_from_str_is_lenient() {
+        assert_eq!(
+            PreferenceOrder::from_str(" Tomo_First "),
+            Some(PreferenceOrder::TomoFirst)
+        );
+        assert_eq!(
+            PreferenceOrder::from_str("DEFAULT"),
+            Some(PreferenceOrder::Default)
+        );
+        assert_eq!(PreferenceOrder::from_str(""), None);
+        assert_eq!(PreferenceOrder::from_str("tomo"), None);
+        assert!("ffdm-first".parse::<PreferenceOrder>().is_err());
+    }
+
+    #[test]
+    fn test_presentation_intent_from_str() {
+        assert_eq!(
+            PresentationIntent::from_str("FOR PRESENTATION"),
+            PresentationIntent::ForPresentation
+        );
+        assert_eq!(
+            PresentationIntent::from_str("for processing "),
+            PresentationIntent::ForProcessing
+        );
+        assert_eq!(
+            PresentationIntent::from_str(""),
+            PresentationIntent::Unknown
+        );
+        assert!(PresentationIntent::ForPresentation.is_for_presentation());
+        assert!(!PresentationIntent::Unknown.is_for_presentation());
+        assert_eq!(
+            PresentationIntent::ForProcessing.to_string(),
+            "FOR PROCESSING"
+        );
+    }
+
     #[test]
     fn test_dbt_object_kind_display() {
         assert_eq!(DbtObjectKind::None.simple_name(), "none");
@@ -559,12 +1242,74 @@ mod tests {
         assert_eq!(DbtObjectKind::Unknown.to_string(), "unknown");
     }
 
+    #[test]
+    fn test_sop_class_kind_from_uid() {
+        let cases = [
+            ("1.2.840.10008.5.1.4.1.1.1.2", SopClassKind::MammoImage),
+            ("1.2.840.10008.5.1.4.1.1.1.2.1", SopClassKind::MammoImage),
+            ("1.2.840.10008.5.1.4.1.1.13.1.4", SopClassKind::MammoImage),
+            ("1.2.840.10008.5.1.4.1.1.13.1.3", SopClassKind::TomoImage),
+            ("1.2.840.10008.5.1.4.1.1.88.50", SopClassKind::CadSr),
+            ("1.2.840.10008.5.1.4.1.1.88.65", SopClassKind::CadSr),
+            (
+                "1.2.840.10008.5.1.4.1.1.11.1",
+                SopClassKind::PresentationState,
+            ),
+            (
+                "1.2.840.10008.5.1.4.1.1.88.59",
+                SopClassKind::KeyObjectSelection,
+            ),
+            ("1.2.840.10008.5.1.4.1.1.7", SopClassKind::SecondaryCapture),
+            (
+                "1.2.840.10008.5.1.4.1.1.7.2",
+                SopClassKind::SecondaryCapture,
+            ),
+            ("1.2.840.10008.5.1.4.1.1.77", SopClassKind::Other),
+            ("1.2.840.10008.5.1.4.1.1.2", SopClassKind::Other),
+            ("", SopClassKind::Other),
+        ];
+        for (uid, expected) in cases {
+            assert_eq!(SopClassKind::from_uid(uid), expected, "{uid}");
+        }
+        assert_eq!(
+            SopClassKind::from_uid(" 1.2.840.10008.5.1.4.1.1.88.50\0"),
+            SopClassKind::CadSr
+        );
+    }
+
+    #[test]
+    fn test_sop_class_kind_is_non_image() {
+        assert!(SopClassKind::CadSr.is_non_image());
+        assert!(SopClassKind::PresentationState.is_non_image());
+        assert!(SopClassKind::KeyObjectSelection.is_non_image());
+        assert!(!SopClassKind::MammoImage.is_non_image());
+        assert!(!SopClassKind::SecondaryCapture.is_non_image());
+        assert!(!SopClassKind::Other.is_non_image());
+        assert_eq!(SopClassKind::CadSr.to_string(), "cad_sr");
+    }
+
+    #[test]
+    fn test_all_lists_every_variant_once() {
+        assert_eq!(MammogramType::all().len(), 6);
+        assert!(MammogramType::all().contains(&MammogramType::Cem));
+        assert_eq!(Laterality::all().len(), 5);
+        assert!(Laterality::all().contains(&Laterality::Bilateral));
+        assert_eq!(ViewPosition::all().len(), 12);
+        assert!(ViewPosition::all().contains(&ViewPosition::Xccl));
+
+        let unique_types: std::collections::HashSet<_> = MammogramType::all().iter().collect();
+        assert_eq!(unique_types.len(), MammogramType::all().len());
+        let unique_views: std::collections::HashSet<_> = ViewPosition::all().iter().collect();
+        assert_eq!(unique_views.len(), ViewPosition::all().len());
+    }
+
     #[test]
     fn test_mammogram_type_ordering() {
         assert!(MammogramType::Tomo < MammogramType::Ffdm);
         assert!(MammogramType::Ffdm < MammogramType::Synth);
         assert!(MammogramType::Synth < MammogramType::Sfm);
-        assert!(MammogramType::Sfm < MammogramType::Unknown);
+        assert!(MammogramType::Sfm < MammogramType::Cem);
+        assert!(MammogramType::Cem < MammogramType::Unknown);
     }
 
     #[test]
@@ -582,29 +1327,67 @@ mod tests {
         assert_eq!(MammogramType::Ffdm.serialized_name(), "ffdm");
         assert_eq!(MammogramType::Synth.serialized_name(), "synth");
         assert_eq!(MammogramType::Sfm.serialized_name(), "sfm");
+        assert_eq!(MammogramType::Cem.serialized_name(), "cem");
         assert_eq!(MammogramType::Synth.simple_name(), "s-view");
         assert_eq!(MammogramType::Synth.to_string(), "s-view");
     }
 
     #[cfg(feature = "json")]
     #[test]
-    fn mammogram_type_json_uses_canonical_machine_names() {
-        let expected = [
-            (MammogramType::Unknown, "unknown"),
-            (MammogramType::Tomo, "tomo"),
-            (MammogramType::Ffdm, "ffdm"),
-            (MammogramType::Synth, "synth"),
-            (MammogramType::Sfm, "sfm"),
-        ];
-
-        for (mammogram_type, serialized_name) in expected {
+    fn mammogram_type_json_matches_simple_name() {
+        assert_eq!(
+            serde_json::to_string(&MammogramType::Synth).unwrap(),
+            "\"s-view\""
+        );
+        for &mammogram_type in MammogramType::all() {
+            let json = serde_json::to_string(&mammogram_type).unwrap();
+            assert_eq!(json, format!("\"{}\"", mammogram_type.simple_name()));
             assert_eq!(
-                serde_json::to_string(&mammogram_type).unwrap(),
-                format!("\"{serialized_name}\"")
+                serde_json::from_str::<MammogramType>(&json).unwrap(),
+                mammogram_type
             );
         }
     }
 
+    #[cfg(feature = "json")]
+    #[test]
+    fn view_position_json_matches_short_str() {
+        for &view in ViewPosition::all() {
+            let json = serde_json::to_string(&view).unwrap();
+            assert_eq!(json, format!("\"{}\"", view.short_str()));
+            assert_eq!(serde_json::from_str::<ViewPosition>(&json).unwrap(), view);
+        }
+        assert_eq!(
+            serde_json::from_str::<ViewPosition>("\"unknown\"").unwrap(),
+            ViewPosition::Unknown
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn enums_deserialize_from_serialized_names() {
+        assert_eq!(
+            serde_json::from_str::<MammogramType>("\"ffdm\"").unwrap(),
+            MammogramType::Ffdm
+        );
+        assert_eq!(
+            serde_json::from_str::<MammogramType>("\"synth\"").unwrap(),
+            MammogramType::Synth
+        );
+        assert_eq!(
+            serde_json::from_str::<Laterality>("\"left\"").unwrap(),
+            Laterality::Left
+        );
+        assert_eq!(
+            serde_json::from_str::<ViewPosition>("\"xccl\"").unwrap(),
+            ViewPosition::Xccl
+        );
+        assert_eq!(
+            serde_json::from_str::<MammographyViewModifier>("\"spot_compression\"").unwrap(),
+            MammographyViewModifier::SpotCompression
+        );
+    }
+
     #[test]
     fn test_laterality_reduce() {
         assert_eq!(
@@ -624,6 +1407,7 @@ mod tests {
         assert!(MammogramType::Ffdm.is_2d_group());
         assert!(MammogramType::Synth.is_2d_group());
         assert!(MammogramType::Sfm.is_2d_group());
+        assert!(MammogramType::Cem.is_2d_group());
         assert!(!MammogramType::Tomo.is_2d_group());
         assert!(!MammogramType::Unknown.is_2d_group());
     }
@@ -651,4 +1435,18 @@ mod tests {
         assert!(ViewPosition::Xccl.is_cc_like());
         assert!(!ViewPosition::Mlo.is_cc_like());
     }
+
+    #[test]
+    fn test_view_position_normalized_standard() {
+        assert_eq!(ViewPosition::Xccl.normalized_standard(), ViewPosition::Cc);
+        assert_eq!(ViewPosition::Xccm.normalized_standard(), ViewPosition::Cc);
+        assert_eq!(ViewPosition::Ml.normalized_standard(), ViewPosition::Mlo);
+        assert_eq!(ViewPosition::Lm.normalized_standard(), ViewPosition::Mlo);
+        assert_eq!(ViewPosition::Mlo.normalized_standard(), ViewPosition::Mlo);
+        assert_eq!(ViewPosition::Sio.normalized_standard(), ViewPosition::Sio);
+        assert_eq!(
+            ViewPosition::Unknown.normalized_standard(),
+            ViewPosition::Unknown
+        );
+    }
 }
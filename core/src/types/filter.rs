@@ -1,6 +1,12 @@
 use crate::types::{DbtObjectKind, MammogramType};
 use std::collections::HashSet;
 
+/// PatientName words matched when [`FilterConfig::exclude_phantoms`] is set
+///
+/// These match whole PatientName components only, so surnames such as
+/// "TESTA" are kept.
+pub const PHANTOM_PATIENT_NAME_PATTERNS: [&str; 2] = ["PHANTOM", "TEST"];
+
 /// Configuration for filtering mammogram records during selection
 ///
 /// Hard-exclusion filters remove records from consideration. Ranking options,
@@ -28,8 +34,13 @@ use std::collections::HashSet;
 /// assert_eq!(filter.allowed_types.unwrap().len(), 1);
 /// assert_eq!(filter.allowed_dbt_object_kinds.unwrap().len(), 1);
 /// ```
+///
+/// With the `json` feature, fields missing from serialized input fall back to
+/// [`FilterConfig::default`], and unknown fields are rejected so a misspelled
+/// key cannot silently leave a filter at its default.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json", serde(default, deny_unknown_fields))]
 pub struct FilterConfig {
     /// Allowed mammogram types (whitelist approach)
     /// If None, all types are allowed. If Some, only types in the set are included.
@@ -46,12 +57,70 @@ pub struct FilterConfig {
     /// Exclude non-standard views (only CC and MLO)
     pub exclude_non_standard_views: bool,
 
+    /// Exclude spot compression and magnification views
+    ///
+    /// Without this filter such views remain available as fallbacks that
+    /// rank behind unmodified views.
+    #[cfg_attr(feature = "json", serde(default))]
+    pub exclude_spot_mag: bool,
+
     /// Exclude "FOR PROCESSING" views
     pub exclude_for_processing: bool,
 
+    /// Require PresentationIntentType to be explicitly "FOR PRESENTATION"
+    ///
+    /// Stricter than `exclude_for_processing`: records with a missing or
+    /// unrecognized intent are also dropped.
+    #[cfg_attr(feature = "json", serde(default))]
+    pub require_for_presentation: bool,
+
+    /// Minimum NumberOfFrames a record must have to be included
+    ///
+    /// Combined with a TOMO-only `allowed_types` whitelist, a value of 2
+    /// isolates genuine multi-frame DBT volumes. If None, no minimum applies.
+    #[cfg_attr(feature = "json", serde(default))]
+    pub min_frames: Option<i32>,
+
+    /// Minimum image area (Rows × Columns) a record must have to be included
+    ///
+    /// Drops scouts and thumbnails; records with unknown dimensions are
+    /// dropped too. If None, no minimum applies.
+    #[cfg_attr(feature = "json", serde(default))]
+    pub min_image_area: Option<u32>,
+
+    /// Allowed manufacturers (whitelist approach, case-insensitive)
+    /// If None, all manufacturers are allowed. If Some, records whose
+    /// Manufacturer is missing or not in the set are excluded.
+    #[cfg_attr(feature = "json", serde(default))]
+    pub allowed_manufacturers: Option<HashSet<String>>,
+
+    /// Excluded scanner models (blacklist approach, case-insensitive)
+    /// Records without ManufacturerModelName are kept.
+    #[cfg_attr(feature = "json", serde(default))]
+    pub excluded_models: Option<HashSet<String>>,
+
+    /// Excluded PatientName substrings (blacklist approach, case-insensitive)
+    ///
+    /// Drops phantom, QC, and test scans. Records without PatientName are kept.
+    #[cfg_attr(feature = "json", serde(default))]
+    pub exclude_patient_name_patterns: Option<Vec<String>>,
+
+    /// Exclude phantom and test scans by PatientName
+    ///
+    /// Drops records whose PatientName has a component (split on `^`, spaces,
+    /// and other separators) equal to one of [`PHANTOM_PATIENT_NAME_PATTERNS`].
+    #[cfg_attr(feature = "json", serde(default))]
+    pub exclude_phantoms: bool,
+
     /// Exclude secondary capture images
     pub exclude_secondary_capture: bool,
 
+    /// Exclude scout/localizer frames
+    pub exclude_localizers: bool,
+
+    /// Exclude specimen images
+    pub exclude_specimens: bool,
+
     /// Exclude non-MG modality
     pub exclude_non_mg_modality: bool,
 
@@ -63,6 +132,9 @@ pub struct FilterConfig {
 
     /// Require all selected views to come from a common modality group (2D or DBT)
     pub require_common_modality: bool,
+
+    /// Select the most recent StudyDate's study, ahead of study completeness
+    pub prefer_latest_study: bool,
 }
 
 impl Default for FilterConfig {
@@ -72,12 +144,23 @@ impl Default for FilterConfig {
             allowed_dbt_object_kinds: None, // Allow all DBT object kinds by default
             exclude_implants: false,
             exclude_non_standard_views: false,
+            exclude_spot_mag: false,
             exclude_for_processing: true, // Default: exclude FOR PROCESSING
+            require_for_presentation: false,
+            min_frames: None,
+            min_image_area: None,
+            allowed_manufacturers: None,
+            excluded_models: None,
+            exclude_patient_name_patterns: None,
+            exclude_phantoms: false,
             exclude_secondary_capture: true, // Default: exclude secondary capture
-            exclude_non_mg_modality: true, // Default: exclude non-MG
+            exclude_localizers: true,        // Default: exclude localizers
+            exclude_specimens: true,         // Default: exclude specimens
+            exclude_non_mg_modality: true,   // Default: exclude non-MG
             exclude_lossy_compressed: false,
             deprioritize_lossy_compressed: true,
             require_common_modality: false,
+            prefer_latest_study: false,
         }
     }
 }
@@ -103,12 +186,23 @@ impl FilterConfig {
             allowed_dbt_object_kinds: None,
             exclude_implants: false,
             exclude_non_standard_views: false,
+            exclude_spot_mag: false,
             exclude_for_processing: false,
+            require_for_presentation: false,
+            min_frames: None,
+            min_image_area: None,
+            allowed_manufacturers: None,
+            excluded_models: None,
+            exclude_patient_name_patterns: None,
+            exclude_phantoms: false,
             exclude_secondary_capture: false,
+            exclude_localizers: false,
+            exclude_specimens: false,
             exclude_non_mg_modality: false,
             exclude_lossy_compressed: false,
             deprioritize_lossy_compressed: true,
             require_common_modality: false,
+            prefer_latest_study: false,
         }
     }
 
@@ -180,6 +274,21 @@ impl FilterConfig {
         self
     }
 
+    /// Builder: Exclude spot compression and magnification views
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default().exclude_spot_mag(true);
+    /// assert!(filter.exclude_spot_mag);
+    /// ```
+    pub fn exclude_spot_mag(mut self, exclude: bool) -> Self {
+        self.exclude_spot_mag = exclude;
+        self
+    }
+
     /// Builder: Exclude FOR PROCESSING
     ///
     /// # Example
@@ -195,6 +304,168 @@ impl FilterConfig {
         self
     }
 
+    /// Builder: Require explicit FOR PRESENTATION intent
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default().require_for_presentation(true);
+    /// assert!(filter.require_for_presentation);
+    /// ```
+    pub fn require_for_presentation(mut self, require: bool) -> Self {
+        self.require_for_presentation = require;
+        self
+    }
+
+    /// Builder: Set minimum number of frames
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::{FilterConfig, MammogramType};
+    ///
+    /// // Keep only multi-frame TOMO volumes
+    /// let filter = FilterConfig::default()
+    ///     .with_allowed_types([MammogramType::Tomo].into())
+    ///     .with_min_frames(2);
+    /// assert_eq!(filter.min_frames, Some(2));
+    /// ```
+    pub fn with_min_frames(mut self, min_frames: i32) -> Self {
+        self.min_frames = Some(min_frames);
+        self
+    }
+
+    /// Builder: Set minimum image area in pixels
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default().with_min_image_area(1_000_000);
+    /// assert_eq!(filter.min_image_area, Some(1_000_000));
+    /// ```
+    pub fn with_min_image_area(mut self, min_image_area: u32) -> Self {
+        self.min_image_area = Some(min_image_area);
+        self
+    }
+
+    /// Builder: Set allowed manufacturers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default().with_allowed_manufacturers(["HOLOGIC, Inc.".to_string()].into());
+    /// assert!(filter.manufacturer_allowed(Some("hologic, inc.")));
+    /// assert!(!filter.manufacturer_allowed(None));
+    /// ```
+    pub fn with_allowed_manufacturers(mut self, manufacturers: HashSet<String>) -> Self {
+        self.allowed_manufacturers = Some(manufacturers);
+        self
+    }
+
+    /// Builder: Set excluded scanner models
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default().with_excluded_models(["Selenia".to_string()].into());
+    /// assert!(filter.model_excluded(Some("SELENIA")));
+    /// assert!(!filter.model_excluded(None));
+    /// ```
+    pub fn with_excluded_models(mut self, models: HashSet<String>) -> Self {
+        self.excluded_models = Some(models);
+        self
+    }
+
+    /// Builder: Set excluded PatientName substrings
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default()
+    ///     .with_excluded_patient_name_patterns(vec!["daily qa".to_string()]);
+    /// assert!(filter.patient_name_excluded(Some("DAILY QA^MORNING")));
+    /// assert!(!filter.patient_name_excluded(None));
+    /// ```
+    pub fn with_excluded_patient_name_patterns(mut self, patterns: Vec<String>) -> Self {
+        self.exclude_patient_name_patterns = Some(patterns);
+        self
+    }
+
+    /// Builder: Exclude phantom and test scans by PatientName
+    ///
+    /// Matches [`PHANTOM_PATIENT_NAME_PATTERNS`] as whole name components,
+    /// alongside any `exclude_patient_name_patterns`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default().exclude_phantoms();
+    /// assert!(filter.patient_name_excluded(Some("ACR PHANTOM")));
+    /// assert!(!filter.patient_name_excluded(Some("DOE^JANE")));
+    /// assert!(!filter.patient_name_excluded(Some("TESTA^MARIA")));
+    /// ```
+    pub fn exclude_phantoms(mut self) -> Self {
+        self.exclude_phantoms = true;
+        self
+    }
+
+    /// Whether a Manufacturer value passes `allowed_manufacturers`
+    pub fn manufacturer_allowed(&self, manufacturer: Option<&str>) -> bool {
+        match &self.allowed_manufacturers {
+            None => true,
+            Some(allowed) => manufacturer.is_some_and(|value| contains_ignore_case(allowed, value)),
+        }
+    }
+
+    /// Whether a ManufacturerModelName value is in `excluded_models`
+    pub fn model_excluded(&self, model: Option<&str>) -> bool {
+        match (&self.excluded_models, model) {
+            (Some(excluded), Some(value)) => contains_ignore_case(excluded, value),
+            _ => false,
+        }
+    }
+
+    /// Whether a PatientName value is dropped by `exclude_patient_name_patterns`
+    /// or `exclude_phantoms`
+    ///
+    /// User patterns match as substrings; phantom patterns must match a whole
+    /// name component.
+    pub fn patient_name_excluded(&self, patient_name: Option<&str>) -> bool {
+        let Some(name) = patient_name else {
+            return false;
+        };
+        let name = name.to_ascii_lowercase();
+        let phantom = self.exclude_phantoms
+            && name
+                .split(|character: char| !character.is_ascii_alphanumeric())
+                .any(|component| {
+                    PHANTOM_PATIENT_NAME_PATTERNS
+                        .iter()
+                        .any(|pattern| component.eq_ignore_ascii_case(pattern))
+                });
+        phantom
+            || self
+                .exclude_patient_name_patterns
+                .iter()
+                .flatten()
+                .any(|pattern| {
+                    let pattern = pattern.trim();
+                    !pattern.is_empty() && name.contains(&pattern.to_ascii_lowercase())
+                })
+    }
+
     /// Builder: Exclude secondary capture
     ///
     /// # Example
@@ -210,6 +481,36 @@ impl FilterConfig {
         self
     }
 
+    /// Builder: Exclude scout/localizer frames
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default().exclude_localizers(false);
+    /// assert!(!filter.exclude_localizers);
+    /// ```
+    pub fn exclude_localizers(mut self, exclude: bool) -> Self {
+        self.exclude_localizers = exclude;
+        self
+    }
+
+    /// Builder: Exclude specimen images
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default().exclude_specimens(false);
+    /// assert!(!filter.exclude_specimens);
+    /// ```
+    pub fn exclude_specimens(mut self, exclude: bool) -> Self {
+        self.exclude_specimens = exclude;
+        self
+    }
+
     /// Builder: Exclude non-MG modality
     ///
     /// # Example
@@ -260,6 +561,12 @@ impl FilterConfig {
     /// When enabled, enforces that all selected views come from the same
     /// modality group: 2D (FFDM, SYNTH, SFM) or DBT (TOMO).
     ///
+    /// Selection is re-run separately on the 2D and DBT candidates and the
+    /// group covering more standard views wins. When the groups split evenly
+    /// (for example 2–2), the group with fewer lossy selections wins if lossy
+    /// deprioritization is enabled, then the group whose selected types rank
+    /// better under the preference order, and finally 2D.
+    ///
     /// # Example
     ///
     /// ```
@@ -272,6 +579,34 @@ impl FilterConfig {
         self.require_common_modality = require;
         self
     }
+
+    /// Builder: Prefer the most recent study when records span several studies
+    ///
+    /// When enabled, study selection picks the study with the newest StudyDate
+    /// even if an older study covers more views; completeness only ranks
+    /// studies from the same date. Candidates for the same view from different
+    /// studies are ranked newest first before the StudyInstanceUID ordering.
+    /// Undated studies rank after dated ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mammocat_core::FilterConfig;
+    ///
+    /// let filter = FilterConfig::default().prefer_latest_study(true);
+    /// assert!(filter.prefer_latest_study);
+    /// ```
+    pub fn prefer_latest_study(mut self, prefer: bool) -> Self {
+        self.prefer_latest_study = prefer;
+        self
+    }
+}
+
+fn contains_ignore_case(values: &HashSet<String>, value: &str) -> bool {
+    let value = value.trim();
+    values
+        .iter()
+        .any(|candidate| candidate.trim().eq_ignore_ascii_case(value))
 }
 
 #[cfg(test)]
@@ -285,8 +620,17 @@ mod tests {
         assert!(config.allowed_dbt_object_kinds.is_none());
         assert!(!config.exclude_implants);
         assert!(!config.exclude_non_standard_views);
+        assert!(!config.exclude_spot_mag);
         assert!(config.exclude_for_processing);
+        assert!(!config.require_for_presentation);
+        assert!(config.min_frames.is_none());
+        assert!(config.min_image_area.is_none());
+        assert!(config.allowed_manufacturers.is_none());
+        assert!(config.excluded_models.is_none());
+        assert!(config.exclude_patient_name_patterns.is_none());
         assert!(config.exclude_secondary_capture);
+        assert!(config.exclude_localizers);
+        assert!(config.exclude_specimens);
         assert!(config.exclude_non_mg_modality);
         assert!(!config.exclude_lossy_compressed);
         assert!(config.deprioritize_lossy_compressed);
@@ -301,7 +645,10 @@ mod tests {
         assert!(!config.exclude_implants);
         assert!(!config.exclude_non_standard_views);
         assert!(!config.exclude_for_processing);
+        assert!(!config.require_for_presentation);
         assert!(!config.exclude_secondary_capture);
+        assert!(!config.exclude_localizers);
+        assert!(!config.exclude_specimens);
         assert!(!config.exclude_non_mg_modality);
         assert!(!config.exclude_lossy_compressed);
         assert!(config.deprioritize_lossy_compressed);
@@ -340,6 +687,65 @@ mod tests {
         assert!(!config.exclude_implants);
     }
 
+    #[test]
+    fn test_patient_name_patterns_match_case_insensitive_substrings() {
+        let config = FilterConfig::default()
+            .with_excluded_patient_name_patterns(vec!["qc".to_string(), " ".to_string()])
+            .exclude_phantoms();
+
+        assert!(config.exclude_phantoms);
+        assert_eq!(
+            config.exclude_patient_name_patterns.as_deref(),
+            Some(&["qc".to_string(), " ".to_string()][..])
+        );
+        assert!(config.patient_name_excluded(Some("ACR Phantom")));
+        assert!(config.patient_name_excluded(Some("DAILY^QC")));
+        assert!(config.patient_name_excluded(Some("test patient")));
+        assert!(config.patient_name_excluded(Some("QA^TEST_01")));
+        assert!(!config.patient_name_excluded(Some("DOE^JANE")));
+        assert!(!config.patient_name_excluded(None));
+        assert!(!FilterConfig::default().patient_name_excluded(Some("ACR PHANTOM")));
+    }
+
+    #[test]
+    fn test_exclude_phantoms_keeps_surnames_containing_test() {
+        let config = FilterConfig::default().exclude_phantoms();
+        assert!(!config.patient_name_excluded(Some("TESTA^MARIA")));
+        assert!(!config.patient_name_excluded(Some("CONTESTABILE^LUCA")));
+
+        let substring =
+            FilterConfig::default().with_excluded_patient_name_patterns(vec!["test".to_string()]);
+        assert!(substring.patient_name_excluded(Some("TESTA^MARIA")));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_round_trip() {
+        let config = FilterConfig::default()
+            .with_allowed_types([MammogramType::Ffdm, MammogramType::Synth].into())
+            .with_allowed_dbt_object_kinds([DbtObjectKind::Volume].into())
+            .exclude_implants(true)
+            .exclude_spot_mag(true)
+            .require_for_presentation(true)
+            .with_min_frames(2)
+            .require_common_modality(true);
+
+        let json = serde_json::to_string(&config).unwrap();
+        let restored: FilterConfig = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, config);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_rejects_unknown_fields() {
+        let error = serde_json::from_str::<FilterConfig>(r#"{"exclude_implant": true}"#)
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("unknown field `exclude_implant`"), "{error}");
+    }
+
     #[test]
     fn test_allowed_types_whitelist() {
         let mut allowed = HashSet::new();
@@ -8,7 +8,7 @@ use std::fmt;
 /// - `flavor`: Third element (optional)
 /// - `extras`: Additional elements beyond the first three
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "json", derive(serde::Serialize))]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImageType {
     pub pixels: String,
     pub exam: String,
@@ -3,9 +3,11 @@
 //! This module provides the fundamental types used throughout the mammocat library:
 //! - [`MammogramType`]: Classification of mammogram imaging types (FFDM, TOMO, SYNTH, SFM)
 //! - [`DbtObjectKind`]: DBT storage representation (volume, slice, unknown, or none)
+//! - [`SopClassKind`]: SOP class family (mammo image, CAD SR, presentation state, ...)
 //! - [`Laterality`]: Breast laterality (Left, Right, Bilateral)
 //! - [`ViewPosition`]: View positions (CC, MLO, etc.)
 //! - [`MammogramView`]: Combined laterality and view position
+//! - [`PresentationIntent`]: Explicit FOR PRESENTATION / FOR PROCESSING intent
 //! - [`ImageType`]: Decomposed DICOM ImageType field
 //! - [`PreferenceOrder`]: Strategies for selecting preferred mammograms
 //! - [`FilterConfig`]: Configuration for filtering mammogram records during selection
@@ -18,9 +20,9 @@ mod view;
 
 pub use enums::{
     DbtObjectKind, Laterality, MammogramType, MammographyViewModifier, PhotometricInterpretation,
-    PreferenceOrder, ViewPosition,
+    PreferenceOrder, PresentationIntent, SopClassKind, ViewPosition,
 };
-pub use filter::FilterConfig;
+pub use filter::{FilterConfig, PHANTOM_PATIENT_NAME_PATTERNS};
 pub use image_type::ImageType;
 pub use pixel_spacing::PixelSpacing;
 pub use view::{MammogramView, STANDARD_MAMMO_VIEWS};
@@ -5,7 +5,7 @@ use std::fmt;
 /// Represents the physical spacing between adjacent pixels
 /// in the detector/imager, measured in mm.
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[cfg_attr(feature = "json", derive(serde::Serialize))]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 pub struct PixelSpacing {
     pub row: f64,
     #[cfg_attr(feature = "json", serde(rename = "column"))]
@@ -25,6 +25,7 @@ impl PixelSpacing {
     /// - "0.1 0.1"
     /// - "[0.1, 0.1]"
     /// - Exponential notation: "1.5e-4 1.5e-4"
+    /// - A single value for square pixels: "0.1" or "[0.1]"
     ///
     /// # Errors
     ///
@@ -68,9 +69,11 @@ fn spacing_values(s: &str) -> Result<[&str; 2], String> {
         contents.split_whitespace().collect()
     };
 
-    values
-        .try_into()
-        .map_err(|_| "PixelSpacing must contain exactly two values".to_string())
+    match values[..] {
+        [value] => Ok([value, value]),
+        [row, col] => Ok([row, col]),
+        _ => Err("PixelSpacing must contain one or two values".to_string()),
+    }
 }
 
 fn parse_spacing_value(
@@ -149,7 +152,24 @@ mod tests {
     fn test_parse_invalid() {
         assert!(PixelSpacing::parse("invalid").is_err());
         assert!(PixelSpacing::parse("").is_err());
-        assert!(PixelSpacing::parse("0.1").is_err());
+        assert!(PixelSpacing::parse("[]").is_err());
+    }
+
+    #[test]
+    fn test_parse_single_value_as_square_pixels() {
+        assert_eq!(
+            PixelSpacing::parse("0.1").unwrap(),
+            PixelSpacing::new(0.1, 0.1)
+        );
+        assert_eq!(
+            PixelSpacing::parse("[0.094]").unwrap(),
+            PixelSpacing::new(0.094, 0.094)
+        );
+        assert!(PixelSpacing::parse("0").is_err());
+        assert_eq!(
+            PixelSpacing::parse_with_dimensions("0", Some(1), Some(1)).unwrap(),
+            PixelSpacing::new(0.0, 0.0)
+        );
     }
 
     #[test]
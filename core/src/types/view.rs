@@ -31,6 +31,34 @@ impl MammogramView {
     pub fn is_cc_like(&self) -> bool {
         self.view.is_cc_like()
     }
+
+    /// Returns this view with its position mapped onto CC or MLO when CC-like or MLO-like
+    ///
+    /// See [`ViewPosition::normalized_standard`].
+    pub fn normalized_standard(&self) -> Self {
+        Self::new(self.laterality, self.view.normalized_standard())
+    }
+
+    /// Returns an uppercase abbreviation for clinical reports, e.g. "L MLO"
+    ///
+    /// Unlike the compact [`Display`](fmt::Display) form ("lmlo"), the
+    /// laterality and view are separated by a space. Unknown parts are
+    /// omitted.
+    pub fn display_abbrev(&self) -> String {
+        let laterality = match self.laterality {
+            Laterality::Left => "L",
+            Laterality::Right => "R",
+            Laterality::Bilateral => "B",
+            Laterality::None | Laterality::Unknown => "",
+        };
+        let view = self.view.short_str().to_uppercase();
+
+        [laterality, view.as_str()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
 }
 
 impl fmt::Display for MammogramView {
@@ -90,6 +118,31 @@ mod tests {
         assert!(!view.is_cc_like());
     }
 
+    #[test]
+    fn test_normalized_standard() {
+        let view = MammogramView::new(Laterality::Left, ViewPosition::Xccl);
+        assert_eq!(
+            view.normalized_standard(),
+            MammogramView::new(Laterality::Left, ViewPosition::Cc)
+        );
+
+        let view = MammogramView::new(Laterality::Right, ViewPosition::Sio);
+        assert_eq!(view.normalized_standard(), view);
+    }
+
+    #[test]
+    fn test_display_abbrev() {
+        let view = MammogramView::new(Laterality::Right, ViewPosition::Cc);
+        assert_eq!(view.display_abbrev(), "R CC");
+        assert_eq!(view.to_string(), "rcc");
+
+        let view = MammogramView::new(Laterality::Left, ViewPosition::Xccl);
+        assert_eq!(view.display_abbrev(), "L XCCL");
+
+        let view = MammogramView::new(Laterality::Unknown, ViewPosition::Mlo);
+        assert_eq!(view.display_abbrev(), "MLO");
+    }
+
     #[test]
     fn test_standard_views_constant() {
         assert_eq!(STANDARD_MAMMO_VIEWS.len(), 4);
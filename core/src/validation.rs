@@ -15,8 +15,7 @@ use dicom_object::{open_file, FileDicomObject, FileMetaTable, InMemDicomObject,
 
 use crate::api::{MammogramExtractor, MammogramMetadata};
 use crate::completion::{plan_completion, CompletionOptions};
-use crate::dicom_files::collect_dicom_files;
-use crate::extraction::extract_view_descriptor;
+use crate::dicom_files::{collect_dicom_files, gzipped_dicom_reader, is_gzipped_dicom_path};
 use crate::extraction::tags::{
     get_string_value, BITS_ALLOCATED, BITS_STORED, COLUMNS, DICOM_MAGIC_BYTES, HIGH_BIT,
     IMAGER_PIXEL_SPACING, IMAGE_LATERALITY, IMAGE_TYPE, LOSSY_IMAGE_COMPRESSION,
@@ -24,6 +23,7 @@ use crate::extraction::tags::{
     PIXEL_DATA_TAG, PIXEL_REPRESENTATION, PIXEL_SPACING, ROWS, SAMPLES_PER_PIXEL,
     SERIES_INSTANCE_UID, SOP_CLASS_UID, SOP_INSTANCE_UID, STUDY_INSTANCE_UID, VIEW_POSITION,
 };
+use crate::extraction::{detect_mixed_laterality, extract_view_descriptor};
 use crate::selection::{
     get_preferred_views_filtered, lossy_compression_source, refine_dbt_object_classification,
     LossyCompressionSource, MammogramRecord,
@@ -364,6 +364,8 @@ pub struct MammographyValidationReport {
     pub is_magnified: Option<bool>,
     pub is_implant_displaced: Option<bool>,
     pub is_secondary_capture: Option<bool>,
+    pub is_localizer: Option<bool>,
+    pub is_specimen: Option<bool>,
     pub manufacturer: Option<String>,
     pub model: Option<String>,
     pub concatenation_uid: Option<String>,
@@ -1112,7 +1114,7 @@ fn apply_refined_record_to_file_report(
     }
 
     reset_selection_eligibility(report);
-    validate_selection_eligibility(report, Some(metadata), filter_config);
+    validate_selection_eligibility(report, Some(metadata), Some(record), filter_config);
     report.finalize();
 }
 
@@ -1153,6 +1155,23 @@ fn validate_file_with_record(path: &Path, options: &ValidationOptions) -> FileVa
         };
     }
 
+    if is_gzipped_dicom_path(path) {
+        let mut bytes = Vec::new();
+        return match gzipped_dicom_reader(path)
+            .and_then(|mut reader| reader.read_to_end(&mut bytes))
+        {
+            Ok(_) => validate_dicom_bytes_with_record(path.to_path_buf(), &bytes, options),
+            Err(source) => validate_open_result_with_record(
+                path.to_path_buf(),
+                Err::<FileDicomObject<InMemDicomObject>, _>(format!(
+                    "gzip decompression failed: {source}"
+                )),
+                options,
+                None,
+            ),
+        };
+    }
+
     match probe_pixel_data(path) {
         Ok(pixel_data_state) => validate_open_result_with_record(
             path.to_path_buf(),
@@ -1230,7 +1249,12 @@ where
     let record = metadata.as_ref().and_then(|metadata| {
         MammogramRecord::from_dicom_with_metadata(source_path, &dcm, metadata.clone()).ok()
     });
-    validate_selection_eligibility(&mut report, metadata.as_ref(), &options.filter_config);
+    validate_selection_eligibility(
+        &mut report,
+        metadata.as_ref(),
+        record.as_ref(),
+        &options.filter_config,
+    );
 
     report.finalize();
     FileValidationOutcome { report, record }
@@ -1692,6 +1716,8 @@ fn collect_mammography_metadata(
     report.mammography.is_magnified = Some(metadata.is_magnified());
     report.mammography.is_implant_displaced = Some(metadata.is_implant_displaced());
     report.mammography.is_secondary_capture = Some(metadata.is_secondary_capture);
+    report.mammography.is_localizer = Some(metadata.is_localizer);
+    report.mammography.is_specimen = Some(metadata.is_specimen);
     report.mammography.manufacturer = metadata.manufacturer.clone();
     report.mammography.model = metadata.model.clone();
     report.mammography.concatenation_uid = metadata.concatenation_uid.clone();
@@ -1701,6 +1727,14 @@ fn collect_mammography_metadata(
 
     validate_image_type(report, dcm, profile);
     validate_laterality_value(report, metadata.laterality, profile);
+    if let Some(conflict) = detect_mixed_laterality(dcm) {
+        report.record_plain(
+            MessageKind::Warning,
+            "mixed_laterality",
+            "Laterality",
+            conflict,
+        );
+    }
     validate_view_value(report, metadata.view_position, profile);
     let descriptor = extract_view_descriptor(dcm);
     for conflict in descriptor.conflicts {
@@ -1790,6 +1824,7 @@ fn validate_pixel_spacing_metadata(
 fn validate_selection_eligibility(
     report: &mut FileValidationReport,
     metadata: Option<&MammogramMetadata>,
+    record: Option<&MammogramRecord>,
     filter_config: &FilterConfig,
 ) {
     let Some(metadata) = metadata else {
@@ -1822,12 +1857,49 @@ fn validate_selection_eligibility(
     if filter_config.exclude_non_standard_views && !metadata.is_standard_view() {
         filtered_by.push("only_standard_views".to_string());
     }
+    if filter_config.exclude_spot_mag && (metadata.is_spot_compression() || metadata.is_magnified())
+    {
+        filtered_by.push("exclude_spot_mag".to_string());
+    }
     if filter_config.exclude_for_processing && metadata.is_for_processing {
         filtered_by.push("exclude_for_processing".to_string());
     }
+    if filter_config.require_for_presentation && !metadata.presentation_intent.is_for_presentation()
+    {
+        filtered_by.push("require_for_presentation".to_string());
+    }
+    if filter_config
+        .min_frames
+        .is_some_and(|min_frames| metadata.number_of_frames < min_frames)
+    {
+        filtered_by.push("min_frames".to_string());
+    }
+    if !filter_config.manufacturer_allowed(metadata.manufacturer.as_deref()) {
+        filtered_by.push("allowed_manufacturers".to_string());
+    }
+    if filter_config.model_excluded(metadata.model.as_deref()) {
+        filtered_by.push("excluded_models".to_string());
+    }
+    if filter_config.patient_name_excluded(record.and_then(|record| record.patient_name.as_deref()))
+    {
+        filtered_by.push("exclude_patient_name_patterns".to_string());
+    }
+    if filter_config.min_image_area.is_some_and(|min_area| {
+        record
+            .and_then(MammogramRecord::image_area)
+            .is_none_or(|area| area < min_area)
+    }) {
+        filtered_by.push("min_image_area".to_string());
+    }
     if filter_config.exclude_secondary_capture && metadata.is_secondary_capture {
         filtered_by.push("exclude_secondary_capture".to_string());
     }
+    if filter_config.exclude_localizers && metadata.is_localizer {
+        filtered_by.push("exclude_localizers".to_string());
+    }
+    if filter_config.exclude_specimens && metadata.is_specimen {
+        filtered_by.push("exclude_specimens".to_string());
+    }
     if filter_config.exclude_non_mg_modality {
         match &metadata.modality {
             Some(modality) if modality.eq_ignore_ascii_case("MG") => {}
@@ -2413,7 +2485,7 @@ mod tests {
 
     use crate::extraction::tags::{
         ACQUISITION_DEVICE_PROCESSING_DESCRIPTION, CONCATENATION_UID, LATERALITY,
-        NUMBER_OF_TOMOSYNTHESIS_SOURCE_IMAGES, PRESENTATION_INTENT_TYPE,
+        NUMBER_OF_TOMOSYNTHESIS_SOURCE_IMAGES, PATIENT_NAME, PRESENTATION_INTENT_TYPE,
         SOP_INSTANCE_UID_OF_CONCATENATION_SOURCE, TOMO_CLASS, VIEW_CODE_SEQUENCE,
         VIEW_MODIFIER_CODE_SEQUENCE, VOLUMETRIC_PROPERTIES, VOLUME_BASED_CALCULATION_TECHNIQUE,
     };
@@ -2540,6 +2612,14 @@ mod tests {
     fn validate_object(
         dcm: &mut FileDicomObject<InMemDicomObject>,
         profile: ValidationProfile,
+    ) -> FileValidationReport {
+        validate_object_with_filter(dcm, profile, &FilterConfig::default())
+    }
+
+    fn validate_object_with_filter(
+        dcm: &mut FileDicomObject<InMemDicomObject>,
+        profile: ValidationProfile,
+        filter_config: &FilterConfig,
     ) -> FileValidationReport {
         let mut report = FileValidationReport::new(Path::new("test.dcm"), profile);
         collect_file_meta(&mut report, dcm);
@@ -2548,7 +2628,20 @@ mod tests {
         validate_pixel_fields(&mut report, dcm, profile, None);
         validate_canonical_completion(&mut report, dcm);
         let metadata = validate_extraction(&mut report, dcm, profile, false);
-        validate_selection_eligibility(&mut report, metadata.as_ref(), &FilterConfig::default());
+        let record = metadata.as_ref().and_then(|metadata| {
+            MammogramRecord::from_dicom_with_metadata(
+                PathBuf::from("test.dcm"),
+                dcm,
+                metadata.clone(),
+            )
+            .ok()
+        });
+        validate_selection_eligibility(
+            &mut report,
+            metadata.as_ref(),
+            record.as_ref(),
+            filter_config,
+        );
         report.finalize();
         report
     }
@@ -2597,6 +2690,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn gzipped_file_validation_reads_decompressed_object() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("synthetic.dcm");
+        valid_metadata_object().write_to_file(&path).unwrap();
+        let gz_path = temp_dir.path().join("synthetic.dcm.gz");
+        let mut encoder = GzEncoder::new(File::create(&gz_path).unwrap(), Compression::default());
+        encoder.write_all(&std::fs::read(&path).unwrap()).unwrap();
+        encoder.finish().unwrap();
+        let options = ValidationOptions::default();
+
+        let plain = validate_file_with_record(&path, &options).report;
+        let gzipped = validate_file_with_record(&gz_path, &options).report;
+
+        assert!(gzipped.is_valid(), "{:?}", gzipped.errors);
+        assert_eq!(gzipped.status, plain.status);
+        assert_eq!(gzipped.selection.eligible, plain.selection.eligible);
+
+        std::fs::write(&gz_path, b"not gzip").unwrap();
+        let corrupt = validate_file_with_record(&gz_path, &options).report;
+        assert!(error_codes(&corrupt).contains("dicom_read_failed"));
+    }
+
     #[test]
     fn metadata_only_validation_rejects_truncated_native_pixel_data() {
         const PIXEL_VALUE_LENGTH: u64 = 64 * 2;
@@ -2754,6 +2873,29 @@ mod tests {
         assert!(warning_codes(&report).contains("selection_ranking_warning"));
     }
 
+    #[test]
+    fn selection_eligibility_applies_record_level_filters() {
+        let mut dcm = valid_metadata_object();
+        put_str(&mut dcm, PATIENT_NAME, "ACR PHANTOM");
+        let filter_config = FilterConfig::default()
+            .with_excluded_patient_name_patterns(vec!["phantom".to_string()])
+            .with_min_image_area(1_000_000);
+
+        let report =
+            validate_object_with_filter(&mut dcm, ValidationProfile::Selection, &filter_config);
+
+        assert!(!report.selection.eligible);
+        assert_eq!(
+            report.selection.filtered_by,
+            vec!["exclude_patient_name_patterns", "min_image_area"]
+        );
+        assert!(
+            validate_object(&mut dcm, ValidationProfile::Selection)
+                .selection
+                .eligible
+        );
+    }
+
     #[test]
     fn fixed_iod_conflicts_are_checked_through_completion_registry() {
         let mut dcm = valid_metadata_object();
@@ -2984,6 +3126,24 @@ mod tests {
         assert!(error_codes(&report).contains("unknown_laterality"));
     }
 
+    #[test]
+    fn validation_warns_mixed_laterality() {
+        let mut dcm = valid_metadata_object();
+        put_str(&mut dcm, IMAGE_LATERALITY, "L");
+        put_str(&mut dcm, LATERALITY, "R");
+
+        let report = validate_object(&mut dcm, ValidationProfile::Selection);
+
+        assert!(warning_codes(&report).contains("mixed_laterality"));
+
+        let mut agreeing = valid_metadata_object();
+        put_str(&mut agreeing, IMAGE_LATERALITY, "L");
+        put_str(&mut agreeing, LATERALITY, "L");
+        let report = validate_object(&mut agreeing, ValidationProfile::Selection);
+
+        assert!(!warning_codes(&report).contains("mixed_laterality"));
+    }
+
     #[test]
     fn extraction_profile_warns_unknown_view() {
         let mut dcm = valid_metadata_object();
@@ -44,6 +44,8 @@ export interface MammogramMetadata {
   concatenationUid: string | null
   sopInstanceUidOfConcatenationSource: string | null
   isSecondaryCapture: boolean
+  isLocalizer: boolean
+  isSpecimen: boolean
   modality: string | null
   transferSyntaxUid: string | null
   transferSyntaxName: string | null
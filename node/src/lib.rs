@@ -56,6 +56,8 @@ pub struct MammogramMetadata {
     pub concatenation_uid: Option<String>,
     pub sop_instance_uid_of_concatenation_source: Option<String>,
     pub is_secondary_capture: bool,
+    pub is_localizer: bool,
+    pub is_specimen: bool,
     pub modality: Option<String>,
     pub transfer_syntax_uid: Option<String>,
     pub transfer_syntax_name: Option<String>,
@@ -333,7 +335,9 @@ fn views_filter(preference_order: PreferenceOrder) -> FilterConfig {
             MammogramType::Sfm,
             MammogramType::Tomo,
         ]),
-        PreferenceOrder::Default | PreferenceOrder::Synthetic2dFirst => HashSet::from([
+        PreferenceOrder::Default
+        | PreferenceOrder::Synthetic2dFirst
+        | PreferenceOrder::Custom(_) => HashSet::from([
             MammogramType::Ffdm,
             MammogramType::Synth,
             MammogramType::Sfm,
@@ -346,9 +350,9 @@ fn views_filter(preference_order: PreferenceOrder) -> FilterConfig {
             DbtObjectKind::Slice,
             DbtObjectKind::Unknown,
         ]),
-        PreferenceOrder::Default | PreferenceOrder::Synthetic2dFirst => {
-            HashSet::from([DbtObjectKind::None])
-        }
+        PreferenceOrder::Default
+        | PreferenceOrder::Synthetic2dFirst
+        | PreferenceOrder::Custom(_) => HashSet::from([DbtObjectKind::None]),
     };
 
     FilterConfig::default()
@@ -488,6 +492,12 @@ fn filter_reasons(record: &CoreMammogramRecord, filter_config: &FilterConfig) ->
     if filter_config.exclude_secondary_capture && record.metadata.is_secondary_capture {
         reasons.push("excludeSecondaryCapture".to_string());
     }
+    if filter_config.exclude_localizers && record.metadata.is_localizer {
+        reasons.push("excludeLocalizers".to_string());
+    }
+    if filter_config.exclude_specimens && record.metadata.is_specimen {
+        reasons.push("excludeSpecimens".to_string());
+    }
     if filter_config.exclude_non_mg_modality {
         match record.metadata.modality.as_deref() {
             Some("MG") => {}
@@ -552,6 +562,8 @@ fn metadata_to_dto(metadata: &mammocat_core::MammogramMetadata) -> MammogramMeta
             .sop_instance_uid_of_concatenation_source
             .clone(),
         is_secondary_capture: metadata.is_secondary_capture,
+        is_localizer: metadata.is_localizer,
+        is_specimen: metadata.is_specimen,
         modality: metadata.modality.clone(),
         transfer_syntax_uid: metadata.transfer_syntax_uid.clone(),
         transfer_syntax_name: metadata.transfer_syntax_name.clone(),
@@ -565,7 +577,9 @@ fn error_code(error: &mammocat_core::MammocatError) -> &'static str {
         mammocat_core::MammocatError::TagNotFound(_) => "tag_not_found",
         mammocat_core::MammocatError::InvalidValue(_) => "invalid_value",
         mammocat_core::MammocatError::ExtractionError(_) => "extraction_error",
+        mammocat_core::MammocatError::NotMammography { .. } => "not_mammography",
         mammocat_core::MammocatError::SelectionError(_) => "selection_error",
+        mammocat_core::MammocatError::NonImageSopClass(_) => "non_image_sop_class",
         mammocat_core::MammocatError::IoError(_) => "io_error",
     }
 }
@@ -42,6 +42,7 @@ from ._mammocat import (
     MammogramType,
     MammogramView,
     MammographyViewModifier,
+    NotMammographyError,
     PhotometricInterpretation,
     PreferenceOrder,
     SelectionError,
@@ -53,8 +54,13 @@ from ._mammocat import (
     get_preferred_views,
     get_preferred_views_filtered,
     get_preferred_views_with_order,
+    get_ranked_views,
+    missing_views,
     plan_mammography_collection,
+    process_directory,
     scan_dbt_study,
+    scan_directory,
+    study_completeness,
     validate_dicom,
     validate_directory,
 )
@@ -75,6 +81,7 @@ __all__ = [
     "MammogramType",
     "MammogramView",
     "MammographyViewModifier",
+    "NotMammographyError",
     "PhotometricInterpretation",
     "PreferenceOrder",
     "SelectionError",
@@ -85,8 +92,13 @@ __all__ = [
     "get_preferred_views",
     "get_preferred_views_filtered",
     "get_preferred_views_with_order",
+    "get_ranked_views",
+    "missing_views",
     "plan_mammography_collection",
+    "process_directory",
     "scan_dbt_study",
+    "scan_directory",
+    "study_completeness",
     "validate_dicom",
     "validate_directory",
 ]
@@ -22,6 +22,9 @@ class InvalidValueError(MammocatError):
 class ExtractionError(MammocatError):
     """Generic metadata extraction error."""
 
+class NotMammographyError(ExtractionError):
+    """File is not a mammogram (Modality is not MG)."""
+
 class SelectionError(MammocatError):
     """Preferred-view selection error."""
 
@@ -34,7 +37,10 @@ class MammogramType:
     FFDM: MammogramType
     SYNTH: MammogramType
     SFM: MammogramType
+    CEM: MammogramType
 
+    @staticmethod
+    def all() -> list[MammogramType]: ...
     @property
     def value(self) -> str: ...
     def is_unknown(self) -> bool: ...
@@ -74,6 +80,8 @@ class Laterality:
     RIGHT: Laterality
     BILATERAL: Laterality
 
+    @staticmethod
+    def all() -> list[Laterality]: ...
     @property
     def value(self) -> str: ...
     def is_unknown(self) -> bool: ...
@@ -103,6 +111,8 @@ class ViewPosition:
     XCCM: ViewPosition
     SPECIMEN: ViewPosition
 
+    @staticmethod
+    def all() -> list[ViewPosition]: ...
     @property
     def value(self) -> str: ...
     def is_unknown(self) -> bool: ...
@@ -152,6 +162,8 @@ class PreferenceOrder:
     TOMO_FIRST: PreferenceOrder
     SYNTHETIC_2D_FIRST: PreferenceOrder
 
+    @staticmethod
+    def custom(ranking: list[MammogramType]) -> PreferenceOrder: ...
     @property
     def value(self) -> str: ...
     def __str__(self) -> str: ...
@@ -243,6 +255,8 @@ class MammogramMetadata:
     @property
     def is_for_processing(self) -> bool: ...
     @property
+    def presentation_intent(self) -> str: ...
+    @property
     def has_implant(self) -> bool: ...
     @property
     def is_spot_compression(self) -> bool: ...
@@ -251,6 +265,8 @@ class MammogramMetadata:
     @property
     def is_implant_displaced(self) -> bool: ...
     @property
+    def is_rolled(self) -> bool: ...
+    @property
     def manufacturer(self) -> str | None: ...
     @property
     def model(self) -> str | None: ...
@@ -259,14 +275,36 @@ class MammogramMetadata:
     @property
     def pixel_spacing(self) -> dict[str, float] | None: ...
     @property
+    def imager_pixel_spacing(self) -> dict[str, float] | None: ...
+    @property
+    def spacing_magnification_factor(self) -> float | None: ...
+    @property
+    def photometric(self) -> PhotometricInterpretation: ...
+    @property
+    def needs_inversion(self) -> bool: ...
+    @property
+    def rescale_slope(self) -> float | None: ...
+    @property
+    def rescale_intercept(self) -> float | None: ...
+    @property
+    def body_part_thickness_mm(self) -> float | None: ...
+    @property
+    def compression_force_n(self) -> float | None: ...
+    @property
     def concatenation_uid(self) -> str | None: ...
     @property
     def sop_instance_uid_of_concatenation_source(self) -> str | None: ...
     @property
     def is_secondary_capture(self) -> bool: ...
     @property
+    def is_localizer(self) -> bool: ...
+    @property
+    def is_specimen(self) -> bool: ...
+    @property
     def modality(self) -> str | None: ...
     @property
+    def character_set(self) -> str | None: ...
+    @property
     def transfer_syntax_uid(self) -> str | None: ...
     @property
     def transfer_syntax_name(self) -> str | None: ...
@@ -278,6 +316,11 @@ class MammogramMetadata:
     def to_dict(self) -> dict[str, Any]: ...
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __reduce__(self) -> tuple[Any, tuple[str]]: ...
+    def __getstate__(self) -> str: ...
+    def __setstate__(self, state: str) -> None: ...
 
 class MammogramRecord:
     """Mammogram record combining file path and extracted metadata."""
@@ -285,6 +328,10 @@ class MammogramRecord:
     def from_file(path: str | Path) -> MammogramRecord: ...
     @staticmethod
     def from_bytes(data: bytes, id: str | None = None) -> MammogramRecord: ...
+    @staticmethod
+    def from_files(
+        paths: list[str | Path],
+    ) -> tuple[list[MammogramRecord], list[tuple[str, str]]]: ...
     @property
     def file_path(self) -> str: ...
     @property
@@ -304,12 +351,39 @@ class MammogramRecord:
     @property
     def is_lossy_compressed(self) -> bool: ...
     @property
+    def study_date(self) -> str | None: ...
+    @property
+    def patient_id(self) -> str | None: ...
+    @property
+    def patient_name(self) -> str | None: ...
+    @property
+    def compression(
+        self,
+    ) -> Literal[
+        "uncompressed", "rle", "jpeg_lossless", "jpeg_lossy", "jpeg2000", "jpeg_ls", "other"
+    ]: ...
+    @property
+    def sop_class(
+        self,
+    ) -> Literal[
+        "mammo_image",
+        "tomo_image",
+        "cad_sr",
+        "presentation_state",
+        "key_object_selection",
+        "secondary_capture",
+        "other",
+    ]: ...
+    @property
     def is_implant_displaced(self) -> bool: ...
     @property
     def is_spot_compression(self) -> bool: ...
     @property
     def is_magnified(self) -> bool: ...
+    @property
+    def is_rolled(self) -> bool: ...
     def image_area(self) -> int | None: ...
+    def physical_size_mm(self) -> tuple[float, float] | None: ...
     def is_spot_or_mag(self) -> bool: ...
     def is_preferred_to(self, other: MammogramRecord) -> bool: ...
     def is_preferred_to_with_order(
@@ -326,8 +400,12 @@ class MammogramExtractor:
     def extract_from_file(path: str | Path) -> MammogramMetadata: ...
     @staticmethod
     def extract_from_file_with_options(
-        path: str | Path, is_sfm: bool = False
+        path: str | Path, is_sfm: bool = False, ignore_modality: bool = False
     ) -> MammogramMetadata: ...
+    @staticmethod
+    def get_tag(path: str | Path, group: int, element: int) -> str | None: ...
+    @staticmethod
+    def extract_display_metadata(path: str | Path) -> dict[str, float | None]: ...
 
 def validate_dicom(
     path: str | Path,
@@ -358,6 +436,17 @@ class FilterConfig:
         exclude_lossy_compressed: bool = False,
         deprioritize_lossy_compressed: bool = True,
         allowed_dbt_object_kinds: list[DbtObjectKind] | None = None,
+        require_for_presentation: bool = False,
+        min_frames: int | None = None,
+        exclude_spot_mag: bool = False,
+        min_image_area: int | None = None,
+        allowed_manufacturers: list[str] | None = None,
+        excluded_models: list[str] | None = None,
+        prefer_latest_study: bool = False,
+        exclude_patient_name_patterns: list[str] | None = None,
+        exclude_phantoms: bool = False,
+        exclude_localizers: bool = True,
+        exclude_specimens: bool = True,
     ) -> None: ...
     @staticmethod
     def default() -> FilterConfig: ...
@@ -372,10 +461,30 @@ class FilterConfig:
     @property
     def exclude_non_standard_views(self) -> bool: ...
     @property
+    def exclude_spot_mag(self) -> bool: ...
+    @property
     def exclude_for_processing(self) -> bool: ...
     @property
+    def require_for_presentation(self) -> bool: ...
+    @property
+    def min_frames(self) -> int | None: ...
+    @property
+    def min_image_area(self) -> int | None: ...
+    @property
+    def allowed_manufacturers(self) -> list[str] | None: ...
+    @property
+    def excluded_models(self) -> list[str] | None: ...
+    @property
+    def exclude_patient_name_patterns(self) -> list[str] | None: ...
+    @property
+    def exclude_phantoms(self) -> bool: ...
+    @property
     def exclude_secondary_capture(self) -> bool: ...
     @property
+    def exclude_localizers(self) -> bool: ...
+    @property
+    def exclude_specimens(self) -> bool: ...
+    @property
     def exclude_non_mg_modality(self) -> bool: ...
     @property
     def require_common_modality(self) -> bool: ...
@@ -383,6 +492,8 @@ class FilterConfig:
     def exclude_lossy_compressed(self) -> bool: ...
     @property
     def deprioritize_lossy_compressed(self) -> bool: ...
+    @property
+    def prefer_latest_study(self) -> bool: ...
     def __repr__(self) -> str: ...
 
 # Selection functions
@@ -394,7 +505,7 @@ def get_preferred_views(
 
     For each of the 4 standard views (L-MLO, R-MLO, L-CC, R-CC), selects the
     most preferred mammogram based on comparison logic using the default
-    preference order (FFDM > SYNTH > TOMO > SFM).
+    preference order (FFDM > SYNTH > TOMO > SFM > CEM).
 
     Args:
         records: List of MammogramRecord objects to select from
@@ -426,6 +537,55 @@ def get_preferred_views_with_order(
         Dictionary mapping MammogramView to MammogramRecord (or None if not found)
     """
 
+def get_ranked_views(
+    records: list[MammogramRecord],
+    preference_order: PreferenceOrder,
+) -> dict[MammogramView, list[MammogramRecord]]:
+    """Rank all candidates for each standard view, best first.
+
+    Uses the same comparison logic as get_preferred_views_with_order, so the
+    first record of each list is the record that function would select.
+
+    Args:
+        records: List of MammogramRecord objects to rank
+        preference_order: The preference ordering strategy to use
+
+    Returns:
+        Dictionary mapping MammogramView to candidates sorted best to worst
+        (empty list if no candidates)
+    """
+
+def process_directory(
+    path: str | Path,
+    filter_config: FilterConfig | None = None,
+    preference_order: PreferenceOrder | None = None,
+    recursive: bool = False,
+) -> dict[str, Any]:
+    """Scan a directory, read every DICOM file, and select preferred views.
+
+    Files that cannot be read as mammogram records are reported instead of
+    raising. Selection warnings are emitted as UserWarning.
+
+    Returns:
+        Dictionary with ``selections`` (MammogramView to MammogramRecord or
+        None), ``records_processed`` (int), and ``skipped`` (list of
+        ``(path, reason)`` tuples)
+    """
+
+def study_completeness(
+    selections: dict[MammogramView, MammogramRecord | None],
+) -> float:
+    """Fraction of the four standard views with a selected record.
+
+    Returns 1.0 for a complete bilateral study, 0.75 with one view missing,
+    and 0.0 when no standard view is selected.
+    """
+
+def missing_views(
+    selections: dict[MammogramView, MammogramRecord | None],
+) -> list[MammogramView]:
+    """Standard views without a selected record, in standard view order."""
+
 def get_preferred_views_filtered(
     records: list[MammogramRecord],
     filter_config: FilterConfig,
@@ -458,6 +618,9 @@ def plan_mammography_collection(
 ) -> dict[str, Any]:
     """Plan 2D mammography view and/or DBT inputs from a DICOM directory."""
 
+def scan_directory(path: str | Path, recursive: bool = False) -> list[str]:
+    """List DICOM file candidates in a directory, sorted by path."""
+
 def scan_dbt_study(input_dir: str | Path) -> dict[str, Any]:
     """Scan a study directory for old-format DBT series needing conversion."""
 
@@ -1,5 +1,6 @@
 """Tests for mammocat main API (requires DICOM fixtures)."""
 
+import pickle
 from pathlib import Path
 
 import pytest
@@ -7,16 +8,23 @@ import pytest
 from mammocat import (
     DbtObjectKind,
     DicomError,
+    ExtractionError,
     FilterConfig,
     MammogramExtractor,
     MammogramRecord,
     MammogramType,
     MammographyViewModifier,
+    NotMammographyError,
     PreferenceOrder,
     SelectionError,
     get_preferred_views,
     get_preferred_views_filtered,
     get_preferred_views_with_order,
+    get_ranked_views,
+    missing_views,
+    process_directory,
+    scan_directory,
+    study_completeness,
 )
 from tests.conftest import create_old_format_dbt_slice
 
@@ -63,6 +71,7 @@ class TestMammogramExtractor:
         assert isinstance(metadata.is_spot_compression, bool)
         assert isinstance(metadata.is_magnified, bool)
         assert isinstance(metadata.is_implant_displaced, bool)
+        assert not metadata.is_rolled
         assert isinstance(metadata.view_modifiers, list)
         assert isinstance(metadata.number_of_frames, int)
         assert metadata.pixel_spacing == {"row": 0.07, "column": 0.07}
@@ -82,6 +91,70 @@ class TestMammogramExtractor:
         metadata = MammogramExtractor.extract_from_file_with_options(sample_dicom, is_sfm=False)
         assert metadata is not None
 
+    def test_extract_with_options_ignore_modality(self, tmp_path, mammogram_dicom_factory):
+        """A mislabeled modality is classified only when ignore_modality is set."""
+        ds = mammogram_dicom_factory()
+        ds.Modality = "OT"
+        path = tmp_path / "mislabeled.dcm"
+        ds.save_as(path, enforce_file_format=True)
+
+        with pytest.raises(NotMammographyError):
+            MammogramExtractor.extract_from_file_with_options(path)
+        metadata = MammogramExtractor.extract_from_file_with_options(path, ignore_modality=True)
+
+        assert metadata.mammogram_type == MammogramType.FFDM
+        assert metadata.modality == "OT"
+
+    def test_non_mg_modality_raises_not_mammography_error(self, tmp_path, mammogram_dicom_factory):
+        """A non-MG file raises NotMammographyError, which is also an ExtractionError."""
+        ds = mammogram_dicom_factory()
+        ds.Modality = "CT"
+        path = tmp_path / "ct.dcm"
+        ds.save_as(path, enforce_file_format=True)
+
+        with pytest.raises(NotMammographyError, match="found CT") as excinfo:
+            MammogramExtractor.extract_from_file(path)
+
+        assert isinstance(excinfo.value, ExtractionError)
+
+    def test_get_tag_reads_unmodeled_tag(self, sample_dicom):
+        """Test reading a tag by group and element."""
+        assert MammogramExtractor.get_tag(sample_dicom, 0x0008, 0x0060) == "MG"
+
+    def test_get_tag_missing_returns_none(self, sample_dicom):
+        """Test that an absent tag (BodyPartThickness) returns None."""
+        assert MammogramExtractor.get_tag(sample_dicom, 0x0018, 0x11A0) is None
+
+    def test_get_tag_nonexistent_file(self):
+        with pytest.raises(IOError):
+            MammogramExtractor.get_tag("/nonexistent/file.dcm", 0x0008, 0x0060)
+
+    def test_extract_display_metadata_uses_first_window_preset(
+        self, tmp_path, mammogram_dicom_factory
+    ):
+        """Multi-valued windowing reports the first preset."""
+        ds = mammogram_dicom_factory()
+        ds.WindowCenter = ["2000", "1800"]
+        ds.WindowWidth = ["4000", "3000"]
+        ds.RescaleSlope = "1"
+        ds.RescaleIntercept = "0"
+        path = tmp_path / "windowed.dcm"
+        ds.save_as(path, enforce_file_format=True)
+
+        display = MammogramExtractor.extract_display_metadata(path)
+
+        assert display == {
+            "window_center": 2000.0,
+            "window_width": 4000.0,
+            "rescale_slope": 1.0,
+            "rescale_intercept": 0.0,
+        }
+
+    def test_extract_display_metadata_without_window(self, sample_dicom):
+        display = MammogramExtractor.extract_display_metadata(sample_dicom)
+        assert display["window_center"] is None
+        assert display["window_width"] is None
+
     def test_metadata_methods(self, sample_dicom):
         """Test metadata helper methods."""
         metadata = MammogramExtractor.extract_from_file(sample_dicom)
@@ -118,6 +191,27 @@ class TestMammogramExtractor:
         assert d["transfer_syntax_name"] == "Explicit VR Little Endian"
         assert d["compression_type"] == "uncompressed"
 
+    def test_thickness_and_compression_force(self, fixtures_dir, mammogram_dicom_factory):
+        """Test BodyPartThickness and CompressionForce extraction."""
+        dicom_path = fixtures_dir / "thickness_force.dcm"
+        ds = mammogram_dicom_factory()
+        ds.BodyPartThickness = "52.5"
+        ds.CompressionForce = "98"
+        ds.save_as(dicom_path, enforce_file_format=True)
+
+        metadata = MammogramExtractor.extract_from_file(dicom_path)
+
+        assert metadata.body_part_thickness_mm == 52.5
+        assert metadata.compression_force_n == 98.0
+        assert metadata.to_dict()["compression_force_n"] == 98.0
+
+    def test_missing_thickness_and_compression_force(self, sample_dicom):
+        """Test absent BodyPartThickness and CompressionForce are None."""
+        metadata = MammogramExtractor.extract_from_file(sample_dicom)
+
+        assert metadata.body_part_thickness_mm is None
+        assert metadata.compression_force_n is None
+
     def test_synthesized_metadata_uses_canonical_machine_value(
         self, fixtures_dir, mammogram_dicom_factory
     ):
@@ -262,6 +356,7 @@ class TestMammogramRecord:
         assert record.metadata is not None
         assert isinstance(record.series_instance_uid, str)
         assert isinstance(record.is_implant_displaced, bool)
+        assert isinstance(record.is_rolled, bool)
         assert isinstance(record.is_spot_compression, bool)
         assert isinstance(record.is_magnified, bool)
         assert isinstance(record.is_lossy_compressed, bool)
@@ -285,11 +380,36 @@ class TestMammogramRecord:
             assert isinstance(area, int)
             assert area > 0
 
+    def test_physical_size_mm(self, tmp_path, mammogram_dicom_factory):
+        """Test physical size uses dimensions times pixel spacing."""
+        paths = []
+        for name, spacing in [("spaced.dcm", (0.125, 0.0625)), ("unspaced.dcm", None)]:
+            ds = mammogram_dicom_factory(rows=2048, columns=1536, pixel_spacing=spacing)
+            paths.append(tmp_path / name)
+            ds.save_as(paths[-1], enforce_file_format=True)
+
+        assert MammogramRecord.from_file(str(paths[0])).physical_size_mm() == (256.0, 96.0)
+        assert MammogramRecord.from_file(str(paths[1])).physical_size_mm() is None
+
     def test_is_spot_or_mag(self, sample_dicom):
         """Test is_spot_or_mag method."""
         record = MammogramRecord.from_file(sample_dicom)
         assert isinstance(record.is_spot_or_mag(), bool)
 
+    def test_metadata_equality_hash_and_pickle(self, sample_dicom, lossy_dicom):
+        """Test metadata compares by value, hashes, and survives pickling."""
+        metadata = MammogramRecord.from_file(sample_dicom).metadata
+        same = MammogramRecord.from_file(sample_dicom).metadata
+        other = MammogramRecord.from_file(lossy_dicom).metadata
+
+        restored = pickle.loads(pickle.dumps(metadata))
+
+        assert metadata == same
+        assert metadata != other
+        assert restored == metadata
+        assert restored.to_dict() == metadata.to_dict()
+        assert len({metadata, same, restored}) == 1
+
     def test_record_to_dict(self, sample_dicom):
         """Test record to_dict conversion."""
         record = MammogramRecord.from_file(sample_dicom)
@@ -359,6 +479,8 @@ class TestMammogramRecordFromBytes:
 
         assert record.is_lossy_compressed
         assert record.transfer_syntax_uid is not None
+        # Lossy history comes from LossyImageCompression; pixel data is stored natively
+        assert record.compression == "uncompressed"
 
     def test_from_bytes_invalid_data(self):
         """Test from_bytes with invalid DICOM data."""
@@ -386,6 +508,99 @@ class TestMammogramRecordFromBytes:
         assert len(result) == 4
 
 
+class TestMammogramRecordSopClass:
+    """Tests for SOP class classification on MammogramRecord."""
+
+    def test_mammography_image_sop_class(self, sample_dicom):
+        record = MammogramRecord.from_file(sample_dicom)
+
+        assert record.sop_class == "mammo_image"
+        assert record.to_dict()["sop_class"] == "mammo_image"
+
+    def test_cad_sr_sop_class_is_recorded(self, tmp_path, mammogram_dicom_factory):
+        cad_sr_uid = "1.2.840.10008.5.1.4.1.1.88.50"
+        ds = mammogram_dicom_factory()
+        ds.SOPClassUID = cad_sr_uid
+        ds.file_meta.MediaStorageSOPClassUID = cad_sr_uid
+        path = tmp_path / "cad_sr.dcm"
+        ds.save_as(path, enforce_file_format=True)
+
+        record = MammogramRecord.from_file(path)
+
+        assert record.sop_class == "cad_sr"
+
+
+class TestMammogramRecordFromFiles:
+    """Tests for MammogramRecord.from_files batch construction."""
+
+    def test_from_files_returns_records_and_failures(self, sample_dicom_set, tmp_path):
+        missing = tmp_path / "missing.dcm"
+        not_dicom = tmp_path / "notes.dcm"
+        not_dicom.write_text("not dicom")
+        paths = [sample_dicom_set[0], missing, Path(sample_dicom_set[1]), not_dicom]
+
+        records, failures = MammogramRecord.from_files(paths)
+
+        assert [r.file_path for r in records] == [str(sample_dicom_set[0]), str(sample_dicom_set[1])]
+        assert [path for path, _ in failures] == [str(missing), str(not_dicom)]
+        assert all(isinstance(message, str) and message for _, message in failures)
+
+    def test_from_files_matches_from_file(self, sample_dicom_set):
+        records, failures = MammogramRecord.from_files(sample_dicom_set)
+
+        assert failures == []
+        assert [r.to_dict() for r in records] == [
+            MammogramRecord.from_file(path).to_dict() for path in sample_dicom_set
+        ]
+
+    def test_from_files_empty(self):
+        assert MammogramRecord.from_files([]) == ([], [])
+
+
+class TestScanDirectory:
+    """Tests for DICOM file discovery."""
+
+    def test_scan_directory_recursive_flag(self, tmp_path):
+        """Nested files are only listed when recursive=True."""
+        nested = tmp_path / "series"
+        nested.mkdir()
+        (tmp_path / "top.dcm").write_bytes(b"candidate")
+        (nested / "image.dcm").write_bytes(b"candidate")
+        (nested / "notes.txt").write_text("not DICOM")
+
+        assert scan_directory(tmp_path) == [str(tmp_path / "top.dcm")]
+        assert scan_directory(tmp_path, recursive=True) == [
+            str(nested / "image.dcm"),
+            str(tmp_path / "top.dcm"),
+        ]
+
+
+class TestProcessDirectory:
+    """Tests for the one-call directory pipeline."""
+
+    def test_process_directory_reports_skipped_files(self, tmp_path, mammogram_dicom_factory):
+        """Valid files are selected and unreadable files are listed as skipped."""
+        for index, (laterality, view) in enumerate([("L", "CC"), ("R", "MLO")], start=1):
+            _write_test_dicom(
+                tmp_path,
+                mammogram_dicom_factory,
+                filename=f"{laterality}_{view}.dcm",
+                study_uid="1.2.3.4",
+                sop_suffix=str(index),
+                laterality=laterality,
+                view_position=view,
+            )
+        broken = tmp_path / "broken.dcm"
+        broken.write_bytes(b"not a DICOM file")
+
+        result = process_directory(tmp_path)
+
+        assert result["records_processed"] == 2
+        assert [path for path, _ in result["skipped"]] == [str(broken)]
+        assert len(result["selections"]) == 4
+        assert sum(record is not None for record in result["selections"].values()) == 2
+
+
 class TestPreferredViews:
     def test_get_preferred_views_empty(self):
         """Test get_preferred_views with empty list."""
@@ -419,6 +634,21 @@ class TestPreferredViews:
         for view in result:
             assert view is not None
 
+    def test_study_completeness_and_missing_views(self, sample_dicom_set):
+        records = [MammogramRecord.from_file(str(f)) for f in sample_dicom_set]
+        selections = get_preferred_views(records)
+        assert study_completeness(selections) == 1.0
+        assert missing_views(selections) == []
+
+        dropped = next(iter(selections))
+        selections[dropped] = None
+        assert study_completeness(selections) == 0.75
+        assert missing_views(selections) == [dropped]
+
+        empty = get_preferred_views([])
+        assert study_completeness(empty) == 0.0
+        assert len(missing_views(empty)) == 4
+
     def test_preference_order_variants(self, sample_dicom_set):
         """Test different preference orders."""
         records = [MammogramRecord.from_file(str(f)) for f in sample_dicom_set]
@@ -432,6 +662,21 @@ class TestPreferredViews:
         assert len(result_default) == 4
         assert len(result_tomo) == 4
 
+    def test_get_ranked_views_first_matches_preferred(self, sample_dicom_set):
+        """Test that ranked candidates start with the preferred selection."""
+        records = [MammogramRecord.from_file(str(f)) for f in sample_dicom_set]
+
+        ranked = get_ranked_views(records, PreferenceOrder.DEFAULT)
+        preferred = get_preferred_views_with_order(records, PreferenceOrder.DEFAULT)
+
+        assert len(ranked) == 4
+        for view, candidates in ranked.items():
+            selected = preferred[view]
+            if selected is None:
+                assert candidates == []
+            else:
+                assert str(candidates[0].file_path) == str(selected.file_path)
+
     def test_default_selects_one_most_complete_study(self, fixtures_dir, mammogram_dicom_factory):
         """Test default selection does not mix studies."""
         incomplete_study = "1.2.826.0.10"
@@ -539,6 +784,10 @@ class TestFilterConfig:
         config = FilterConfig(require_common_modality=True)
         assert config.require_common_modality is True
 
+    def test_prefer_latest_study_true(self):
+        config = FilterConfig(prefer_latest_study=True)
+        assert config.prefer_latest_study is True
+
     def test_default_static_method(self):
         """Test FilterConfig.default() has require_common_modality == False."""
         config = FilterConfig.default()
@@ -558,10 +807,13 @@ class TestFilterConfig:
         assert config.exclude_non_standard_views is False
         assert config.exclude_for_processing is True
         assert config.exclude_secondary_capture is True
+        assert config.exclude_localizers is True
+        assert config.exclude_specimens is True
         assert config.exclude_non_mg_modality is True
         assert config.require_common_modality is False
         assert config.exclude_lossy_compressed is False
         assert config.deprioritize_lossy_compressed is True
+        assert config.prefer_latest_study is False
 
     def test_lossy_compression_options(self):
         """Test FilterConfig lossy compression options."""
@@ -573,6 +825,55 @@ class TestFilterConfig:
         assert config.exclude_lossy_compressed is True
         assert config.deprioritize_lossy_compressed is False
 
+    def test_min_image_area_option(self):
+        """Test FilterConfig minimum image area option."""
+        assert FilterConfig().min_image_area is None
+        assert FilterConfig(min_image_area=1_000_000).min_image_area == 1_000_000
+
+    def test_manufacturer_and_model_options(self):
+        """Test FilterConfig manufacturer whitelist and model blacklist options."""
+        config = FilterConfig(allowed_manufacturers=["HOLOGIC, Inc."], excluded_models=["Lorad"])
+
+        assert config.allowed_manufacturers == ["HOLOGIC, Inc."]
+        assert config.excluded_models == ["Lorad"]
+        assert FilterConfig().allowed_manufacturers is None
+
+    def test_exclude_phantoms_filters_phantom_patient_names(
+        self, tmp_path, mammogram_dicom_factory
+    ):
+        """A PatientName of "ACR PHANTOM" is dropped when exclude_phantoms is set."""
+        ds = mammogram_dicom_factory(laterality="L", view_position="CC")
+        ds.PatientName = "ACR PHANTOM"
+        path = tmp_path / "phantom.dcm"
+        ds.save_as(path, enforce_file_format=True)
+        record = MammogramRecord.from_file(str(path))
+
+        config = FilterConfig(exclude_phantoms=True)
+        result = get_preferred_views_filtered([record], config, PreferenceOrder.DEFAULT)
+
+        assert record.patient_name == "ACR PHANTOM"
+        assert config.exclude_phantoms is True
+        assert config.exclude_patient_name_patterns is None
+        assert all(selected is None for selected in result.values())
+        unfiltered = get_preferred_views_filtered([record], FilterConfig(), PreferenceOrder.DEFAULT)
+        assert any(selected is not None for selected in unfiltered.values())
+
+    def test_localizer_frames_are_excluded_by_default(self, tmp_path, mammogram_dicom_factory):
+        """An ImageType containing LOCALIZER is flagged and filtered unless allowed."""
+        ds = mammogram_dicom_factory(laterality="L", view_position="CC")
+        ds.ImageType = ["ORIGINAL", "PRIMARY", "", "LOCALIZER"]
+        path = tmp_path / "localizer.dcm"
+        ds.save_as(path, enforce_file_format=True)
+        record = MammogramRecord.from_file(str(path))
+
+        assert record.metadata.is_localizer is True
+        assert record.metadata.is_specimen is False
+        result = get_preferred_views_filtered([record], FilterConfig(), PreferenceOrder.DEFAULT)
+        assert all(selected is None for selected in result.values())
+        config = FilterConfig(exclude_localizers=False)
+        kept = get_preferred_views_filtered([record], config, PreferenceOrder.DEFAULT)
+        assert any(selected is not None for selected in kept.values())
+
     def test_dbt_object_kind_filter_options(self):
         """Test FilterConfig DBT object kind whitelist options."""
         config = FilterConfig(allowed_dbt_object_kinds=[DbtObjectKind.VOLUME, DbtObjectKind.SLICE])
@@ -1,8 +1,11 @@
 """Tests for mammocat enum types."""
 
+import pytest
+
 from mammocat import (
     DbtObjectKind,
     ImageType,
+    InvalidValueError,
     Laterality,
     MammogramType,
     MammogramView,
@@ -54,6 +57,14 @@ class TestMammogramType:
         assert MammogramType.FFDM.is_preferred_to(MammogramType.SYNTH)
         assert not MammogramType.SYNTH.is_preferred_to(MammogramType.FFDM)
 
+    def test_all(self):
+        """Test all() lists every variant."""
+        all_types = MammogramType.all()
+        assert len(all_types) == 6
+        assert len(set(all_types)) == 6
+        assert MammogramType.CEM in all_types
+        assert MammogramType.UNKNOWN in all_types
+
 
 class TestDbtObjectKind:
     def test_enum_values(self):
@@ -113,6 +124,12 @@ class TestLaterality:
         lat_set = {Laterality.LEFT, Laterality.RIGHT, Laterality.LEFT}
         assert len(lat_set) == 2
 
+    def test_all(self):
+        """Test all() lists every variant."""
+        all_lateralities = Laterality.all()
+        assert len(all_lateralities) == 5
+        assert Laterality.BILATERAL in all_lateralities
+
 
 class TestViewPosition:
     def test_enum_values(self):
@@ -154,6 +171,13 @@ class TestViewPosition:
         assert ViewPosition.UNKNOWN < ViewPosition.XCCL
         assert ViewPosition.CC < ViewPosition.MLO
 
+    def test_all(self):
+        """Test all() lists every variant."""
+        all_views = ViewPosition.all()
+        assert len(all_views) == 12
+        assert ViewPosition.SPECIMEN in all_views
+        assert all_views[0] == ViewPosition.UNKNOWN
+
 
 class TestMammographyViewModifier:
     def test_complete_cid_4015_values(self):
@@ -208,6 +232,25 @@ class TestPreferenceOrder:
         assert str(PreferenceOrder.TOMO_FIRST) == "tomo-first"
         assert str(PreferenceOrder.SYNTHETIC_2D_FIRST) == "synthetic-2d-first"
 
+    def test_custom(self):
+        """Test building a custom ranking."""
+        order = PreferenceOrder.custom(
+            [
+                MammogramType.SFM,
+                MammogramType.SYNTH,
+                MammogramType.FFDM,
+                MammogramType.TOMO,
+                MammogramType.CEM,
+            ]
+        )
+        assert order.value == "custom:sfm,synth,ffdm,tomo,cem"
+        assert order != PreferenceOrder.DEFAULT
+
+    def test_custom_rejects_incomplete_ranking(self):
+        """Test custom ranking validation."""
+        with pytest.raises(InvalidValueError):
+            PreferenceOrder.custom([MammogramType.FFDM])
+
 
 class TestPhotometricInterpretation:
     def test_enum_values(self):